@@ -1,6 +1,10 @@
+use crate::{
+    http::{HttpRequest, HttpResponse},
+    http_cache::HttpCache,
+    permission::Permissions,
+};
 use directories_next::ProjectDirs;
 use include_dir::{include_dir, Dir};
-use reqwest::blocking::Response;
 use std::{
     fmt,
     fs::{self, read_to_string, File},
@@ -36,7 +40,8 @@ pub enum AddressType {
 #[derive(Debug)]
 pub enum AddressErr {
     FileSystemFailure(std::io::Error),
-    NetworkFailure(reqwest::Error),
+    NetworkFailure(String),
+    UnsuccessfulStatus(u16),
     InvalidAddress(String),
     ZonkeyAssetError(String),
 }
@@ -46,6 +51,9 @@ impl fmt::Display for AddressErr {
         match self {
             AddressErr::FileSystemFailure(e) => write!(f, "Failed to read file - {e}"),
             AddressErr::NetworkFailure(e) => write!(f, "Failed to read over network - {e}"),
+            AddressErr::UnsuccessfulStatus(status) => {
+                write!(f, "Server responded with status {status}")
+            }
             AddressErr::InvalidAddress(e) => {
                 write!(f, "Could not load as the address is invalid - {e}")
             }
@@ -110,22 +118,95 @@ impl Address {
         }
     }
 
+    // Resolves an address written in a script against the address of that script. Addresses with
+    // a scheme are absolute, and anything else is relative to the directory of the script, or to
+    // the root of its server or the zonkey assets when it starts with '/'.
+    pub fn resolve(&self, reference: &str, arguments: Vec<String>) -> Self {
+        if has_scheme(reference) || reference.is_empty() {
+            return Self::new(reference, arguments);
+        }
+
+        let location = match &self.address_type {
+            AddressType::HTTP { .. } => {
+                let (authority, path) = match self.location.strip_prefix("//") {
+                    Some(location) => location.split_once('/').unwrap_or((location, "")),
+                    None => return Self::new(reference, arguments),
+                };
+
+                match reference.strip_prefix("//") {
+                    // A network path reference, which only keeps the scheme
+                    Some(_) => reference.to_string(),
+                    None => format!("//{authority}/{}", resolve_path(path, reference)),
+                }
+            }
+            AddressType::Zonkey => resolve_path(&self.location, reference),
+            AddressType::File | AddressType::Installed => {
+                match (
+                    Path::new(reference).is_absolute(),
+                    Path::new(&self.location).parent(),
+                ) {
+                    (false, Some(directory)) => directory.join(reference).display().to_string(),
+                    _ => reference.to_string(),
+                }
+            }
+        };
+
+        Self {
+            address_type: self.address_type.clone(),
+            arguments,
+            location,
+        }
+    }
+
+    // The scheme, host and port of a network address, such as 'https://example.com:8000'
+    pub fn origin(&self) -> Option<String> {
+        let AddressType::HTTP { secure } = self.address_type else {
+            return None;
+        };
+
+        let authority = self
+            .location
+            .strip_prefix("//")?
+            .split(['/', '?', '#'])
+            .next()?;
+
+        match authority.is_empty() {
+            true => None,
+            false => Some(format!(
+                "{}://{}",
+                http_name(secure),
+                authority.to_lowercase()
+            )),
+        }
+    }
+
     pub fn read_string(&self) -> Result<String, AddressErr> {
+        Ok(self.read_string_offline(None)?.0)
+    }
+
+    // Also returns whether the string is a stored copy, as the server could not be reached. When
+    // read for a script, redirects are only followed to origins its permissions allow.
+    pub fn read_string_offline(
+        &self,
+        permissions: Option<&Permissions>,
+    ) -> Result<(String, bool), AddressErr> {
         match &self.address_type {
-            AddressType::Zonkey => zonkey_asset_read(&self.location),
+            AddressType::Zonkey => Ok((zonkey_asset_read(&self.location)?, false)),
             AddressType::File | AddressType::Installed => {
-                file_system_read(Path::new(&self.location))
+                Ok((file_system_read(Path::new(&self.location))?, false))
             }
             AddressType::HTTP { secure } => {
-                match network_read(http_name(*secure), &self.location)?.text() {
-                    Ok(script) => Ok(script),
-                    Err(e) => Err(AddressErr::NetworkFailure(e)),
-                }
+                let response = network_read(http_name(*secure), &self.location, permissions)?;
+                Ok((response.text(), response.offline))
             }
         }
     }
 
-    pub fn write_string(&self, string: String) -> Result<String, AddressErr> {
+    pub fn write_string(
+        &self,
+        string: String,
+        permissions: Option<&Permissions>,
+    ) -> Result<String, AddressErr> {
         match &self.address_type {
             AddressType::File | AddressType::Installed => {
                 match file_system_write(Path::new(&self.location), string) {
@@ -134,7 +215,7 @@ impl Address {
                 }
             }
             AddressType::HTTP { secure } => {
-                network_write(http_name(*secure), &self.location, string)
+                network_write(http_name(*secure), &self.location, string, permissions)
             }
             AddressType::Zonkey => Err(AddressErr::ZonkeyAssetError(
                 "Cannot overwrite internal zonkey assets".into(),
@@ -143,21 +224,27 @@ impl Address {
     }
 
     pub fn load_bytes(&self) -> Result<Vec<u8>, String> {
+        Ok(self.load_bytes_offline(None)?.0)
+    }
+
+    // Also returns whether the bytes are a stored copy, as the server could not be reached. When
+    // loaded for a script, redirects are only followed to origins its permissions allow.
+    pub fn load_bytes_offline(
+        &self,
+        permissions: Option<&Permissions>,
+    ) -> Result<(Vec<u8>, bool), String> {
         match &self.address_type {
             AddressType::Zonkey => match zonkey_asset_read_bytes(&self.location) {
-                Ok(bytes) => Ok(bytes),
+                Ok(bytes) => Ok((bytes, false)),
                 Err(e) => Err(e.to_string()),
             },
             AddressType::File | AddressType::Installed => match file_read_bytes(&self.location) {
-                Ok(bytes) => Ok(bytes),
+                Ok(bytes) => Ok((bytes, false)),
                 Err(e) => Err(e.to_string()),
             },
             AddressType::HTTP { secure } => {
-                match network_read(http_name(*secure), &self.location) {
-                    Ok(response) => match response.bytes() {
-                        Ok(data) => Ok(data.to_vec()),
-                        Err(e) => Err(e.to_string()),
-                    },
+                match network_read(http_name(*secure), &self.location, permissions) {
+                    Ok(response) => Ok((response.body, response.offline)),
                     Err(e) => Err(e.to_string()),
                 }
             }
@@ -177,6 +264,46 @@ impl fmt::Display for Address {
     }
 }
 
+// Whether a reference starts with a scheme such as 'https:' or 'zonkey:', so it does not depend on
+// the address of the script it was written in
+pub fn has_scheme(reference: &str) -> bool {
+    matches!(
+        reference.split_once(':'),
+        Some((scheme, _)) if !scheme.contains(['/', '.'])
+    )
+}
+
+// Joins a reference to the directory of a '/' separated path, removing '.' and '..' segments.
+// The query and fragment of the path are dropped.
+fn resolve_path(path: &str, reference: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    let mut segments: Vec<&str> = match reference.strip_prefix('/') {
+        Some(_) => vec![],
+        None => path.split('/').filter(|s| !s.is_empty()).collect(),
+    };
+
+    // The last segment is the name of the script rather than a directory
+    if !reference.starts_with('/') && !path.ends_with('/') {
+        segments.pop();
+    }
+
+    let reference_segments: Vec<&str> = reference.trim_start_matches('/').split('/').collect();
+
+    for (i, segment) in reference_segments.iter().enumerate() {
+        match *segment {
+            "." => (),
+            ".." => {
+                segments.pop();
+            }
+            "" if i + 1 < reference_segments.len() => (),
+            segment => segments.push(segment),
+        }
+    }
+
+    segments.join("/")
+}
+
 fn zonkey_asset_read(path: &str) -> Result<String, AddressErr> {
     match PROJECT_DIR.get_file(path) {
         Some(file) => match file.contents_utf8() {
@@ -226,30 +353,39 @@ fn file_read_bytes(location: &str) -> Result<Vec<u8>, AddressErr> {
     Ok(buffer)
 }
 
-pub fn network_read(protocol: &str, location: &str) -> Result<Response, AddressErr> {
-    match reqwest::blocking::get(protocol.to_string() + ":" + location) {
-        Ok(response) => match response.error_for_status() {
-            Ok(response) => Ok(response),
-            Err(e) => Err(AddressErr::NetworkFailure(e)),
-        },
-        Err(e) => Err(AddressErr::NetworkFailure(e)),
-    }
+pub fn network_read(
+    protocol: &str,
+    location: &str,
+    permissions: Option<&Permissions>,
+) -> Result<HttpResponse, AddressErr> {
+    let url = protocol.to_string() + ":" + location;
+
+    check_response(match HttpCache::new() {
+        Some(cache) => cache.get(&url, permissions),
+        None => {
+            let mut request = HttpRequest::new("GET", url);
+            request.permissions = permissions.cloned();
+            request.send()
+        }
+    })
 }
 
-pub fn network_write(protocol: &str, location: &str, string: String) -> Result<String, AddressErr> {
-    let client = reqwest::blocking::Client::new();
-    match client
-        .post(protocol.to_string() + ":" + location)
-        .body(string)
-        .send()
-    {
-        Ok(response) => match response.error_for_status() {
-            Ok(response) => match response.text() {
-                Ok(text) => Ok(text),
-                Err(e) => Err(AddressErr::NetworkFailure(e)),
-            },
-            Err(e) => Err(AddressErr::NetworkFailure(e)),
-        },
+pub fn network_write(
+    protocol: &str,
+    location: &str,
+    string: String,
+    permissions: Option<&Permissions>,
+) -> Result<String, AddressErr> {
+    let mut request = HttpRequest::new("POST", protocol.to_string() + ":" + location);
+    request.body = string;
+    request.permissions = permissions.cloned();
+    Ok(check_response(request.send())?.text())
+}
+
+fn check_response(response: Result<HttpResponse, String>) -> Result<HttpResponse, AddressErr> {
+    match response {
+        Ok(response) if response.success() => Ok(response),
+        Ok(response) => Err(AddressErr::UnsuccessfulStatus(response.status)),
         Err(e) => Err(AddressErr::NetworkFailure(e)),
     }
 }
@@ -286,6 +422,21 @@ mod tests {
         assert_eq!(address.address_type, AddressType::HTTP { secure: true });
     }
 
+    #[test]
+    fn origins() {
+        let address = Address::new("https://Example.com:8000/documents/test.zonk?a=1", vec![]);
+        assert_eq!(
+            address.origin().as_deref(),
+            Some("https://example.com:8000")
+        );
+
+        let address = Address::new("http://localhost", vec![]);
+        assert_eq!(address.origin().as_deref(), Some("http://localhost"));
+
+        let address = Address::new("file:/home/user/test.zonk", vec![]);
+        assert_eq!(address.origin(), None);
+    }
+
     #[test]
     fn file_addresses_ok() {
         let address = Address::new("file:/home/user/documents/scripts/test.zonk", vec![]);
@@ -306,4 +457,63 @@ mod tests {
         assert_eq!(address.location, "/home/user/documents/scripts/test.zonk");
         assert_eq!(address.address_type, AddressType::File);
     }
+
+    #[test]
+    fn relative_addresses_resolved() {
+        let page = Address::new("https://example.com/apps/notes/app.zonk?v=1", vec![]);
+        let resolve = |reference| page.resolve(reference, vec![]).to_string();
+        assert_eq!(
+            resolve("logo.png"),
+            "https://example.com/apps/notes/logo.png"
+        );
+        assert_eq!(
+            resolve("./images/logo.png"),
+            "https://example.com/apps/notes/images/logo.png"
+        );
+        assert_eq!(
+            resolve("../other.zonk"),
+            "https://example.com/apps/other.zonk"
+        );
+        assert_eq!(
+            resolve("../../../../top.zonk"),
+            "https://example.com/top.zonk"
+        );
+        assert_eq!(
+            resolve("/path/data.json"),
+            "https://example.com/path/data.json"
+        );
+        assert_eq!(
+            resolve("//cdn.example.com/a.png"),
+            "https://cdn.example.com/a.png"
+        );
+        assert_eq!(resolve("http://other.com/x"), "http://other.com/x");
+        assert_eq!(resolve("zonkey:home.zonk"), "zonkey:home.zonk");
+
+        let root = Address::new("http://localhost:8000", vec![]);
+        assert_eq!(
+            root.resolve("app.zonk", vec![]).to_string(),
+            "http://localhost:8000/app.zonk"
+        );
+
+        let asset = Address::new("zonkey:documentation/index.zonk", vec![]);
+        assert_eq!(
+            asset.resolve("../home.zonk", vec![]).to_string(),
+            "zonkey:home.zonk"
+        );
+
+        let file = Address::new("/home/user/scripts/app.zonk", vec![]);
+        assert_eq!(
+            file.resolve("images/logo.png", vec![]).to_string(),
+            "file:/home/user/scripts/images/logo.png"
+        );
+        assert_eq!(
+            file.resolve("/etc/hosts", vec![]).to_string(),
+            "file:/etc/hosts"
+        );
+
+        let arguments = vec![String::from("a")];
+        let address = Address::new("", vec![]).resolve("notes.txt", arguments.clone());
+        assert_eq!(address.location, "notes.txt");
+        assert_eq!(address.arguments, arguments);
+    }
 }
@@ -1,8 +1,25 @@
-use crate::stmt::Stmt;
+use crate::{
+    debug_symbols::DebugSymbols, input::Subscriptions, permission::Capability, stmt::Stmt,
+    token::Token,
+};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AST {
     pub start: Stmt,
     pub callable: Vec<Rc<Stmt>>,
+    #[serde(skip)]
+    pub debug_symbols: Option<DebugSymbols>,
+    pub tests: Vec<Test>,
+    // Declared with 'requires' so the user can be asked to allow them before the script starts
+    pub capabilities: Vec<Capability>,
+    pub subscriptions: Subscriptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Test {
+    pub name: Rc<String>,
+    pub token: Token,
+    pub block: Stmt,
 }
@@ -0,0 +1,62 @@
+// A read or write of an address which runs in the background, so the page keeps updating while
+// it is in flight. wait_for_event returns when it finishes.
+#[derive(Debug)]
+pub struct AsyncRequest {
+    pub write: bool,
+    // The string read or the response to the write, or the reason it failed
+    pub result: Option<Result<String, String>>,
+    // Set when the finished event is received, and cleared when the script checks it
+    pub finished: bool,
+}
+
+impl AsyncRequest {
+    pub fn new(write: bool) -> Self {
+        Self {
+            write,
+            result: None,
+            finished: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::run_test_script;
+
+    #[test]
+    fn requests_finish_as_events() {
+        let directory = std::env::temp_dir().join("zonkey_async_request_test");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("data.txt"), "stored").unwrap();
+
+        let source = format!(
+            "\
+start {{
+    let read = read_string_async(\"{0}/data.txt\");
+    let write = write_string_async(\"{0}/written.txt\", \"value\");
+    let missing = read_string_async(\"{0}/missing.txt\");
+
+    let remaining = 3;
+
+    while (remaining > 0 & wait_for_event()) {{
+        if (read.finished()) remaining -= 1;
+        if (write.finished()) remaining -= 1;
+        if (missing.finished()) remaining -= 1;
+    }}
+
+    assert(!read.finished());
+    assert_eq(read.result(), \"stored\");
+    assert_eq(write.result(), \"OK\");
+    assert_eq(read_string(\"{0}/written.txt\"), \"value\");
+    assert(missing.failed());
+    assert(!read.failed());
+}}
+",
+            directory.display()
+        );
+
+        run_test_script(&source);
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+}
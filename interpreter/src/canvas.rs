@@ -0,0 +1,157 @@
+// The shapes drawn on a canvas are recorded as a list of commands, which the browser draws in
+// order each time the page is shown. Each command keeps the colours that were set when it was
+// recorded, so changing them only affects the shapes drawn afterwards.
+pub type Colour = (u8, u8, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub fill: Option<Colour>,
+    pub stroke: Option<Colour>,
+    pub stroke_width: f32,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: Some((0, 0, 0)),
+            stroke: Some((0, 0, 0)),
+            stroke_width: 1.,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    // Lines are only stroked
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+        style: Style,
+    },
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        style: Style,
+    },
+    Circle {
+        x: f32,
+        y: f32,
+        radius: f32,
+        style: Style,
+    },
+    Path {
+        points: Vec<(f32, f32)>,
+        closed: bool,
+        style: Style,
+    },
+    // Text is drawn in the fill colour, with its top left corner at the position
+    Text {
+        x: f32,
+        y: f32,
+        content: String,
+        size: f32,
+        colour: Option<Colour>,
+    },
+}
+
+// Sent when the pointer is pressed, released or moved over a canvas, with its position relative to
+// the top left corner of the canvas
+#[derive(Debug, Clone, Copy)]
+pub enum Pointer {
+    Pressed(f32, f32),
+    Released(f32, f32),
+    Moved(f32, f32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DrawCommand, Style};
+    use crate::{element::ElementType, event::InterpreterEvent, tests::run_test_script};
+
+    #[test]
+    fn commands_recorded_as_display_list() {
+        let source = "\
+start {
+    let canvas = Canvas(200., 100.)
+        .line(0., 0., 200., 100.)
+        .set_fill(\"#ff0000\")
+        .set_stroke(\"\")
+        .rect(10., 20., 30., 40.)
+        .set_stroke(\"#0000ff\")
+        .set_stroke_width(2.5)
+        .circle(50., 50., 5.)
+        .path(Float[0., 0., 10., 0., 10., 10.], true)
+        .set_font_size(20.)
+        .text(5., 5., \"Score\");
+
+    set_page(Page().add(canvas));
+}
+";
+
+        let receiver = run_test_script(source);
+
+        let page = receiver
+            .iter()
+            .find_map(|event| match event {
+                InterpreterEvent::SetPage(page) => Some(page),
+                _ => None,
+            })
+            .unwrap();
+        let page = page.lock().unwrap();
+
+        let ElementType::Canvas(canvas) = &page.elements[0].1 else {
+            panic!("Expected the canvas to be added to the page");
+        };
+        let canvas = canvas.lock().unwrap();
+
+        let red = Style {
+            fill: Some((255, 0, 0)),
+            stroke: None,
+            stroke_width: 1.,
+        };
+        let blue = Style {
+            stroke: Some((0, 0, 255)),
+            stroke_width: 2.5,
+            ..red
+        };
+
+        assert_eq!((canvas.width, canvas.height), (200., 100.));
+        assert_eq!(
+            canvas.commands,
+            vec![
+                DrawCommand::Line {
+                    from: (0., 0.),
+                    to: (200., 100.),
+                    style: Style::default(),
+                },
+                DrawCommand::Rect {
+                    x: 10.,
+                    y: 20.,
+                    width: 30.,
+                    height: 40.,
+                    style: red,
+                },
+                DrawCommand::Circle {
+                    x: 50.,
+                    y: 50.,
+                    radius: 5.,
+                    style: blue,
+                },
+                DrawCommand::Path {
+                    points: vec![(0., 0.), (10., 0.), (10., 10.)],
+                    closed: true,
+                    style: blue,
+                },
+                DrawCommand::Text {
+                    x: 5.,
+                    y: 5.,
+                    content: String::from("Score"),
+                    size: 20.,
+                    colour: Some((255, 0, 0)),
+                },
+            ]
+        );
+    }
+}
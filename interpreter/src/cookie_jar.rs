@@ -0,0 +1,458 @@
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    net::IpAddr,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Cookies set by servers, which are sent back with later requests to the same site. They are
+// stored in the data directory, so sessions and logins last between browser sessions.
+#[derive(Default, Serialize, Deserialize)]
+pub struct CookieJar {
+    cookies: Vec<Cookie>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Cookie {
+    name: String,
+    value: String,
+    domain: String,
+    // Only sent to the host that set it, rather than to its subdomains as well
+    host_only: bool,
+    path: String,
+    // Seconds since the unix epoch, where cookies without an expiry are kept until cleared
+    expires: Option<u64>,
+    secure: bool,
+    // Sent to the server, but hidden from scripts
+    http_only: bool,
+}
+
+// The jar used by every request, loaded from the data directory when it is first needed
+pub fn shared() -> &'static Mutex<CookieJar> {
+    static JAR: OnceLock<Mutex<CookieJar>> = OnceLock::new();
+    JAR.get_or_init(|| Mutex::new(CookieJar::load(shared_path())))
+}
+
+#[cfg(not(test))]
+fn shared_path() -> Option<PathBuf> {
+    directories_next::ProjectDirs::from("rocks.sambowden", "", "zonkey")
+        .map(|proj_dirs| proj_dirs.data_dir().join("cookies.json"))
+}
+
+// Tests keep their cookies away from those of the user
+#[cfg(test)]
+fn shared_path() -> Option<PathBuf> {
+    Some(std::env::temp_dir().join(format!("zonkey_cookies_{}.json", std::process::id())))
+}
+
+impl CookieJar {
+    // Cookies are saved back to the path whenever they change, and a jar without a path only
+    // lasts for this session
+    fn load(path: Option<PathBuf>) -> Self {
+        let mut jar: Self = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        jar.path = path;
+        jar
+    }
+
+    // The value of the Cookie header to send with a request to the url
+    pub fn header(&mut self, url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+
+        let cookies = self
+            .matching(&url)
+            .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+            .collect::<Vec<_>>();
+
+        match cookies.is_empty() {
+            true => None,
+            false => Some(cookies.join("; ")),
+        }
+    }
+
+    // Stores the cookies from the Set-Cookie headers of a response to the url, where those which
+    // have already expired remove the cookie they replace
+    pub fn store<'a>(&mut self, url: &str, headers: impl Iterator<Item = &'a str>) {
+        let Ok(url) = Url::parse(url) else {
+            return;
+        };
+
+        let mut changed = false;
+
+        for header in headers {
+            let Some(cookie) = Cookie::parse(header, &url) else {
+                continue;
+            };
+
+            self.cookies.retain(|existing| {
+                existing.name != cookie.name
+                    || existing.domain != cookie.domain
+                    || existing.path != cookie.path
+            });
+
+            if !cookie.expired() {
+                self.cookies.push(cookie);
+            }
+
+            changed = true;
+        }
+
+        if changed {
+            self.save();
+        }
+    }
+
+    // The names and values of the cookies sent to the url that scripts are allowed to see
+    pub fn visible(&mut self, url: &str) -> Vec<(String, String)> {
+        let Ok(url) = Url::parse(url) else {
+            return vec![];
+        };
+
+        self.matching(&url)
+            .filter(|cookie| !cookie.http_only)
+            .map(|cookie| (cookie.name.clone(), cookie.value.clone()))
+            .collect()
+    }
+
+    // Removes every cookie that would be sent to the host of the url, whatever its path
+    pub fn clear(&mut self, url: &str) {
+        let Some(host) = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_lowercase()))
+        else {
+            return;
+        };
+
+        self.cookies.retain(|cookie| !cookie.domain_matches(&host));
+        self.save();
+    }
+
+    fn matching<'a>(&'a mut self, url: &Url) -> impl Iterator<Item = &'a Cookie> {
+        self.cookies.retain(|cookie| !cookie.expired());
+
+        let host = url.host_str().unwrap_or_default().to_lowercase();
+        let path = url.path().to_string();
+        let secure = url.scheme() == "https";
+
+        self.cookies.iter().filter(move |cookie| {
+            cookie.domain_matches(&host) && cookie.path_matches(&path) && (secure || !cookie.secure)
+        })
+    }
+
+    // Failing to save only means the cookies will not last beyond this session
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).ok();
+        }
+
+        if let Ok(contents) = serde_json::to_string(self) {
+            fs::write(path, contents).ok();
+        }
+    }
+}
+
+impl Cookie {
+    fn parse(header: &str, url: &Url) -> Option<Self> {
+        let mut parts = header.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let host = url.host_str()?.to_lowercase();
+
+        let mut cookie = Self {
+            name: name.trim().to_string(),
+            value: value.trim().to_string(),
+            domain: host.clone(),
+            host_only: true,
+            path: default_path(url.path()),
+            expires: None,
+            secure: false,
+            http_only: false,
+        };
+
+        if cookie.name.is_empty() {
+            return None;
+        }
+
+        let mut max_age = None;
+
+        for attribute in parts {
+            let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            let value = value.trim();
+
+            match key.trim().to_lowercase().as_str() {
+                "domain" => {
+                    let domain = value.trim_start_matches('.').to_lowercase();
+
+                    let address = host
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .parse::<IpAddr>()
+                        .is_ok();
+
+                    match domain.as_str() {
+                        "" => (),
+                        // A cookie set by an address such as 127.0.0.1 is only sent back to it
+                        _ if address && domain == host => (),
+                        // Cookies cannot be shared with every site under a top level domain such
+                        // as com, or with other addresses
+                        _ if address || (!domain.contains('.') && domain != host) => return None,
+                        _ => {
+                            cookie.domain = domain;
+                            cookie.host_only = false;
+                        }
+                    }
+                }
+                "path" if value.starts_with('/') => cookie.path = value.to_string(),
+                "max-age" => max_age = value.parse::<i64>().ok(),
+                "expires" => {
+                    cookie.expires = httpdate::parse_http_date(value)
+                        .ok()
+                        .map(|date| seconds_since_epoch(date).unwrap_or_default());
+                }
+                "secure" => cookie.secure = true,
+                "httponly" => cookie.http_only = true,
+                _ => (),
+            }
+        }
+
+        // Max-Age takes priority over Expires, and zero or less expires the cookie immediately
+        if let Some(max_age) = max_age {
+            cookie.expires = Some(match u64::try_from(max_age) {
+                Ok(max_age) if max_age > 0 => now().saturating_add(max_age),
+                _ => 0,
+            });
+        }
+
+        // A server cannot set cookies for a site other than its own
+        match cookie.domain_matches(&host) {
+            true => Some(cookie),
+            false => None,
+        }
+    }
+
+    fn expired(&self) -> bool {
+        self.expires.is_some_and(|expires| expires <= now())
+    }
+
+    fn domain_matches(&self, host: &str) -> bool {
+        match self.host_only {
+            true => host == self.domain,
+            false => {
+                host == self.domain
+                    || host
+                        .strip_suffix(&self.domain)
+                        .is_some_and(|subdomain| subdomain.ends_with('.'))
+            }
+        }
+    }
+
+    fn path_matches(&self, path: &str) -> bool {
+        path == self.path
+            || path
+                .strip_prefix(&self.path)
+                .is_some_and(|rest| self.path.ends_with('/') || rest.starts_with('/'))
+    }
+}
+
+// Cookies without a path are sent to the directory of the address that set them
+fn default_path(path: &str) -> String {
+    match path.rfind('/') {
+        Some(0) | None => String::from("/"),
+        Some(end) => path[..end].to_string(),
+    }
+}
+
+fn seconds_since_epoch(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|duration| duration.as_secs())
+}
+
+fn now() -> u64 {
+    seconds_since_epoch(SystemTime::now()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CookieJar;
+    use crate::tests::run_test_script;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    #[test]
+    fn cookies_sent_to_matching_addresses() {
+        let mut jar = CookieJar::default();
+
+        jar.store(
+            "https://example.com/account/login",
+            [
+                "session=abc; HttpOnly; Secure",
+                "theme=dark; Domain=.Example.com; Path=/; Max-Age=3600",
+                "draft=1",
+                "tracker=x; Domain=other.com",
+                "everywhere=1; Domain=com",
+                "own=1; Domain=example.com; Path=/",
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(
+            jar.header("https://example.com/account/settings")
+                .as_deref(),
+            Some("session=abc; theme=dark; draft=1; own=1")
+        );
+        assert_eq!(
+            jar.header("http://www.example.com/").as_deref(),
+            Some("theme=dark; own=1")
+        );
+        assert_eq!(jar.header("https://notexample.com/"), None);
+
+        // Addresses cannot set cookies for other addresses, but a domain naming the host itself
+        // only sends the cookie back to that host
+        jar.store(
+            "http://192.168.1.10/",
+            [
+                "plain=1",
+                "shared=1; Domain=168.1.10",
+                "local=1; Domain=192.168.1.10",
+            ]
+            .into_iter(),
+        );
+        assert_eq!(
+            jar.header("http://192.168.1.10/").as_deref(),
+            Some("plain=1; local=1")
+        );
+        assert_eq!(jar.header("http://10.168.1.10/"), None);
+
+        // HttpOnly cookies are only sent to the server
+        assert_eq!(
+            jar.visible("https://example.com/account/"),
+            vec![
+                (String::from("theme"), String::from("dark")),
+                (String::from("draft"), String::from("1")),
+                (String::from("own"), String::from("1"))
+            ]
+        );
+
+        jar.store(
+            "https://example.com/",
+            [
+                "theme=; Max-Age=0",
+                "draft=; Path=/account; Expires=Thu, 01 Jan 1970 00:00:00 GMT",
+            ]
+            .into_iter(),
+        );
+        assert_eq!(
+            jar.header("https://example.com/account/").as_deref(),
+            Some("session=abc; own=1")
+        );
+
+        jar.clear("https://example.com/");
+        assert_eq!(jar.header("https://example.com/account/"), None);
+    }
+
+    #[test]
+    fn sessions_kept_between_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        // Stand-in server which sets cookies while redirecting after a login, and then sends back
+        // the method and cookies of each request it receives
+        std::thread::spawn(move || {
+            for response in [
+                "302 Found\r\nSet-Cookie: user=sam\r\nSet-Cookie: token=secret; HttpOnly\r\nLocation: /home\r\n",
+                "200 OK\r\n",
+                "200 OK\r\n",
+            ] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let method = request_line.split_whitespace().next().unwrap().to_string();
+                let mut cookies = String::new();
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line.trim().is_empty() {
+                        break;
+                    }
+
+                    if let Some(value) = line.strip_prefix("cookie: ") {
+                        cookies = value.trim().to_string();
+                    }
+                }
+
+                let content = format!("{method} {cookies}");
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {response}Content-Length: {}\r\nConnection: close\r\n\r\n{content}",
+                    content.len()
+                )
+                .unwrap();
+            }
+        });
+
+        let source = format!(
+            "\
+start {{
+    let home = HttpRequest(\"POST\", \"{address}/login\").send();
+    assert_eq(home.status(), 200);
+    assert_eq(home.body(), \"GET user=sam; token=secret\");
+
+    assert_eq(get_cookie(\"{address}\", \"user\"), \"sam\");
+    assert_eq(get_cookie(\"{address}\", \"token\"), \"\");
+    assert_eq(HttpRequest(\"GET\", \"{address}/account\").send().body(), \"GET user=sam; token=secret\");
+
+    clear_cookies(\"{address}\");
+    assert_eq(cookie_names(\"{address}\").len(), 0);
+}}
+"
+        );
+
+        run_test_script(&source);
+
+        // The shared jar of the tests is kept in a temporary file
+        std::fs::remove_file(super::shared_path().unwrap()).ok();
+    }
+
+    #[test]
+    fn saved_to_its_path() {
+        let path = std::env::temp_dir().join("zonkey_cookie_jar_test.json");
+        std::fs::remove_file(&path).ok();
+
+        let mut jar = CookieJar::load(Some(path.clone()));
+        jar.store(
+            "https://example.com/",
+            ["session=abc; Max-Age=3600", "temporary=1"].into_iter(),
+        );
+
+        let mut loaded = CookieJar::load(Some(path.clone()));
+        assert_eq!(
+            loaded.header("https://example.com/").as_deref(),
+            Some("session=abc; temporary=1")
+        );
+
+        loaded.clear("https://example.com/");
+        assert!(CookieJar::load(Some(path.clone())).cookies.is_empty());
+
+        std::fs::remove_file(path).ok();
+    }
+}
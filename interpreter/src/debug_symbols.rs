@@ -0,0 +1,166 @@
+use crate::parser::location::Location;
+use rustc_hash::FxHashMap;
+use std::rc::Rc;
+
+// Emitted by the parser when requested, so that tools such as the debugger can map the slots
+// assigned to variables in the state back to the names used in the source code.
+#[derive(Debug, Default)]
+pub struct DebugSymbols {
+    pub variables: Vec<VariableSymbol>,
+    pub classes: FxHashMap<Rc<String>, Vec<(Rc<String>, Location)>>,
+    // Positions of traced statements and of those which branch on a condition
+    pub statements: Vec<usize>,
+    pub branches: Vec<usize>,
+    callable_names: FxHashMap<usize, String>,
+    current_callable: Option<usize>,
+    open_variables: Vec<usize>,
+    line_starts: Vec<usize>,
+    source_lines: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct VariableSymbol {
+    pub name: Rc<String>,
+    pub location: Location,
+    // None when the variable belongs to the start block
+    pub callable: Option<usize>,
+    pub declared: usize,
+    pub scope_end: usize,
+}
+
+impl DebugSymbols {
+    pub fn begin_callable(&mut self, callable: Option<usize>, name: String) {
+        if let Some(id) = callable {
+            self.callable_names.insert(id, name);
+        }
+        self.current_callable = callable;
+    }
+
+    pub fn add_variable(&mut self, name: Rc<String>, location: Location, declared: usize) {
+        self.open_variables.push(self.variables.len());
+        self.variables.push(VariableSymbol {
+            name,
+            location,
+            callable: self.current_callable,
+            declared,
+            scope_end: usize::MAX,
+        });
+    }
+
+    // Closes every variable declared since the scope was opened
+    pub fn close_scope(&mut self, opened: usize, end: usize) {
+        while let Some(index) = self.open_variables.last() {
+            let variable = &mut self.variables[*index];
+
+            if variable.declared < opened {
+                break;
+            }
+
+            variable.scope_end = end;
+            self.open_variables.pop();
+        }
+    }
+
+    pub fn close_callable(&mut self, end: usize) {
+        self.close_scope(0, end);
+        self.current_callable = None;
+    }
+
+    pub fn add_class(&mut self, name: Rc<String>, properties: &FxHashMap<Rc<String>, Location>) {
+        let mut properties = properties
+            .iter()
+            .map(|(name, location)| (Rc::clone(name), location.clone()))
+            .collect::<Vec<_>>();
+        properties.sort_by(|a, b| a.0.cmp(&b.0));
+        self.classes.insert(name, properties);
+    }
+
+    pub fn add_statement(&mut self, position: usize, branches: bool) {
+        self.statements.push(position);
+
+        if branches {
+            self.branches.push(position);
+        }
+    }
+
+    pub fn callable_name(&self, callable: Option<usize>) -> &str {
+        match callable {
+            Some(id) => match self.callable_names.get(&id) {
+                Some(name) => name,
+                None => "<unknown>",
+            },
+            None => "start",
+        }
+    }
+
+    // Returns the innermost variable with the name that is in scope at the position
+    pub fn find_variable(
+        &self,
+        callable: Option<usize>,
+        position: usize,
+        name: &str,
+    ) -> Option<&VariableSymbol> {
+        self.visible_variables(callable, position)
+            .into_iter()
+            .find(|variable| variable.name.as_str() == name)
+    }
+
+    // Variables in scope at the position, innermost first and without shadowed names
+    pub fn visible_variables(
+        &self,
+        callable: Option<usize>,
+        position: usize,
+    ) -> Vec<&VariableSymbol> {
+        let mut visible: Vec<&VariableSymbol> = vec![];
+
+        for variable in self.variables.iter().rev() {
+            if variable.callable == callable
+                && variable.declared < position
+                && position < variable.scope_end
+                && !visible.iter().any(|v| v.name == variable.name)
+            {
+                visible.push(variable);
+            }
+        }
+
+        visible
+    }
+
+    pub fn load_source(&mut self, graphemes: &[&str]) {
+        self.line_starts = vec![0];
+        self.source_lines = vec![];
+
+        let mut line = String::new();
+
+        for (i, grapheme) in graphemes.iter().enumerate() {
+            match *grapheme {
+                "\n" | "\r\n" | "\r" => {
+                    self.line_starts.push(i + 1);
+                    self.source_lines.push(std::mem::take(&mut line));
+                }
+                grapheme => line.push_str(grapheme),
+            }
+        }
+
+        if !line.is_empty() {
+            self.source_lines.push(line);
+        }
+    }
+
+    // Line numbers start at 1 to match error messages
+    pub fn line(&self, position: usize) -> usize {
+        self.line_starts
+            .partition_point(|line_start| *line_start <= position)
+            .max(1)
+    }
+
+    pub fn source_line(&self, line: usize) -> Option<&str> {
+        self.source_lines
+            .get(line.wrapping_sub(1))
+            .map(|line| line.as_str())
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.source_lines.len()
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fs, path::Path, time::SystemTime};
+
+// Caches in the data directory are kept under a maximum total size by removing the entries that
+// were written longest ago. Failing to remove an entry only means the cache stays larger for now.
+pub fn prune(directory: &Path, max_size: u64) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    let mut files = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            Some((modified, metadata.len(), entry.path()))
+        })
+        .collect::<Vec<_>>();
+
+    let mut size = files.iter().map(|(_, len, _)| len).sum::<u64>();
+
+    if size <= max_size {
+        return;
+    }
+
+    files.sort_by_key(|(modified, _, _)| *modified);
+
+    for (_, len, path) in files {
+        if size <= max_size {
+            break;
+        }
+
+        if fs::remove_file(path).is_ok() {
+            size -= len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prune;
+    use std::{fs, thread, time::Duration};
+
+    #[test]
+    fn oldest_entries_removed_first() {
+        let directory = std::env::temp_dir().join("zonkey_disk_cache_test");
+        fs::remove_dir_all(&directory).ok();
+        fs::create_dir_all(&directory).unwrap();
+
+        for name in ["first", "second", "third"] {
+            fs::write(directory.join(name), [0; 10]).unwrap();
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        prune(&directory, 30);
+        assert!(directory.join("first").exists());
+
+        prune(&directory, 25);
+        assert!(!directory.join("first").exists());
+        assert!(directory.join("second").exists());
+        assert!(directory.join("third").exists());
+
+        prune(&directory, 0);
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 0);
+
+        fs::remove_dir_all(&directory).ok();
+    }
+}
@@ -1,6 +1,10 @@
+use crate::{
+    canvas::{self, Colour, DrawCommand},
+    style::Style,
+};
 use iced::{
     widget::{button, container},
-    Color,
+    Alignment, Color, Length,
 };
 use std::sync::{Arc, Mutex};
 
@@ -13,28 +17,36 @@ pub enum ElementType {
     Row(Arc<Mutex<Row>>),
     Column(Arc<Mutex<Column>>),
     Image(Arc<Mutex<Image>>),
+    Checkbox(Arc<Mutex<Checkbox>>),
+    Toggle(Arc<Mutex<Toggle>>),
+    RadioGroup(Arc<Mutex<RadioGroup>>),
+    Slider(Arc<Mutex<Slider>>),
+    Dropdown(Arc<Mutex<Dropdown>>),
+    TextArea(Arc<Mutex<TextArea>>),
+    Table(Arc<Mutex<Table>>),
+    Canvas(Arc<Mutex<Canvas>>),
+    Scroll(Arc<Mutex<Scroll>>),
+    Stack(Arc<Mutex<Stack>>),
 }
 
 #[derive(Debug, Clone)]
 pub struct Page {
     pub id: u64,
     pub elements: Vec<(u64, ElementType)>,
-    pub bg_red: u8,
-    pub bg_green: u8,
-    pub bg_blue: u8,
-    pub txt_red: u8,
-    pub txt_green: u8,
-    pub txt_blue: u8,
+    pub background_colour: Option<Colour>,
+    pub text_colour: Option<Colour>,
     pub title: String,
-    pub center: bool,
     pub max_width: Option<f32>,
+    pub layout: Layout,
+    pub style: Option<Arc<Mutex<Style>>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Row {
     pub id: u64,
     pub elements: Vec<(u64, ElementType)>,
-    pub center: bool,
+    pub layout: Layout,
+    pub style: Option<Arc<Mutex<Style>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,14 +54,67 @@ pub struct Column {
     pub id: u64,
     pub elements: Vec<(u64, ElementType)>,
     pub max_width: Option<f32>,
+    pub layout: Layout,
+    pub style: Option<Arc<Mutex<Style>>>,
+}
+
+// A column of elements with a fixed height, which scrolls when they do not fit
+#[derive(Debug, Clone)]
+pub struct Scroll {
+    pub id: u64,
+    pub elements: Vec<(u64, ElementType)>,
+    pub height: f32,
+}
+
+// Elements drawn on top of each other, where the last added is on top
+#[derive(Debug, Clone)]
+pub struct Stack {
+    pub id: u64,
+    pub elements: Vec<(u64, ElementType)>,
+}
+
+// Styling shared by the elements that lay out other elements. Values which are not set keep the
+// defaults of each element. For a page it applies to the block holding its elements, apart from
+// the background colour which is set on the page itself.
+#[derive(Debug, Clone, Default)]
+pub struct Layout {
+    // Vertical and horizontal
+    pub padding: Option<(f32, f32)>,
+    pub spacing: Option<f32>,
+    pub horizontal_alignment: Option<Alignment>,
+    pub vertical_alignment: Option<Alignment>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub background_colour: Option<Colour>,
+    pub border_colour: Option<Colour>,
+    pub border_width: f32,
+    pub border_radius: f32,
 }
 
 #[derive(Debug, Clone)]
 pub struct Text {
     pub id: u64,
     pub value: String,
-    pub size: f32,
+    pub size: Option<f32>,
     pub colour: Option<(u8, u8, u8)>,
+    pub style: Option<Arc<Mutex<Style>>>,
+    pub bold: bool,
+    pub italic: bool,
+    // One of the fonts bundled with the browser, or its default font when not set
+    pub font_family: Option<FontFamily>,
+    // Within the width available to the text
+    pub alignment: Option<Alignment>,
+    // The space between lines as a multiple of the size, or the spacing of the font when not set
+    pub line_height: Option<f32>,
+    // Text which is not wrapped is cut off at the edge of the space available to it
+    pub wrap: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontFamily {
+    Sans,
+    Serif,
+    Monospace,
 }
 
 #[derive(Debug, Clone)]
@@ -57,15 +122,12 @@ pub struct Button {
     pub id: u64,
     pub text: String,
     pub clicked: bool,
-    pub bg_red: u8,
-    pub bg_green: u8,
-    pub bg_blue: u8,
-    pub txt_red: u8,
-    pub txt_green: u8,
-    pub txt_blue: u8,
-    pub vertical_padding: f32,
-    pub horizontal_padding: f32,
+    pub background_colour: Option<Colour>,
+    pub text_colour: Option<Colour>,
+    // Vertical and horizontal
+    pub padding: Option<(f32, f32)>,
     pub width_fill: bool,
+    pub style: Option<Arc<Mutex<Style>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -89,6 +151,200 @@ pub struct Image {
     pub data: Option<Vec<u8>>,
     pub id: u64,
     pub max_width: Option<f32>,
+    // Set when the image has loaded, and cleared when the script checks it
+    pub loaded: bool,
+}
+
+// The changed flags of the elements below are set when the user changes their value, and cleared
+// when the script checks them
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    pub id: u64,
+    pub label: String,
+    pub checked: bool,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Toggle {
+    pub id: u64,
+    pub label: String,
+    pub on: bool,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    pub id: u64,
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Slider {
+    pub id: u64,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub value: f64,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Dropdown {
+    pub id: u64,
+    pub options: Vec<String>,
+    pub selected: Option<String>,
+    pub placeholder: String,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    pub id: u64,
+    pub placeholder: String,
+    pub text: String,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub id: u64,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    // Widths in pixels, where columns without a width share the remaining space
+    pub column_widths: Vec<Option<f32>>,
+    // The column the rows are sorted by, and whether they are in ascending order
+    pub sort: Option<(usize, bool)>,
+    pub selected: Option<usize>,
+    pub sort_changed: bool,
+    pub selection_changed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    pub id: u64,
+    pub width: f32,
+    pub height: f32,
+    pub commands: Vec<DrawCommand>,
+    // Used by the shapes and text drawn after they are set
+    pub style: canvas::Style,
+    pub font_size: f32,
+    pub pointer_x: f32,
+    pub pointer_y: f32,
+    pub pointer_down: bool,
+    // Set when the pointer is pressed, released or moved over the canvas, and cleared when the
+    // script checks them
+    pub pointer_pressed: bool,
+    pub pointer_released: bool,
+    pub pointer_moved: bool,
+}
+
+impl Table {
+    // Cells which are both numbers are compared by value, and any others alphabetically. The
+    // selected row stays selected after it is moved.
+    pub fn sort(&mut self, column: usize, ascending: bool) {
+        let cell = |row: &Vec<String>| row.get(column).cloned().unwrap_or_default();
+
+        let mut order = (0..self.rows.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| {
+            let (a, b) = (cell(&self.rows[*a]), cell(&self.rows[*b]));
+
+            let ordering = match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+                (Ok(a), Ok(b)) => a.total_cmp(&b),
+                _ => a.to_lowercase().cmp(&b.to_lowercase()),
+            };
+
+            match ascending {
+                true => ordering,
+                false => ordering.reverse(),
+            }
+        });
+
+        let mut rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        self.rows = order
+            .iter()
+            .filter_map(|index| rows[*index].take())
+            .collect();
+
+        self.selected = self
+            .selected
+            .and_then(|selected| order.iter().position(|index| *index == selected));
+        self.sort = Some((column, ascending));
+    }
+}
+
+// Styling set on an element takes priority over its style, which is inherited from the elements
+// it is inside. What is still unset when the page is drawn falls back to these defaults.
+pub const TEXT_SIZE: f32 = 20.;
+const PAGE_BACKGROUND_COLOUR: Colour = (255, 255, 255);
+const PAGE_TEXT_COLOUR: Colour = (0, 0, 0);
+const BUTTON_COLOUR: Colour = (127, 127, 127);
+const BUTTON_TEXT_COLOUR: Colour = (255, 255, 255);
+pub const BUTTON_PADDING: (f32, f32) = (10., 10.);
+
+fn colour((red, green, blue): Colour) -> Color {
+    Color::from_rgb8(red, green, blue)
+}
+
+// The style set on an element, with what it does not set inherited from its parent
+pub fn cascade(style: &Option<Arc<Mutex<Style>>>, parent: &Style) -> Style {
+    match style {
+        Some(style) => style.lock().unwrap().cascade(parent),
+        None => Style::default().cascade(parent),
+    }
+}
+
+impl Page {
+    // The page with its style applied, and the style inherited by its elements
+    pub fn styled(&self) -> (Page, Style) {
+        let mut style = cascade(&self.style, &Style::default());
+        style.text_colour = self.text_colour.or(style.text_colour);
+
+        let page = Page {
+            background_colour: self.background_colour.or(style.background_colour),
+            text_colour: style.text_colour,
+            layout: self.layout.styled(&style),
+            ..self.clone()
+        };
+
+        (page, style)
+    }
+}
+
+impl Layout {
+    pub fn styled(&self, style: &Style) -> Layout {
+        Layout {
+            padding: self.padding.or(style.padding),
+            background_colour: self.background_colour.or(style.background_colour),
+            ..self.clone()
+        }
+    }
+}
+
+impl Text {
+    pub fn styled(&self, style: &Style) -> Text {
+        Text {
+            size: self.size.or(style.text_size),
+            colour: self.colour.or(style.text_colour),
+            ..self.clone()
+        }
+    }
+}
+
+impl Button {
+    pub fn styled(&self, style: &Style) -> Button {
+        Button {
+            background_colour: self.background_colour.or(style.button_colour),
+            text_colour: self.text_colour.or(style.button_text_colour),
+            padding: self.padding.or(style.button_padding),
+            ..self.clone()
+        }
+    }
 }
 
 impl container::StyleSheet for Page {
@@ -96,16 +352,26 @@ impl container::StyleSheet for Page {
 
     fn appearance(&self, _: &Self::Style) -> container::Appearance {
         container::Appearance {
-            background: Some(iced::Background::Color(Color::from_rgb8(
-                self.bg_red,
-                self.bg_green,
-                self.bg_blue,
+            background: Some(iced::Background::Color(colour(
+                self.background_colour.unwrap_or(PAGE_BACKGROUND_COLOUR),
             ))),
-            text_color: Some(Color::from_rgb8(
-                self.txt_red,
-                self.txt_green,
-                self.txt_blue,
-            )),
+            text_color: Some(colour(self.text_colour.unwrap_or(PAGE_TEXT_COLOUR))),
+            ..Default::default()
+        }
+    }
+}
+
+impl container::StyleSheet for Layout {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: self
+                .background_colour
+                .map(|background| iced::Background::Color(colour(background))),
+            border_color: self.border_colour.map(colour).unwrap_or(Color::TRANSPARENT),
+            border_width: self.border_width,
+            border_radius: self.border_radius,
             ..Default::default()
         }
     }
@@ -116,23 +382,23 @@ impl button::StyleSheet for Button {
 
     fn active(&self, _: &Self::Style) -> button::Appearance {
         button::Appearance {
-            background: Some(iced::Background::Color(Color::from_rgb8(
-                self.bg_red,
-                self.bg_green,
-                self.bg_blue,
+            background: Some(iced::Background::Color(colour(
+                self.background_colour.unwrap_or(BUTTON_COLOUR),
             ))),
-            text_color: Color::from_rgb8(self.txt_red, self.txt_green, self.txt_blue),
+            text_color: colour(self.text_colour.unwrap_or(BUTTON_TEXT_COLOUR)),
             ..Default::default()
         }
     }
     fn hovered(&self, _: &Self::Style) -> button::Appearance {
+        let (red, green, blue) = self.background_colour.unwrap_or(BUTTON_COLOUR);
+
         button::Appearance {
-            background: Some(iced::Background::Color(Color::from_rgb8(
-                self.bg_red.saturating_add(10),
-                self.bg_green.saturating_add(10),
-                self.bg_blue.saturating_add(10),
-            ))),
-            text_color: Color::from_rgb8(self.txt_red, self.txt_green, self.txt_blue),
+            background: Some(iced::Background::Color(colour((
+                red.saturating_add(10),
+                green.saturating_add(10),
+                blue.saturating_add(10),
+            )))),
+            text_color: colour(self.text_colour.unwrap_or(BUTTON_TEXT_COLOUR)),
             ..Default::default()
         }
     }
@@ -150,3 +416,238 @@ impl button::StyleSheet for Hyperlink {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{cascade, ElementType, FontFamily, Layout};
+    use crate::{event::InterpreterEvent, tests::run_test_script};
+    use iced::{Alignment, Length};
+
+    #[test]
+    fn layout_styling_recorded() {
+        let source = "\
+start {
+    let row = Row()
+        .set_padding(5., 10.)
+        .set_spacing(15.)
+        .set_vertical_alignment(\"end\")
+        .set_width(300.)
+        .set_height_fill()
+        .set_background_colour(\"#ff0000\")
+        .set_border_colour(\"#00ff00\")
+        .set_border_width(2.)
+        .set_border_radius(-4.)
+        .center();
+
+    let column = Column().set_horizontal_alignment(\"center\").set_width_fill();
+
+    set_page(Page()
+        .set_horizontal_alignment(\"end\")
+        .set_height(400.)
+        .add(row)
+        .add(column));
+}
+";
+
+        let receiver = run_test_script(source);
+
+        let page = receiver
+            .iter()
+            .find_map(|event| match event {
+                InterpreterEvent::SetPage(page) => Some(page),
+                _ => None,
+            })
+            .unwrap();
+        let page = page.lock().unwrap();
+
+        assert_eq!(page.layout.horizontal_alignment, Some(Alignment::End));
+        assert_eq!(page.layout.height, Some(Length::Fixed(400.)));
+
+        let (ElementType::Row(row), ElementType::Column(column)) =
+            (&page.elements[0].1, &page.elements[1].1)
+        else {
+            panic!("Expected the row and column to be added to the page");
+        };
+
+        let row = row.lock().unwrap().layout.clone();
+
+        assert_eq!(row.padding, Some((5., 10.)));
+        assert_eq!(row.spacing, Some(15.));
+        assert_eq!(row.horizontal_alignment, Some(Alignment::Center));
+        assert_eq!(row.vertical_alignment, Some(Alignment::End));
+        assert_eq!(row.width, Some(Length::Fixed(300.)));
+        assert_eq!(row.height, Some(Length::Fill));
+        assert_eq!(row.background_colour, Some((255, 0, 0)));
+        assert_eq!(row.border_colour, Some((0, 255, 0)));
+        assert_eq!(row.border_width, 2.);
+        // Negative sizes are treated as 0
+        assert_eq!(row.border_radius, 0.);
+
+        let column = column.lock().unwrap();
+
+        assert_eq!(column.layout.horizontal_alignment, Some(Alignment::Center));
+        assert_eq!(column.layout.width, Some(Length::Fill));
+        assert_eq!(column.layout.padding, Layout::default().padding);
+    }
+
+    #[test]
+    fn scroll_and_stack_hold_elements() {
+        let source = "\
+start {
+    let text = Text(\"Hidden\");
+
+    let scroll = Scroll(-10.)
+        .set_height(150.)
+        .add(Text(\"First\"))
+        .add(text)
+        .remove(text);
+
+    let stack = Stack().add(Canvas(100., 100.)).add(Column().add(Text(\"Over\")));
+
+    set_page(Page().add(scroll).add(Row().add(stack)));
+}
+";
+
+        let receiver = run_test_script(source);
+
+        let page = receiver
+            .iter()
+            .find_map(|event| match event {
+                InterpreterEvent::SetPage(page) => Some(page),
+                _ => None,
+            })
+            .unwrap();
+        let page = page.lock().unwrap();
+
+        let (ElementType::Scroll(scroll), ElementType::Row(row)) =
+            (&page.elements[0].1, &page.elements[1].1)
+        else {
+            panic!("Expected the scroll and row to be added to the page");
+        };
+
+        let scroll = scroll.lock().unwrap();
+
+        assert_eq!(scroll.height, 150.);
+        assert_eq!(scroll.elements.len(), 1);
+
+        let ElementType::Stack(stack) = &row.lock().unwrap().elements[0].1 else {
+            panic!("Expected the stack to be added to the row");
+        };
+
+        let stack = stack.lock().unwrap();
+
+        assert!(matches!(
+            stack.elements.as_slice(),
+            [(_, ElementType::Canvas(_)), (_, ElementType::Column(_))]
+        ));
+    }
+
+    #[test]
+    fn style_cascades_to_child_elements() {
+        let source = "\
+start {
+    let page_style = Style()
+        .set_text_size(30.)
+        .set_button_colour(palette_colour(\"primary\"))
+        .set_background_colour(\"#ff0000\")
+        .set_padding(5., 5.);
+    let row_style = Style().set_text_colour(\"#00ff00\");
+
+    let text = Text(\"Hello\").set_style(row_style).set_size(12.);
+    let button = Button(\"Click\").set_text_colour(\"#0000ff\");
+
+    set_page(Page()
+        .set_style(page_style)
+        .add(Row().set_style(row_style).add(text).add(button)));
+
+    # Changing a style restyles every element it is set on
+    row_style.set_button_padding(1., 2.);
+}
+";
+
+        let receiver = run_test_script(source);
+
+        let page = receiver
+            .iter()
+            .find_map(|event| match event {
+                InterpreterEvent::SetPage(page) => Some(page),
+                _ => None,
+            })
+            .unwrap();
+        let (page, page_style) = page.lock().unwrap().styled();
+
+        assert_eq!(page.background_colour, Some((255, 0, 0)));
+        assert_eq!(page.layout.padding, Some((5., 5.)));
+
+        let ElementType::Row(row) = &page.elements[0].1 else {
+            panic!("Expected the row to be added to the page");
+        };
+        let row = row.lock().unwrap();
+        let row_style = cascade(&row.style, &page_style);
+
+        // Background colour and padding are not inherited
+        assert_eq!(row.layout.styled(&row_style).background_colour, None);
+        assert_eq!(row.layout.styled(&row_style).padding, None);
+
+        let (ElementType::Text(text), ElementType::Button(button)) =
+            (&row.elements[0].1, &row.elements[1].1)
+        else {
+            panic!("Expected the text and button to be added to the row");
+        };
+
+        let text = text.lock().unwrap().styled(&row_style);
+
+        // Set on the element itself, so takes priority over the style
+        assert_eq!(text.size, Some(12.));
+        assert_eq!(text.colour, Some((0, 255, 0)));
+
+        let button = button.lock().unwrap().styled(&row_style);
+
+        assert_eq!(button.background_colour, Some((0xe1, 0xe2, 0xe2)));
+        assert_eq!(button.text_colour, Some((0, 0, 255)));
+        assert_eq!(button.padding, Some((1., 2.)));
+    }
+
+    #[test]
+    fn text_styling_recorded() {
+        let source = "\
+start {
+    set_page(Page()
+        .add(Text(\"Heading\").set_bold(true).set_font(\"serif\").set_alignment(\"center\"))
+        .add(Text(\"Body\").set_italic(true).set_line_height(1.5).set_wrap(false))
+        .add(Text(\"Plain\").set_line_height(-1.)));
+}
+";
+
+        let receiver = run_test_script(source);
+
+        let page = receiver
+            .iter()
+            .find_map(|event| match event {
+                InterpreterEvent::SetPage(page) => Some(page),
+                _ => None,
+            })
+            .unwrap();
+        let page = page.lock().unwrap();
+
+        let [(_, ElementType::Text(heading)), (_, ElementType::Text(body)), (_, ElementType::Text(plain))] =
+            &page.elements[..]
+        else {
+            panic!("Expected three texts to be added to the page");
+        };
+
+        let heading = heading.lock().unwrap();
+        assert!(heading.bold && !heading.italic && heading.wrap);
+        assert_eq!(heading.font_family, Some(FontFamily::Serif));
+        assert_eq!(heading.alignment, Some(Alignment::Center));
+        assert_eq!(heading.line_height, None);
+
+        let body = body.lock().unwrap();
+        assert!(!body.bold && body.italic && !body.wrap);
+        assert_eq!(body.font_family, None);
+        assert_eq!(body.line_height, Some(1.5));
+
+        // Lines cannot overlap each other
+        assert_eq!(plain.lock().unwrap().line_height, Some(0.));
+    }
+}
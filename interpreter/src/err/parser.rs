@@ -179,6 +179,49 @@ pub fn err_handler(err_reporter: &mut ErrReporter, parser_err: ParserErr) {
             }
             //
 
+            // Test errors
+            ParserErrType::RedefinedTest(first, other, name) => {
+                err_reporter.writeln(format!("Test '{name}' was defined more than once.").as_str());
+                err_reporter.writeln("        The first definition of the test was here:");
+                err_reporter.report_token(first);
+                err_reporter.writeln("        But it was defined again here:");
+                err_reporter.report_token(other);
+            }
+            //
+
+            // Requires errors
+            ParserErrType::RequiresExpectedCapability(before, after) => {
+                err_reporter.writeln(
+                    format!(
+                        "Expected the name of a capability in quotes after '{}'.",
+                        before.token_type,
+                    )
+                    .as_str(),
+                );
+                err_reporter.report_token(before);
+                err_reporter.report_next_token(after);
+            }
+
+            ParserErrType::RequiresInvalidCapability(capability) => {
+                err_reporter
+                    .writeln(format!("'{}' is not a capability.", capability.token_type).as_str());
+                err_reporter.report_token(capability);
+                err_reporter.writeln("        Tip: The capabilities are file_read, file_write, applications, settings, clipboard, network: followed by a http or https address and socket: followed by a host and port.");
+            }
+
+            ParserErrType::RequiresExpectedCommaOrSemicolon(before, after) => {
+                err_reporter.writeln(
+                    format!(
+                        "Expected ',' to require another capability or ';' to end the declaration after '{}'.",
+                        before.token_type,
+                    )
+                    .as_str(),
+                );
+                err_reporter.report_token(before);
+                err_reporter.report_next_token(after);
+            }
+            //
+
             // Call errors
             ParserErrType::CallExpectedCommaOrRightParen(before, after) => {
                 err_reporter.writeln(
@@ -42,9 +42,71 @@ pub fn err_handler(err_reporter: &mut ErrReporter, tree_walker_err: TreeWalkerEr
             err_reporter.writeln("  Failed to convert the provided String value into a Float.");
             err_reporter.report_token(location);
         }
-        TreeWalkerErr::InsufficientPermissionLevel => {
-            err_reporter.writeln("InsufficientPermissionLevel");
-            err_reporter.writeln("  Cannot read or write data to the file system when the script is loaded over a network.");
+        TreeWalkerErr::PermissionDenied(capability) => {
+            err_reporter.writeln("PermissionDenied");
+            err_reporter.writeln(
+                format!(
+                    "  The script does not have permission to {}.",
+                    capability.description().to_lowercase()
+                )
+                .as_str(),
+            );
+            err_reporter.writeln(
+                format!(
+                    "  It must be declared with 'requires \"{capability}\";' and allowed by the user."
+                )
+                .as_str(),
+            );
+        }
+        TreeWalkerErr::HttpRequestFailed(message) => {
+            err_reporter.writeln("HttpRequestFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+        }
+        TreeWalkerErr::SocketFailed(message) => {
+            err_reporter.writeln("SocketFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+        }
+        TreeWalkerErr::StorageFailed(message) => {
+            err_reporter.writeln("StorageFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+        }
+        TreeWalkerErr::JsonParseFailed(message, location) => {
+            err_reporter.writeln("JsonParseFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+            err_reporter.report_token(location);
+        }
+        TreeWalkerErr::JsonAccessFailed(message, location) => {
+            err_reporter.writeln("JsonAccessFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+            err_reporter.report_token(location);
+        }
+        TreeWalkerErr::InvalidAlignment(alignment) => {
+            err_reporter.writeln("InvalidAlignment");
+            err_reporter.writeln(
+                format!("  '{alignment}' is not one of 'start', 'center' or 'end'.").as_str(),
+            );
+        }
+        TreeWalkerErr::InvalidFontFamily(font_family) => {
+            err_reporter.writeln("InvalidFontFamily");
+            err_reporter.writeln(
+                format!("  '{font_family}' is not one of 'sans', 'serif' or 'monospace'.").as_str(),
+            );
+        }
+        TreeWalkerErr::InvalidPaletteColour(name) => {
+            err_reporter.writeln("InvalidPaletteColour");
+            err_reporter.writeln(
+                format!("  '{name}' is not one of 'background', 'primary', 'text', 'success' or 'danger'.")
+                    .as_str(),
+            );
+        }
+        TreeWalkerErr::InvalidSliderValue(value) => {
+            err_reporter.writeln("InvalidSliderValue");
+            err_reporter.writeln(format!("  '{value}' is not a finite number.").as_str());
+        }
+        TreeWalkerErr::AssertionFailed(message, location) => {
+            err_reporter.writeln("AssertionFailed");
+            err_reporter.writeln(format!("  {message}").as_str());
+            err_reporter.report_token(location);
         }
         _ => err_reporter.writeln(format!("{:?}", tree_walker_err).as_str()),
     }
@@ -1,5 +1,15 @@
-use super::element::{Button, Input, Page};
-use std::sync::{Arc, Mutex};
+use super::{
+    async_request::AsyncRequest,
+    canvas::Pointer,
+    element::{
+        Button, Canvas, Checkbox, Dropdown, Image, Input, Page, RadioGroup, Slider, Table,
+        TextArea, Toggle,
+    },
+    input::Key,
+    socket::Socket,
+    timer::Timer,
+};
+use std::sync::{mpsc::Sender, Arc, Mutex};
 
 #[derive(Debug)]
 pub enum InterpreterEvent {
@@ -9,10 +19,97 @@ pub enum InterpreterEvent {
     LoadAddressError(String),
     CloseTab,
     OpenLink(String, Vec<String>),
+    RequestPermissions(PermissionRequest),
+    ShowDialog(DialogRequest),
+    // The text of the clipboard is sent back, or the sender is dropped if it can't be read
+    ReadClipboard(Sender<String>),
+    WriteClipboard(String),
+    // A stored copy of the script or something it loaded was used, as the server could not be
+    // reached
+    Offline,
+}
+
+// Sent before the script starts when it declares capabilities that the user has not yet allowed
+// or denied, where true is sent back if they are allowed
+#[derive(Debug, Clone)]
+pub struct PermissionRequest {
+    pub origin: String,
+    pub capabilities: Vec<String>,
+    pub response: Sender<bool>,
+}
+
+// Shown over the page by dialog, which waits for the index of the chosen button to be sent back
+#[derive(Debug, Clone)]
+pub struct DialogRequest {
+    pub title: String,
+    pub message: String,
+    pub buttons: Vec<String>,
+    pub response: Sender<usize>,
 }
 
 #[derive(Debug, Clone)]
 pub enum PageEvent {
     ButtonPress(Arc<Mutex<Button>>),
     InputConfirmed(Arc<Mutex<Input>>),
+    CheckboxChanged(Arc<Mutex<Checkbox>>),
+    ToggleChanged(Arc<Mutex<Toggle>>),
+    RadioGroupChanged(Arc<Mutex<RadioGroup>>),
+    SliderChanged(Arc<Mutex<Slider>>),
+    DropdownChanged(Arc<Mutex<Dropdown>>),
+    TextAreaChanged(Arc<Mutex<TextArea>>),
+    TableSorted(Arc<Mutex<Table>>),
+    TableRowSelected(Arc<Mutex<Table>>),
+    CanvasPointer(Arc<Mutex<Canvas>>, Pointer),
+    // Input on the page that no element handled, apart from mouse moves and resizes which are
+    // always sent. They only wake wait_for_event in scripts that check for them.
+    KeyPressed(Key),
+    MouseMoved(f32, f32),
+    MouseClicked(String),
+    WindowResized(f32, f32),
+    // Sent by the interpreter itself when background work started by the script finishes
+    RequestFinished(Arc<Mutex<AsyncRequest>>),
+    ImageLoaded(Arc<Mutex<Image>>),
+    // A socket connected, received a message or was closed
+    SocketChanged(Arc<Mutex<Socket>>),
+    TimerFired(Arc<Mutex<Timer>>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InterpreterEvent;
+    use crate::tests::spawn_test_script;
+    use std::sync::mpsc;
+
+    #[test]
+    fn dialog_returns_chosen_button() {
+        let source = "\
+start {
+    assert(dialog(\"Remove\", \"Remove this application?\", String[\"Cancel\", \"Remove\"]) == 1);
+    assert(dialog(\"Removed\", \"The application was removed.\", String[]) == 0);
+    assert(dialog(\"Closed\", \"Nobody answers this.\", String[\"OK\"]) == -1);
+}
+";
+
+        // Kept open so the page is not treated as closed
+        let (_page_events, page_event_receiver) = mpsc::channel();
+        let (receiver, script) = spawn_test_script(source, page_event_receiver);
+
+        let mut dialogs = receiver.iter().filter_map(|event| match event {
+            InterpreterEvent::ShowDialog(request) => Some(request),
+            _ => None,
+        });
+
+        let request = dialogs.next().unwrap();
+        assert_eq!(request.buttons, vec!["Cancel", "Remove"]);
+        request.response.send(1).unwrap();
+
+        // A dialog without buttons can still be closed
+        let request = dialogs.next().unwrap();
+        assert_eq!(request.buttons, vec!["OK"]);
+        request.response.send(0).unwrap();
+
+        drop(dialogs.next().unwrap());
+
+        script.join().unwrap();
+    }
 }
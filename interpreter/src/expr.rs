@@ -1,7 +1,8 @@
 use crate::{parser::declaration::ConstructionType, standard_prelude::calls::*, token::Token};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Expr {
     Integer(IntegerExpr),
     Float(FloatExpr),
@@ -93,7 +94,7 @@ impl Expr {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IntegerExpr {
     Binary {
         left: Box<IntegerExpr>,
@@ -108,7 +109,7 @@ pub enum IntegerExpr {
     NativeCall(NativeCallInteger),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FloatExpr {
     Binary {
         left: Box<FloatExpr>,
@@ -123,7 +124,7 @@ pub enum FloatExpr {
     NativeCall(NativeCallFloat),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StringExpr {
     Binary {
         left: Box<StringExpr>,
@@ -137,7 +138,7 @@ pub enum StringExpr {
     NativeCall(NativeCallString),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BooleanExpr {
     IntegerBinary {
         left: Box<IntegerExpr>,
@@ -167,13 +168,13 @@ pub enum BooleanExpr {
     Call(usize, Vec<Expr>), // Call location, argument expressions
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NoneExpr {
     NativeCall(NativeCallNone),
     Call(usize, Vec<Expr>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ObjectExpr {
     Variable(usize),
     Property(usize, usize), // Object address, property address
@@ -182,7 +183,7 @@ pub enum ObjectExpr {
     NativeCall(NativeCallObject),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NumericOperator {
     Add,
     Subtract,
@@ -190,22 +191,22 @@ pub enum NumericOperator {
     Multiply,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StringOperator {
     Add,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NumericUnaryOperator {
     Minus,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BooleanUnaryOperator {
     Bang,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NumericAssignmentOperator {
     Equal,
     PlusEqual,
@@ -214,23 +215,23 @@ pub enum NumericAssignmentOperator {
     StarEqual,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StringAssignmentOperator {
     Equal,
     PlusEqual,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BooleanAssignmentOperator {
     Equal,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ObjectAssignmentOperator {
     Equal,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NumericComparision {
     Equal,
     Inequal,
@@ -240,13 +241,13 @@ pub enum NumericComparision {
     Less,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StringComparision {
     Equal,
     Inequal,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BooleanComparision {
     Equal,
     Inequal,
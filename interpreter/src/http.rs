@@ -0,0 +1,394 @@
+use crate::{cookie_jar, permission::Permissions, Address};
+use reqwest::{
+    blocking::{Client, Response},
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE, LOCATION, SET_COOKIE},
+    redirect::Policy,
+    Method, StatusCode, Url,
+};
+use std::{sync::OnceLock, time::Duration};
+
+// Redirects are followed by the request rather than the client, so the cookies set by every
+// response along the way are stored and the right cookies are sent to each address
+const MAX_REDIRECTS: usize = 10;
+
+// A single client is shared so connections to the same server can be reused
+fn client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        Client::builder()
+            .redirect(Policy::none())
+            .build()
+            .expect("Failed to create HTTP client.")
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    pub timeout: Option<Duration>,
+    // When sent for a script, the request is only redirected to origins the script is allowed to
+    // send requests to
+    pub permissions: Option<Permissions>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    // Served from the cache as the server could not be reached
+    pub offline: bool,
+}
+
+impl HttpRequest {
+    pub fn new(method: &str, url: String) -> Self {
+        Self {
+            method: method.to_uppercase(),
+            url,
+            headers: vec![],
+            body: String::new(),
+            timeout: None,
+            permissions: None,
+        }
+    }
+
+    // Responses with any status are returned, so only failing to reach the server is an error
+    pub fn send(&self) -> Result<HttpResponse, String> {
+        let mut method = match self.method.as_str() {
+            "GET" => Method::GET,
+            "POST" => Method::POST,
+            "PUT" => Method::PUT,
+            "DELETE" => Method::DELETE,
+            "PATCH" => Method::PATCH,
+            "HEAD" => Method::HEAD,
+            method => return Err(format!("'{method}' is not a supported HTTP method")),
+        };
+
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("'{name}' is not a valid header name"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| format!("'{value}' is not a valid header value"))?;
+            headers.append(name, value);
+        }
+
+        let mut url = Url::parse(&self.url).map_err(|e| e.to_string())?;
+        let mut body = Some(self.body.clone()).filter(|body| !body.is_empty());
+
+        for _ in 0..=MAX_REDIRECTS {
+            let mut request = client().request(method.clone(), url.clone());
+
+            // Cookies set by the script itself are sent instead of those stored
+            if !headers.contains_key(COOKIE) {
+                if let Some(cookies) = cookie_jar::shared().lock().unwrap().header(url.as_str()) {
+                    request = request.header(COOKIE, cookies);
+                }
+            }
+
+            request = request.headers(headers.clone());
+
+            if let Some(body) = &body {
+                request = request.body(body.clone());
+            }
+
+            if let Some(timeout) = self.timeout {
+                request = request.timeout(timeout);
+            }
+
+            let response = request.send().map_err(|e| e.to_string())?;
+
+            cookie_jar::shared().lock().unwrap().store(
+                url.as_str(),
+                response
+                    .headers()
+                    .get_all(SET_COOKIE)
+                    .iter()
+                    .filter_map(|value| value.to_str().ok()),
+            );
+
+            let Some(location) = redirect_location(&response) else {
+                return HttpResponse::new(response);
+            };
+
+            // After a form is posted, the page it redirects to is fetched without the form
+            if response.status() == StatusCode::SEE_OTHER
+                || (method == Method::POST
+                    && matches!(
+                        response.status(),
+                        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND
+                    ))
+            {
+                method = Method::GET;
+                body = None;
+            }
+
+            let next = url.join(location).map_err(|e| e.to_string())?;
+
+            if let Some(permissions) = &self.permissions {
+                if permissions
+                    .check_network(&Address::new(next.as_str(), vec![]))
+                    .is_err()
+                {
+                    return Err(format!(
+                        "{url} redirected to {next}, which the script is not allowed to send requests to"
+                    ));
+                }
+            }
+
+            // Credentials the script set for one server are not passed on to another
+            if next.origin() != url.origin() {
+                headers.remove(AUTHORIZATION);
+                headers.remove(COOKIE);
+            }
+
+            url = next;
+        }
+
+        Err(format!("{} redirected too many times", self.url))
+    }
+}
+
+fn redirect_location(response: &Response) -> Option<&str> {
+    match response.status().is_redirection() {
+        true => response.headers().get(LOCATION)?.to_str().ok(),
+        false => None,
+    }
+}
+
+impl HttpResponse {
+    fn new(response: Response) -> Result<Self, String> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).to_string(),
+                )
+            })
+            .collect();
+        let body = response.bytes().map_err(|e| e.to_string())?.to_vec();
+
+        Ok(Self {
+            status,
+            headers,
+            body,
+            offline: false,
+        })
+    }
+
+    pub fn success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    // Header names are case insensitive, and the first value is used when one is repeated
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpRequest;
+    use crate::{
+        run, tests::run_failing_test_script, tree_walker::Instrumentation, Address, Permissions,
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    // Stand-in server which answers each connection with a response chosen by the path, echoing
+    // the method, the X-Token header and the body of the request
+    fn serve(connections: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(connections) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap().to_string();
+                let path = parts.next().unwrap().to_string();
+
+                let (mut content_length, mut token) = (0, String::new());
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line.trim().is_empty() {
+                        break;
+                    }
+
+                    let (name, value) = line.split_once(':').unwrap();
+
+                    match name.to_lowercase().as_str() {
+                        "content-length" => content_length = value.trim().parse().unwrap(),
+                        "x-token" => token = value.trim().to_string(),
+                        _ => (),
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let status = match path.as_str() {
+                    "/missing" => "404 Not Found",
+                    "/broken" => "500 Internal Server Error",
+                    _ => "200 OK",
+                };
+
+                let content = format!("{method} {token} {}", String::from_utf8_lossy(&body));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nX-Method: {method}\r\nConnection: close\r\n\r\n{content}",
+                    content.len()
+                )
+                .unwrap();
+            }
+        });
+
+        address
+    }
+
+    #[test]
+    fn methods_headers_and_statuses() {
+        let address = serve(3);
+
+        let mut request = HttpRequest::new("put", format!("{address}/item"));
+        request
+            .headers
+            .push((String::from("X-Token"), String::from("secret")));
+        request.body = String::from("data");
+
+        let response = request.send().unwrap();
+        assert_eq!(response.status, 200);
+        assert!(response.success());
+        assert_eq!(response.header("x-method"), Some("PUT"));
+        assert_eq!(response.text(), "PUT secret data");
+
+        let response = HttpRequest::new("GET", format!("{address}/missing"))
+            .send()
+            .unwrap();
+        assert_eq!(response.status, 404);
+        assert!(!response.success());
+
+        let response = HttpRequest::new("DELETE", format!("{address}/broken"))
+            .send()
+            .unwrap();
+        assert_eq!(response.status, 500);
+        assert_eq!(response.text(), "DELETE  ");
+
+        assert!(HttpRequest::new("BREW", address).send().is_err());
+    }
+
+    #[test]
+    fn requests_from_scripts() {
+        let address = serve(2);
+
+        let source = format!(
+            "\
+start {{
+    let response = HttpRequest(\"PATCH\", \"{address}/item\")
+        .set_header(\"X-Token\", \"abc\")
+        .set_body(\"value\")
+        .set_timeout(5000)
+        .send();
+
+    assert_eq(response.status(), 200);
+    assert_eq(response.header(\"X-Method\"), \"PATCH\");
+    assert_eq(response.header(\"Not-Sent\"), \"\");
+    assert_eq(response.body(), \"PATCH abc value\");
+
+    let missing = HttpRequest(\"GET\", \"{address}/missing\").send();
+    assert_eq(missing.status(), 404);
+}}
+"
+        );
+
+        std::thread::Builder::new()
+            .stack_size(crate::REQUIRED_STACK_SIZE)
+            .spawn(move || {
+                let (mut sender, _) = mpsc::channel();
+                let (_, receiver) = mpsc::channel();
+
+                if let Err(e) = run(
+                    &source,
+                    &mut sender,
+                    receiver,
+                    Permissions::new(&Address::new(&address, vec![])),
+                    vec![],
+                    Instrumentation::default(),
+                ) {
+                    panic!("{}", e.get_err_messages());
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn redirects_limited_to_allowed_origins() {
+        let elsewhere = format!("{}/collect", serve(0));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        let location = elsewhere.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                }
+
+                write!(
+                    stream,
+                    "HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+            }
+        });
+
+        let permissions = Permissions::new(&Address::new(&format!("{address}/app.zonk"), vec![]));
+
+        for call in [
+            format!("HttpRequest(\"GET\", \"{address}/moved\").send();"),
+            format!("read_string(\"{address}/moved\");"),
+        ] {
+            let error =
+                run_failing_test_script(&format!("start {{ {call} }}"), permissions.clone());
+            assert!(error.contains(&format!("redirected to {elsewhere}")));
+            assert!(error.contains("not allowed"));
+        }
+    }
+}
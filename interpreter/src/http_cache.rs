@@ -0,0 +1,322 @@
+use crate::{
+    disk_cache,
+    http::{HttpRequest, HttpResponse},
+    permission::Permissions,
+};
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Responses which have not been stored for a while are removed once the cache grows past this size
+const MAX_SIZE: u64 = 128 * 1024 * 1024;
+
+// Responses to GET requests are stored in the data directory, keyed by a hash of the URL. They are
+// used without contacting the server while fresh, revalidated with conditional requests after
+// that, and used regardless when the server cannot be reached.
+pub struct HttpCache {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    // Seconds since the unix epoch when the response was stored or last revalidated
+    stored_at: u64,
+    max_age: Option<u64>,
+    no_cache: bool,
+}
+
+#[derive(Default)]
+struct CacheControl {
+    no_store: bool,
+    private: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl HttpCache {
+    pub fn new() -> Option<Self> {
+        let proj_dirs = ProjectDirs::from("rocks.sambowden", "", "zonkey")?;
+        Some(Self {
+            directory: proj_dirs.data_dir().join(".http_cache"),
+        })
+    }
+
+    pub fn get(
+        &self,
+        url: &str,
+        permissions: Option<&Permissions>,
+    ) -> Result<HttpResponse, String> {
+        let path = self.path(url);
+        let entry = fs::read(&path)
+            .ok()
+            .and_then(|bytes| bincode::deserialize::<Entry>(&bytes).ok());
+
+        if let Some(entry) = &entry {
+            if entry.is_fresh() {
+                return Ok(entry.response(false));
+            }
+        }
+
+        let mut request = HttpRequest::new("GET", url.to_string());
+        request.permissions = permissions.cloned();
+
+        if let Some(entry) = &entry {
+            if let Some(etag) = &entry.etag {
+                request
+                    .headers
+                    .push((String::from("If-None-Match"), etag.clone()));
+            }
+
+            if let Some(last_modified) = &entry.last_modified {
+                request
+                    .headers
+                    .push((String::from("If-Modified-Since"), last_modified.clone()));
+            }
+        }
+
+        match (request.send(), entry) {
+            (Ok(response), Some(mut entry)) if response.status == 304 => {
+                entry.revalidate(&response);
+                self.store(url, &entry);
+                Ok(entry.response(false))
+            }
+            (Ok(response), _) => {
+                if response.success() {
+                    match Entry::new(&response) {
+                        Some(entry) => self.store(url, &entry),
+                        None => {
+                            fs::remove_file(&path).ok();
+                        }
+                    }
+                }
+                Ok(response)
+            }
+            (Err(_), Some(entry)) => Ok(entry.response(true)),
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        let hash = Sha256::digest(url.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        self.directory.join(hash)
+    }
+
+    // Failing to store the response only means it will be requested again next time
+    fn store(&self, url: &str, entry: &Entry) {
+        let Ok(bytes) = bincode::serialize(entry) else {
+            return;
+        };
+
+        fs::create_dir_all(&self.directory).ok();
+        fs::write(self.path(url), bytes).ok();
+        disk_cache::prune(&self.directory, MAX_SIZE);
+    }
+}
+
+impl Entry {
+    fn new(response: &HttpResponse) -> Option<Self> {
+        let cache_control = cache_control(response);
+
+        // Entries are keyed only by URL, so responses that depend on the request headers or are
+        // meant for a single user are not stored
+        if cache_control.no_store || cache_control.private || response.header("Vary").is_some() {
+            return None;
+        }
+
+        Some(Self {
+            status: response.status,
+            headers: response.headers.clone(),
+            body: response.body.clone(),
+            etag: response.header("ETag").map(|etag| etag.to_string()),
+            last_modified: response
+                .header("Last-Modified")
+                .map(|date| date.to_string()),
+            stored_at: now(),
+            max_age: cache_control.max_age,
+            no_cache: cache_control.no_cache,
+        })
+    }
+
+    // Responses without a max age are revalidated every time they are used
+    fn is_fresh(&self) -> bool {
+        !self.no_cache
+            && self
+                .max_age
+                .is_some_and(|max_age| now() < self.stored_at.saturating_add(max_age))
+    }
+
+    // A not modified response can update how long the stored response stays fresh
+    fn revalidate(&mut self, response: &HttpResponse) {
+        self.stored_at = now();
+
+        if response.header("Cache-Control").is_some() {
+            let cache_control = cache_control(response);
+            self.max_age = cache_control.max_age;
+            self.no_cache = cache_control.no_cache;
+        }
+
+        if let Some(etag) = response.header("ETag") {
+            self.etag = Some(etag.to_string());
+        }
+    }
+
+    fn response(&self, offline: bool) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+            offline,
+        }
+    }
+}
+
+fn cache_control(response: &HttpResponse) -> CacheControl {
+    let mut cache_control = CacheControl::default();
+
+    let Some(header) = response.header("Cache-Control") else {
+        return cache_control;
+    };
+
+    for directive in header.split(',') {
+        let directive = directive.trim().to_lowercase();
+
+        match directive.split_once('=') {
+            Some(("max-age", seconds)) => {
+                cache_control.max_age = seconds.trim_matches('"').parse().ok();
+            }
+            _ if directive == "no-store" => cache_control.no_store = true,
+            _ if directive == "private" => cache_control.private = true,
+            _ if directive == "no-cache" => cache_control.no_cache = true,
+            _ => (),
+        }
+    }
+
+    cache_control
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpCache;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    // Stand-in server which answers each request with the next of the given responses, sending
+    // back the conditional headers it received
+    fn serve(responses: Vec<&'static str>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            // The listener is closed after the last response, so later requests fail
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut conditions = vec![];
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line.trim().is_empty() {
+                        break;
+                    }
+
+                    if line.to_lowercase().starts_with("if-") {
+                        conditions.push(line.trim().to_lowercase());
+                    }
+                }
+
+                sender.send(conditions.join(", ")).ok();
+                write!(stream, "{response}").unwrap();
+            }
+        });
+
+        (address, receiver)
+    }
+
+    #[test]
+    fn revalidates_and_serves_offline() {
+        let cache = HttpCache {
+            directory: std::env::temp_dir().join("zonkey_http_cache_test"),
+        };
+        std::fs::remove_dir_all(&cache.directory).ok();
+
+        let (address, requests) = serve(vec![
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nCache-Control: no-cache\r\nContent-Length: 5\r\nConnection: close\r\n\r\nfirst",
+            "HTTP/1.1 304 Not Modified\r\nCache-Control: max-age=3600\r\nConnection: close\r\n\r\n",
+        ]);
+        let url = format!("{address}/page.zonk");
+
+        let response = cache.get(&url, None).unwrap();
+        assert_eq!(response.text(), "first");
+        assert!(!response.offline);
+        assert_eq!(requests.recv().unwrap(), "");
+
+        // Revalidated with the ETag, and then fresh for an hour without contacting the server
+        let response = cache.get(&url, None).unwrap();
+        assert_eq!(response.text(), "first");
+        assert_eq!(requests.recv().unwrap(), "if-none-match: \"v1\"");
+
+        assert_eq!(cache.get(&url, None).unwrap().text(), "first");
+        assert!(requests.try_recv().is_err());
+
+        let (address, _) = serve(vec![
+            "HTTP/1.1 200 OK\r\nLast-Modified: Mon, 19 Oct 2026 10:00:00 GMT\r\nContent-Length: 4\r\nConnection: close\r\n\r\ndata",
+        ]);
+        let url = format!("{address}/data.txt");
+        assert_eq!(cache.get(&url, None).unwrap().text(), "data");
+
+        // The server has stopped, so the stored response is used
+        let response = cache.get(&url, None).unwrap();
+        assert_eq!(response.text(), "data");
+        assert!(response.offline);
+
+        let (address, _) = serve(vec![
+            "HTTP/1.1 200 OK\r\nCache-Control: no-store\r\nContent-Length: 6\r\nConnection: close\r\n\r\nsecret",
+        ]);
+        let url = format!("{address}/secret.txt");
+        assert_eq!(cache.get(&url, None).unwrap().text(), "secret");
+        assert!(cache.get(&url, None).is_err());
+
+        let (address, _) = serve(vec![
+            "HTTP/1.1 200 OK\r\nCache-Control: private, max-age=3600\r\nContent-Length: 7\r\nConnection: close\r\n\r\naccount",
+        ]);
+        let url = format!("{address}/account.txt");
+        assert_eq!(cache.get(&url, None).unwrap().text(), "account");
+        assert!(cache.get(&url, None).is_err());
+
+        let (address, _) = serve(vec![
+            "HTTP/1.1 200 OK\r\nVary: Accept-Language\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+        ]);
+        let url = format!("{address}/greeting.txt");
+        assert_eq!(cache.get(&url, None).unwrap().text(), "hello");
+        assert!(cache.get(&url, None).is_err());
+
+        std::fs::remove_dir_all(&cache.directory).ok();
+    }
+}
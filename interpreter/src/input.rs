@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+// Keyboard, mouse and window events from the page that were not handled by one of its elements.
+// The latest of each is kept by the interpreter so the script can check it after wait_for_event.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Key {
+    pub name: String,
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct InputState {
+    pub key: Key,
+    pub key_pressed: bool,
+    // Positions are relative to the top left corner of the window
+    pub mouse_x: f32,
+    pub mouse_y: f32,
+    pub mouse_button: String,
+    pub mouse_moved: bool,
+    pub mouse_clicked: bool,
+    // Zero until the window is first resized
+    pub window_width: f32,
+    pub window_height: f32,
+    pub window_resized: bool,
+}
+
+// The kinds of event a script checks for, found when it is parsed. Events the script never checks
+// for still update the input state but don't wake wait_for_event, so event loops are not woken
+// every time the cursor moves.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Subscriptions {
+    pub key_pressed: bool,
+    pub mouse_moved: bool,
+    pub mouse_clicked: bool,
+    pub window_resized: bool,
+    pub image_loaded: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Key;
+    use crate::{
+        event::{InterpreterEvent, PageEvent},
+        tests::spawn_test_script,
+    };
+    use std::sync::mpsc;
+
+    #[test]
+    fn input_events_queryable_after_wait_for_event() {
+        let source = "\
+start {
+    assert(wait_for_event());
+    assert(key_pressed());
+    assert(!key_pressed());
+    assert(key_name() == \"S\");
+    assert(control_held() & !shift_held() & !alt_held());
+
+    assert(wait_for_event());
+    assert(!key_pressed());
+    assert(mouse_moved());
+    assert(mouse_x() == 12.5);
+    assert(mouse_y() == 40.);
+
+    assert(wait_for_event());
+    assert(mouse_clicked());
+    assert(mouse_button() == \"Right\");
+    assert(mouse_x() == 12.5);
+
+    assert(wait_for_event());
+    assert(window_resized());
+    assert(!window_resized());
+    assert(window_width() == 800.);
+    assert(window_height() == 600.);
+
+    assert(!wait_for_event());
+}
+";
+
+        let (page_events, page_event_receiver) = mpsc::channel();
+
+        for event in [
+            PageEvent::KeyPressed(Key {
+                name: String::from("S"),
+                control: true,
+                ..Key::default()
+            }),
+            PageEvent::MouseMoved(12.5, 40.),
+            PageEvent::MouseClicked(String::from("Right")),
+            PageEvent::WindowResized(800., 600.),
+        ] {
+            page_events.send(event).unwrap();
+        }
+        drop(page_events);
+
+        let (_interpreter_events, script) = spawn_test_script(source, page_event_receiver);
+        script.join().unwrap();
+    }
+
+    #[test]
+    fn unchecked_input_does_not_wake_wait_for_event() {
+        // Written before input events existed, so it only expects element and timer events
+        let source = "\
+start {
+    let wakes = 0;
+    while (wait_for_event()) {
+        wakes += 1;
+    }
+    assert(wakes == 0);
+    assert(mouse_x() == 99.);
+    assert(window_width() == 640.);
+}
+";
+
+        let (page_events, page_event_receiver) = mpsc::channel();
+
+        for x in 0..100 {
+            page_events
+                .send(PageEvent::MouseMoved(x as f32, 0.))
+                .unwrap();
+        }
+        page_events
+            .send(PageEvent::WindowResized(640., 480.))
+            .unwrap();
+        page_events
+            .send(PageEvent::KeyPressed(Key::default()))
+            .unwrap();
+        page_events
+            .send(PageEvent::MouseClicked(String::from("Left")))
+            .unwrap();
+        drop(page_events);
+
+        let (interpreter_events, script) = spawn_test_script(source, page_event_receiver);
+        script.join().unwrap();
+
+        let updates = interpreter_events
+            .try_iter()
+            .filter(|event| matches!(event, InterpreterEvent::Update))
+            .count();
+        assert_eq!(updates, 1);
+    }
+
+    #[test]
+    fn checked_input_wakes_wait_for_event() {
+        let source = "\
+start {
+    let moves = 0;
+    while (wait_for_event()) {
+        if (mouse_moved()) {
+            moves += 1;
+        }
+    }
+    assert(moves == 3);
+}
+";
+
+        let (page_events, page_event_receiver) = mpsc::channel();
+
+        for x in 0..3 {
+            page_events
+                .send(PageEvent::MouseMoved(x as f32, 0.))
+                .unwrap();
+        }
+        page_events
+            .send(PageEvent::WindowResized(640., 480.))
+            .unwrap();
+        drop(page_events);
+
+        let (_interpreter_events, script) = spawn_test_script(source, page_event_receiver);
+        script.join().unwrap();
+    }
+}
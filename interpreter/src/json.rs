@@ -0,0 +1,149 @@
+use serde_json::{Map, Value};
+
+pub fn parse(string: &str) -> Result<Value, String> {
+    serde_json::from_str(string).map_err(|e| format!("Failed to parse JSON - {e}"))
+}
+
+pub fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "Boolean",
+        Value::Number(number) if number.is_f64() => "Float",
+        Value::Number(_) => "Integer",
+        Value::String(_) => "String",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+pub fn get<'a>(json: &'a Value, key: &str) -> Result<&'a Value, String> {
+    match json {
+        Value::Object(object) => match object.get(key) {
+            Some(value) => Ok(value),
+            None => Err(format!("The JSON object does not contain the key '{key}'")),
+        },
+        value => Err(format!(
+            "Expected a JSON object to get '{key}' from but found {}",
+            type_name(value)
+        )),
+    }
+}
+
+pub fn object_mut<'a>(
+    json: &'a mut Value,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>, String> {
+    match json {
+        Value::Object(object) => Ok(object),
+        value => Err(format!(
+            "Expected a JSON object to set '{key}' on but found {}",
+            type_name(value)
+        )),
+    }
+}
+
+pub fn has(json: &Value, key: &str) -> bool {
+    matches!(json, Value::Object(object) if object.contains_key(key))
+}
+
+// Converts the value with the given accessor, where the key is None when the value is accessed
+// directly rather than from an object
+pub fn convert<T>(
+    value: &Value,
+    key: Option<&str>,
+    expected: &str,
+    accessor: impl Fn(&Value) -> Option<T>,
+) -> Result<T, String> {
+    match accessor(value) {
+        Some(value) => Ok(value),
+        None => match key {
+            Some(key) => Err(format!(
+                "Expected the value of '{key}' to be {expected} but found {}",
+                type_name(value)
+            )),
+            None => Err(format!(
+                "Expected the JSON value to be {expected} but found {}",
+                type_name(value)
+            )),
+        },
+    }
+}
+
+pub fn as_string(value: &Value) -> Option<String> {
+    value.as_str().map(|string| string.to_string())
+}
+
+pub fn as_integer(value: &Value) -> Option<i64> {
+    value.as_i64()
+}
+
+// Integers are accepted as floats, as JSON does not tell them apart
+pub fn as_float(value: &Value) -> Option<f64> {
+    value.as_f64()
+}
+
+pub fn as_boolean(value: &Value) -> Option<bool> {
+    value.as_bool()
+}
+
+pub fn as_object(value: &Value) -> Option<Value> {
+    value.is_object().then(|| value.clone())
+}
+
+pub fn as_array(value: &Value) -> Option<Vec<Value>> {
+    value.as_array().cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_access() {
+        let json =
+            parse(r#"{"name": "Zonkey", "version": 1, "ratio": 0.5, "tags": ["a", "b"]}"#).unwrap();
+
+        let name = get(&json, "name").unwrap();
+        assert_eq!(
+            convert(name, Some("name"), "a String", as_string),
+            Ok(String::from("Zonkey"))
+        );
+
+        let version = get(&json, "version").unwrap();
+        assert_eq!(
+            convert(version, Some("version"), "an Integer", as_integer),
+            Ok(1)
+        );
+        assert_eq!(
+            convert(version, Some("version"), "a Float", as_float),
+            Ok(1.)
+        );
+
+        let ratio = get(&json, "ratio").unwrap();
+        assert_eq!(
+            convert(ratio, Some("ratio"), "an Integer", as_integer),
+            Err(String::from(
+                "Expected the value of 'ratio' to be an Integer but found Float"
+            ))
+        );
+
+        let tags = get(&json, "tags").unwrap();
+        assert_eq!(convert(tags, None, "an array", as_array).unwrap().len(), 2);
+
+        assert!(has(&json, "tags"));
+        assert!(!has(&json, "missing"));
+        assert_eq!(
+            get(&json, "missing"),
+            Err(String::from(
+                "The JSON object does not contain the key 'missing'"
+            ))
+        );
+        assert!(get(tags, "name").is_err());
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(parse("{\"unclosed\": ").is_err());
+        assert!(parse("[1, 2,]").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use self::{err::InterpreterErr, lexer::Lexer};
 use crate::{
+    ast::AST,
     err::InterpreterErrType,
     parser::Parser,
     tree_walker::{err::TreeWalkerErr, TreeWalker},
@@ -8,8 +9,13 @@ pub use address::Address;
 use event::{InterpreterEvent, PageEvent};
 pub use iced;
 pub use iced_native;
-use permission::PermissionLevel;
-use std::sync::mpsc::{Receiver, Sender};
+use permission::Permissions;
+use script_cache::ScriptCache;
+use std::sync::mpsc::{self, Receiver, Sender};
+pub use tree_walker::{
+    coverage::Coverage, debugger::Debugger, profiler::Profiler, Instrumentation,
+};
+use unicode_segmentation::UnicodeSegmentation;
 
 pub const REQUIRED_STACK_SIZE: usize = 8 * 1024 * 1024;
 
@@ -18,27 +24,55 @@ mod tests;
 
 pub mod address;
 mod ast;
+pub mod async_request;
+pub mod canvas;
+mod cookie_jar;
+mod debug_symbols;
 mod debugger;
+mod disk_cache;
 pub mod element;
 pub mod err;
 pub mod event;
 mod expr;
+mod http;
+mod http_cache;
+pub mod input;
+mod json;
 pub mod lexer;
 pub mod parser;
 mod permission;
+mod script_cache;
+pub mod socket;
 mod stack;
 mod standard_prelude;
 mod stmt;
+mod storage;
+pub mod style;
+pub mod timer;
 mod token;
 mod tree_walker;
 
 pub fn run_with_error_messages(
+    address: Address,
+    sender: Sender<InterpreterEvent>,
+    receiver: Receiver<PageEvent>,
+) {
+    run_with_instrumentation(address, sender, receiver, Instrumentation::default())
+}
+
+pub fn run_with_instrumentation(
     address: Address,
     mut sender: Sender<InterpreterEvent>,
     receiver: Receiver<PageEvent>,
+    instrumentation: Instrumentation,
 ) {
-    let source = match address.read_string() {
-        Ok(source) => source,
+    let source = match address.read_string_offline(None) {
+        Ok((source, offline)) => {
+            if offline {
+                sender.send(InterpreterEvent::Offline).ok();
+            }
+            source
+        }
         Err(e) => {
             sender
                 .send(InterpreterEvent::LoadAddressError(e.to_string()))
@@ -47,12 +81,23 @@ pub fn run_with_error_messages(
         }
     };
 
-    match run(
+    // The debug symbols needed by instrumentation are not kept in the cache
+    let cache = match instrumentation.needs_debug_symbols() {
+        true => None,
+        false => ScriptCache::new(&address, &source),
+    };
+
+    let mut permissions = Permissions::new(&address);
+    permissions.load_manifest(&address);
+
+    match run_script(
         &source,
+        cache.as_ref(),
         &mut sender,
         receiver,
-        PermissionLevel::new(&address),
-        address.arguments,
+        permissions,
+        address,
+        instrumentation,
     ) {
         Ok(_) => (),
         Err(error) => {
@@ -69,11 +114,76 @@ pub fn run<'a>(
     source: &'a str,
     sender: &mut Sender<InterpreterEvent>,
     receiver: Receiver<PageEvent>,
-    permission_level: PermissionLevel,
+    permissions: Permissions,
     arguments: Vec<String>,
+    instrumentation: Instrumentation,
+) -> Result<(), InterpreterErr<'a>> {
+    // Without an address, relative addresses are resolved against the working directory
+    run_script(
+        source,
+        None,
+        sender,
+        receiver,
+        permissions,
+        Address::new("", arguments),
+        instrumentation,
+    )
+}
+
+fn run_script<'a>(
+    source: &'a str,
+    cache: Option<&ScriptCache>,
+    sender: &mut Sender<InterpreterEvent>,
+    receiver: Receiver<PageEvent>,
+    mut permissions: Permissions,
+    address: Address,
+    mut instrumentation: Instrumentation,
 ) -> Result<(), InterpreterErr<'a>> {
     interpreter_debug!("Debug build");
 
+    let (mut ast, graphemes) = match cache.and_then(|cache| cache.load()) {
+        Some(ast) => {
+            interpreter_debug!("Loaded parsed script from cache");
+            let graphemes = UnicodeSegmentation::graphemes(source, true).collect::<Vec<&str>>();
+            (ast, graphemes)
+        }
+        None => {
+            let (ast, graphemes) = parse(source, instrumentation.needs_debug_symbols())?;
+
+            if let Some(cache) = cache {
+                cache.store(&ast);
+            }
+
+            (ast, graphemes)
+        }
+    };
+
+    // Declared capabilities are asked for before the script starts, so scripts never stop to wait
+    // for the user part way through
+    permissions.request(&ast.capabilities, sender);
+
+    if let Some(mut debug_symbols) = ast.debug_symbols.take() {
+        debug_symbols.load_source(&graphemes);
+        instrumentation.load_symbols(debug_symbols);
+    }
+
+    interpreter_debug!("Starting tree walker");
+    match TreeWalker::run(ast, sender, receiver, permissions, address, instrumentation) {
+        Ok(_) => Ok(()),
+        Err(TreeWalkerErr::Exit) => Ok(()),
+        Err(e) => {
+            return Err(InterpreterErr::new(
+                InterpreterErrType::TreeWalkerFailed(e),
+                graphemes,
+            ))
+        }
+    }
+}
+
+fn parse<'a>(
+    source: &'a str,
+    debug_symbols: bool,
+) -> Result<(AST, Vec<&'a str>), InterpreterErr<'a>> {
     interpreter_debug!("Starting lexer");
     let (result, graphemes) = Lexer::run(source);
     let tokens = match result {
@@ -90,28 +200,76 @@ pub fn run<'a>(
     };
 
     interpreter_debug!("Starting parser");
-    let ast = match Parser::run(tokens) {
+    let result = match debug_symbols {
+        true => Parser::run_with_debug_symbols(tokens),
+        false => Parser::run(tokens),
+    };
+
+    match result {
         Ok(ast) => {
             interpreter_debug!("Parser completed successfully");
-            ast
+            Ok((ast, graphemes))
         }
-        Err(e) => {
-            return Err(InterpreterErr::new(
-                InterpreterErrType::ParserFailed(e),
-                graphemes,
-            ))
-        }
-    };
+        Err(e) => Err(InterpreterErr::new(
+            InterpreterErrType::ParserFailed(e),
+            graphemes,
+        )),
+    }
+}
 
-    interpreter_debug!("Starting tree walker");
-    match TreeWalker::run(ast, sender, receiver, permission_level, arguments) {
-        Ok(_) => Ok(()),
-        Err(TreeWalkerErr::Exit) => Ok(()),
-        Err(e) => {
-            return Err(InterpreterErr::new(
-                InterpreterErrType::TreeWalkerFailed(e),
-                graphemes,
-            ))
-        }
+pub struct TestResult {
+    pub name: String,
+    // Error messages of the exception that caused the test to fail
+    pub failure: Option<String>,
+}
+
+// Every test is interpreted with its own state so that tests cannot affect each other, and the
+// start block is not run
+pub fn run_tests(address: Address) -> Result<Vec<TestResult>, String> {
+    let source = address.read_string().map_err(|e| e.to_string())?;
+
+    let (ast, graphemes) = parse(&source, false).map_err(|e| e.get_err_messages())?;
+
+    // Tests are run without a user to ask, so the declared capabilities are granted
+    let mut permissions = Permissions::new(&address);
+    permissions.load_manifest(&address);
+    permissions.grant_all(&ast.capabilities);
+
+    let mut results = vec![];
+
+    for test in ast.tests {
+        let (mut sender, _interpreter_events) = mpsc::channel();
+        let (_page_events, receiver) = mpsc::channel();
+
+        let test_ast = AST {
+            start: test.block,
+            callable: ast.callable.clone(),
+            debug_symbols: None,
+            tests: vec![],
+            capabilities: vec![],
+            subscriptions: ast.subscriptions,
+        };
+
+        let failure = match TreeWalker::run(
+            test_ast,
+            &mut sender,
+            receiver,
+            permissions.clone(),
+            address.clone(),
+            Instrumentation::default(),
+        ) {
+            Ok(_) | Err(TreeWalkerErr::Exit) => None,
+            Err(e) => Some(
+                InterpreterErr::new(InterpreterErrType::TreeWalkerFailed(e), graphemes.clone())
+                    .get_err_messages(),
+            ),
+        };
+
+        results.push(TestResult {
+            name: test.name.to_string(),
+            failure,
+        });
     }
+
+    Ok(results)
 }
@@ -1,5 +1,6 @@
 use crate::{parser::value::ValueType, token::Token};
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -20,7 +21,7 @@ pub struct ClassDeclaration {
     pub methods: FxHashMap<Rc<String>, Rc<CallableDeclaration>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ConstructionType {
     Integer,
     Float,
@@ -59,6 +59,14 @@ pub enum ParserErrType {
     NoStartBlock,
     RedefinedStart(Token, Token),
 
+    // Test errors
+    RedefinedTest(Token, Token, String),
+
+    // Requires errors
+    RequiresExpectedCapability(Token, Option<Token>),
+    RequiresInvalidCapability(Token),
+    RequiresExpectedCommaOrSemicolon(Token, Option<Token>),
+
     // Call errors
     CallExpectedCommaOrRightParen(Token, Option<Token>),
     CallIncorrectArgumentsNum(Token, usize, usize, String),
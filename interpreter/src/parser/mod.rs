@@ -1,15 +1,20 @@
 pub mod declaration;
 pub mod err;
-mod location;
+pub mod location;
 mod production;
 mod status;
 pub mod value;
 
 use crate::{
-    ast::AST,
+    ast::{Test, AST},
+    debug_symbols::DebugSymbols,
+    input::Subscriptions,
     parser::declaration::{CallableDeclaration, ClassDeclaration},
     parser::location::Location,
-    parser_debug, standard_prelude,
+    parser_debug,
+    permission::Capability,
+    stack::Stack,
+    standard_prelude,
     stmt::Stmt,
     token::Token,
 };
@@ -35,13 +40,27 @@ pub struct Parser {
     callables: Vec<Rc<Stmt>>,
     error: ParserErr,
     start_definition: Option<(Token, Option<Stmt>)>,
+    tests: Vec<Test>,
+    capabilities: Vec<Capability>,
+    subscriptions: Subscriptions,
     current: usize,
     sub_expression_limit: usize,
     nested_scope_limit: usize,
+    debug_symbols: Option<DebugSymbols>,
 }
 
 impl Parser {
     pub fn run(tokens: Vec<Token>) -> Result<AST, ParserErr> {
+        Self::parse(tokens, None)
+    }
+
+    // Statements are wrapped with their source position, which is slower to interpret, so this
+    // should only be used when a tool needs them
+    pub fn run_with_debug_symbols(tokens: Vec<Token>) -> Result<AST, ParserErr> {
+        Self::parse(tokens, Some(DebugSymbols::default()))
+    }
+
+    fn parse(tokens: Vec<Token>, debug_symbols: Option<DebugSymbols>) -> Result<AST, ParserErr> {
         let mut parser = Self {
             tokens,
             environments: vec![],
@@ -59,20 +78,37 @@ impl Parser {
             callables: vec![],
             error: ParserErr::new(),
             start_definition: None,
+            tests: vec![],
+            capabilities: vec![],
+            subscriptions: Subscriptions::default(),
             current: 0,
             sub_expression_limit: 0,
             nested_scope_limit: 0,
+            debug_symbols,
         };
 
         parser_debug!("Production rule path:");
 
         parser.program();
 
-        match (parser.start_definition, parser.error.had_error()) {
+        // Scripts which only contain tests do not need a start block
+        let start_definition = match parser.start_definition {
+            None if !parser.tests.is_empty() => Some((
+                parser.tests[0].token.clone(),
+                Some(Stmt::Block(vec![], Stack::default())),
+            )),
+            start_definition => start_definition,
+        };
+
+        match (start_definition, parser.error.had_error()) {
             (Some((_, Some(stmt))), false) => {
                 let ast = AST {
                     start: stmt,
                     callable: parser.callables,
+                    debug_symbols: parser.debug_symbols,
+                    tests: parser.tests,
+                    capabilities: parser.capabilities,
+                    subscriptions: parser.subscriptions,
                 };
 
                 parser_debug!("AST");
@@ -145,6 +145,10 @@ impl Parser {
 
         let class_declaration = ClassDeclaration { methods };
 
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            debug_symbols.add_class(Rc::clone(&class_name), &properties);
+        }
+
         self.current_properties = Some(properties);
 
         self.class_declarations
@@ -166,15 +170,17 @@ impl Parser {
 
                 self.current += 1;
 
+                self.begin_debug_callable(Some(self.callables.len()), class_name.to_string());
+
                 let parameters = self.parameters()?;
                 let mut parameter_value_types = vec![];
 
                 let mut constructor_scope = FxHashMap::default();
 
-                constructor_scope.insert(
-                    Rc::new("self".to_string()),
-                    Location::Object(Rc::clone(&class_name), self.object_next_id),
-                );
+                let self_name = Rc::new("self".to_string());
+                let self_location = Location::Object(Rc::clone(&class_name), self.object_next_id);
+                self.add_scope_symbol(&self_name, &self_location);
+                constructor_scope.insert(self_name, self_location);
                 self.object_next_id += 1;
 
                 for (value_type, name) in parameters {
@@ -200,6 +206,7 @@ impl Parser {
 
                 // Clean environments after it has been parsed
                 self.environments.clear();
+                self.close_debug_callable();
                 self.integer_next_id = 0;
                 self.float_next_id = 0;
                 self.string_next_id = 0;
@@ -252,16 +259,21 @@ impl Parser {
                     return Err(ParserStatus::End);
                 }
 
+                self.begin_debug_callable(
+                    Some(self.callables.len()),
+                    format!("{}.{}", class_name, method_name),
+                );
+
                 let parameters = self.parameters()?;
                 let return_type = self.return_type()?;
 
                 let mut method_scope = FxHashMap::default();
                 let mut parameter_value_types = vec![];
 
-                method_scope.insert(
-                    Rc::new("self".to_string()),
-                    Location::Object(Rc::clone(&class_name), self.object_next_id),
-                );
+                let self_name = Rc::new("self".to_string());
+                let self_location = Location::Object(Rc::clone(&class_name), self.object_next_id);
+                self.add_scope_symbol(&self_name, &self_location);
+                method_scope.insert(self_name, self_location);
                 self.object_next_id += 1;
 
                 for (value_type, name) in parameters {
@@ -300,6 +312,7 @@ impl Parser {
 
                 // Clean value stack after it has been parsed
                 self.environments.clear();
+                self.close_debug_callable();
                 self.integer_next_id = 0;
                 self.float_next_id = 0;
                 self.string_next_id = 0;
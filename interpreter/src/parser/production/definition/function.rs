@@ -33,6 +33,8 @@ impl Parser {
             return Err(ParserStatus::Unwind);
         }
 
+        self.begin_debug_callable(Some(self.callables.len()), function_name.to_string());
+
         // Get function parameters
         let parameters = self.parameters()?;
 
@@ -78,6 +80,7 @@ impl Parser {
 
         // Clean environments after it has been parsed
         self.environments.clear();
+        self.close_debug_callable();
         self.integer_next_id = 0;
         self.float_next_id = 0;
         self.string_next_id = 0;
@@ -1,7 +1,9 @@
 mod class;
 mod function;
 mod prelude;
+mod requires;
 mod start;
+mod test;
 
 use rustc_hash::FxHashMap;
 
@@ -14,6 +16,26 @@ use crate::{
 use std::rc::Rc;
 
 impl Parser {
+    // Definitions introduced by an identifier that is not reserved must be followed by a string
+    fn contextual_definition_ahead(&self, keyword: &str) -> bool {
+        match (
+            self.tokens.get(self.current),
+            self.tokens.get(self.current + 1),
+        ) {
+            (
+                Some(Token {
+                    token_type: TokenType::Identifier(identifier),
+                    ..
+                }),
+                Some(Token {
+                    token_type: TokenType::String(_),
+                    ..
+                }),
+            ) => identifier.as_str() == keyword,
+            _ => false,
+        }
+    }
+
     // Helper functions used by some definitions to convert token_type to a value_type
     fn data_type(&mut self) -> Result<Option<ValueType>, ParserStatus> {
         match self.current_token_type() {
@@ -165,35 +187,48 @@ impl Parser {
         name: Rc<String>,
         scope: &mut FxHashMap<Rc<String>, Location>,
     ) -> Result<(), ParserStatus> {
-        match value_type {
+        let location = match value_type {
             ValueType::Integer => {
-                scope.insert(name, Location::Integer(self.integer_next_id));
+                let location = Location::Integer(self.integer_next_id);
                 self.integer_next_id += 1;
+                location
             }
             ValueType::Float => {
-                scope.insert(name, Location::Float(self.float_next_id));
+                let location = Location::Float(self.float_next_id);
                 self.float_next_id += 1;
+                location
             }
             ValueType::String => {
-                scope.insert(name, Location::String(self.string_next_id));
+                let location = Location::String(self.string_next_id);
                 self.string_next_id += 1;
+                location
             }
             ValueType::Boolean => {
-                scope.insert(name, Location::Boolean(self.boolean_next_id));
+                let location = Location::Boolean(self.boolean_next_id);
                 self.boolean_next_id += 1;
+                location
             }
             ValueType::Class(class) => {
-                scope.insert(
-                    name,
-                    Location::Object(Rc::clone(class), self.object_next_id),
-                );
+                let location = Location::Object(Rc::clone(class), self.object_next_id);
                 self.object_next_id += 1;
+                location
             }
             ValueType::Printable | ValueType::Element | ValueType::Generic => {
                 unreachable!("Zonkey code cannot use these types")
             }
-        }
+        };
+
+        self.add_scope_symbol(&name, &location);
+        scope.insert(name, location);
 
         Ok(())
     }
+
+    // Parameters and self are visible from the start of the callable body
+    fn add_scope_symbol(&mut self, name: &Rc<String>, location: &Location) {
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            let position = self.tokens[self.current - 1].start;
+            debug_symbols.add_variable(Rc::clone(name), location.clone(), position);
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use crate::{parser::production::definition::prelude::*, permission::Capability};
+
+impl Parser {
+    // 'requires' is not reserved, so it only begins a declaration when followed by a capability
+    pub fn requires_declaration_ahead(&self) -> bool {
+        self.contextual_definition_ahead("requires")
+    }
+
+    pub fn requires(&mut self) -> Result<(), ParserStatus> {
+        debug_information!("requires");
+
+        self.current += 1;
+
+        loop {
+            let capability = match self.current_token_type() {
+                Some(TokenType::String(name)) => Capability::parse(name),
+                _ => {
+                    self.error.add(ParserErrType::RequiresExpectedCapability(
+                        self.tokens[self.current - 1].clone(),
+                        self.tokens.get(self.current).cloned(),
+                    ));
+                    return Err(ParserStatus::Unwind);
+                }
+            };
+
+            match capability {
+                Some(capability) => self.capabilities.push(capability),
+                None => self.error.add(ParserErrType::RequiresInvalidCapability(
+                    self.tokens[self.current].clone(),
+                )),
+            }
+
+            self.current += 1;
+
+            match self.consume_token_type() {
+                Some(TokenType::Comma) => continue,
+                Some(TokenType::SemiColon) => break,
+                _ => {
+                    self.error
+                        .add(ParserErrType::RequiresExpectedCommaOrSemicolon(
+                            self.tokens[self.current - 2].clone(),
+                            self.tokens.get(self.current - 1).cloned(),
+                        ));
+                    return Err(ParserStatus::Unwind);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -9,6 +9,8 @@ impl Parser {
         let start_token = self.tokens[self.current].clone();
         self.current += 1;
 
+        self.begin_debug_callable(None, String::from("start"));
+
         // Add start environment scope
         self.environments.push(FxHashMap::default());
 
@@ -16,6 +18,7 @@ impl Parser {
 
         // Clean environments after it has been parsed
         self.environments.clear();
+        self.close_debug_callable();
         self.integer_next_id = 0;
         self.float_next_id = 0;
         self.string_next_id = 0;
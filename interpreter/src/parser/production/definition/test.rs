@@ -0,0 +1,55 @@
+use rustc_hash::FxHashMap;
+
+use crate::{ast::Test, parser::production::definition::prelude::*};
+use std::rc::Rc;
+
+impl Parser {
+    // 'test' is not reserved, so it only begins a test definition when followed by its name
+    pub fn test_definition_ahead(&self) -> bool {
+        self.contextual_definition_ahead("test")
+    }
+
+    pub fn test(&mut self) -> Result<(), ParserStatus> {
+        debug_information!("test");
+
+        let test_token = self.tokens[self.current].clone();
+
+        let name = match &self.tokens[self.current + 1].token_type {
+            TokenType::String(name) => Rc::clone(name),
+            _ => unreachable!("Checked by test_definition_ahead"),
+        };
+        self.current += 2;
+
+        self.begin_debug_callable(None, format!("test \"{name}\""));
+
+        // Add test environment scope
+        self.environments.push(FxHashMap::default());
+
+        let block = self.block();
+
+        // Clean environments after it has been parsed
+        self.environments.clear();
+        self.close_debug_callable();
+        self.integer_next_id = 0;
+        self.float_next_id = 0;
+        self.string_next_id = 0;
+        self.boolean_next_id = 0;
+
+        if let Some(first) = self.tests.iter().find(|test| test.name == name) {
+            self.error.add(ParserErrType::RedefinedTest(
+                first.token.clone(),
+                test_token,
+                name.to_string(),
+            ));
+            return Err(ParserStatus::Unwind);
+        }
+
+        self.tests.push(Test {
+            name,
+            token: test_token,
+            block: block?,
+        });
+
+        Ok(())
+    }
+}
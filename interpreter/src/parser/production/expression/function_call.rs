@@ -85,6 +85,35 @@ impl Parser {
                         Box::new(arguments.remove(0)),
                         true,
                     )))),
+                    "assert" => Ok(Expr::None(NoneExpr::NativeCall(NativeCallNone::Assert(
+                        Box::new(arguments.remove(0).to_boolean_expr()),
+                        self.tokens[token_pos - 1].clone(),
+                    )))),
+                    "assert_eq" => {
+                        let (left, right) = (arguments.remove(0), arguments.remove(0));
+
+                        if self.expr_type(&left) != self.expr_type(&right) {
+                            self.error.add(ParserErrType::CallArgumentIncorrectType(
+                                self.tokens[token_pos - 1].clone(),
+                                1,
+                                self.expr_type(&right),
+                                name.to_string(),
+                            ));
+                            return Err(ParserStatus::Unwind);
+                        }
+
+                        Ok(Expr::None(NoneExpr::NativeCall(
+                            NativeCallNone::AssertEqual(
+                                Box::new(left),
+                                Box::new(right),
+                                self.tokens[token_pos - 1].clone(),
+                            ),
+                        )))
+                    }
+                    "fail" => Ok(Expr::None(NoneExpr::NativeCall(NativeCallNone::Fail(
+                        Box::new(arguments.remove(0).to_string_expr()),
+                        self.tokens[token_pos - 1].clone(),
+                    )))),
                     "power" => Ok(Expr::Integer(IntegerExpr::NativeCall(
                         NativeCallInteger::Power(
                             Box::new(arguments.remove(0).to_integer_expr()),
@@ -126,9 +155,126 @@ impl Parser {
                             Box::new(arguments.remove(0).to_string_expr()),
                         ),
                     ))),
+                    "storage_get" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::StorageGet(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        )),
+                    ))),
+                    "storage_set" => Ok(Expr::None(NoneExpr::NativeCall(
+                        NativeCallNone::StorageSet(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_string_expr()),
+                        ),
+                    ))),
+                    "storage_remove" => Ok(Expr::None(NoneExpr::NativeCall(
+                        NativeCallNone::StorageRemove(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        )),
+                    ))),
+                    "storage_keys" => Ok(Expr::Object(
+                        Rc::new("[String]".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::StorageKeys),
+                    )),
+                    "get_cookie" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::GetCookie(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_string_expr()),
+                        ),
+                    ))),
+                    "cookie_names" => Ok(Expr::Object(
+                        Rc::new("[String]".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::CookieNames(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "clear_cookies" => Ok(Expr::None(NoneExpr::NativeCall(
+                        NativeCallNone::ClearCookies(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        )),
+                    ))),
+                    "read_string_async" => Ok(Expr::Object(
+                        Rc::new("AsyncRequest".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::ReadStringAsync(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "write_string_async" => Ok(Expr::Object(
+                        Rc::new("AsyncRequest".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::WriteStringAsync(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_string_expr()),
+                        )),
+                    )),
                     "wait_for_event" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
                         NativeCallBoolean::WaitForEvent,
                     ))),
+                    "set_timeout" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                        NativeCallInteger::StartTimer(
+                            Box::new(arguments.remove(0).to_integer_expr()),
+                            false,
+                        ),
+                    ))),
+                    "set_interval" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                        NativeCallInteger::StartTimer(
+                            Box::new(arguments.remove(0).to_integer_expr()),
+                            true,
+                        ),
+                    ))),
+                    "timer_fired" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                        NativeCallBoolean::TimerFired(Box::new(
+                            arguments.remove(0).to_integer_expr(),
+                        )),
+                    ))),
+                    "clear_timer" => Ok(Expr::None(NoneExpr::NativeCall(
+                        NativeCallNone::ClearTimer(Box::new(arguments.remove(0).to_integer_expr())),
+                    ))),
+                    "key_pressed" => {
+                        self.subscriptions.key_pressed = true;
+                        Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::KeyPressed,
+                        )))
+                    }
+                    "key_name" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::KeyName,
+                    ))),
+                    "shift_held" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                        NativeCallBoolean::ShiftHeld,
+                    ))),
+                    "control_held" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                        NativeCallBoolean::ControlHeld,
+                    ))),
+                    "alt_held" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                        NativeCallBoolean::AltHeld,
+                    ))),
+                    "mouse_moved" => {
+                        self.subscriptions.mouse_moved = true;
+                        Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::MouseMoved,
+                        )))
+                    }
+                    "mouse_clicked" => {
+                        self.subscriptions.mouse_clicked = true;
+                        Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::MouseClicked,
+                        )))
+                    }
+                    "mouse_button" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::MouseButton,
+                    ))),
+                    "mouse_x" => Ok(Expr::Float(FloatExpr::NativeCall(NativeCallFloat::MouseX))),
+                    "mouse_y" => Ok(Expr::Float(FloatExpr::NativeCall(NativeCallFloat::MouseY))),
+                    "window_resized" => {
+                        self.subscriptions.window_resized = true;
+                        Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::WindowResized,
+                        )))
+                    }
+                    "window_width" => Ok(Expr::Float(FloatExpr::NativeCall(
+                        NativeCallFloat::WindowWidth,
+                    ))),
+                    "window_height" => Ok(Expr::Float(FloatExpr::NativeCall(
+                        NativeCallFloat::WindowHeight,
+                    ))),
                     "integer_to_string" => Ok(Expr::String(StringExpr::NativeCall(
                         NativeCallString::FromInteger(arguments.remove(0).to_integer_expr()),
                     ))),
@@ -189,6 +335,112 @@ impl Parser {
                             Box::new(arguments.remove(0).to_string_expr()),
                         )),
                     )),
+                    "HttpRequest" => Ok(Expr::Object(
+                        Rc::new("HttpRequest".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::HttpRequestConstructor(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_string_expr()),
+                        )),
+                    )),
+                    "Socket" => Ok(Expr::Object(
+                        Rc::new("Socket".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::SocketConstructor(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "Json" => Ok(Expr::Object(
+                        Rc::new("Json".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::JsonConstructor),
+                    )),
+                    "json_parse" => Ok(Expr::Object(
+                        Rc::new("Json".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::JsonParse(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            self.tokens[token_pos - 1].clone(),
+                        )),
+                    )),
+                    "Checkbox" => Ok(Expr::Object(
+                        Rc::new("Checkbox".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::CheckboxConstructor(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "Toggle" => Ok(Expr::Object(
+                        Rc::new("Toggle".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::ToggleConstructor(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "RadioGroup" => Ok(Expr::Object(
+                        Rc::new("RadioGroup".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::RadioGroupConstructor),
+                    )),
+                    "Slider" => Ok(Expr::Object(
+                        Rc::new("Slider".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::SliderConstructor(
+                            Box::new(arguments.remove(0).to_float_expr()),
+                            Box::new(arguments.remove(0).to_float_expr()),
+                        )),
+                    )),
+                    "Dropdown" => Ok(Expr::Object(
+                        Rc::new("Dropdown".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::DropdownConstructor(Box::new(
+                            arguments.remove(0).to_object_expr(),
+                        ))),
+                    )),
+                    "TextArea" => Ok(Expr::Object(
+                        Rc::new("TextArea".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::TextAreaConstructor(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        ))),
+                    )),
+                    "Table" => Ok(Expr::Object(
+                        Rc::new("Table".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::TableConstructor(Box::new(
+                            arguments.remove(0).to_object_expr(),
+                        ))),
+                    )),
+                    "Scroll" => Ok(Expr::Object(
+                        Rc::new("Scroll".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::ScrollConstructor(Box::new(
+                            arguments.remove(0).to_float_expr(),
+                        ))),
+                    )),
+                    "Stack" => Ok(Expr::Object(
+                        Rc::new("Stack".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::StackConstructor),
+                    )),
+                    "dialog" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                        NativeCallInteger::Dialog(
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_string_expr()),
+                            Box::new(arguments.remove(0).to_object_expr()),
+                        ),
+                    ))),
+                    "clipboard_get" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::ClipboardGet,
+                    ))),
+                    "clipboard_set" => Ok(Expr::None(NoneExpr::NativeCall(
+                        NativeCallNone::ClipboardSet(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        )),
+                    ))),
+                    "Style" => Ok(Expr::Object(
+                        Rc::new("Style".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::StyleConstructor),
+                    )),
+                    "palette_colour" => Ok(Expr::String(StringExpr::NativeCall(
+                        NativeCallString::PaletteColour(Box::new(
+                            arguments.remove(0).to_string_expr(),
+                        )),
+                    ))),
+                    "Canvas" => Ok(Expr::Object(
+                        Rc::new("Canvas".to_string()),
+                        ObjectExpr::NativeCall(NativeCallObject::CanvasConstructor(
+                            Box::new(arguments.remove(0).to_float_expr()),
+                            Box::new(arguments.remove(0).to_float_expr()),
+                        )),
+                    )),
                     "Input" => Ok(Expr::Object(
                         Rc::new("Input".to_string()),
                         ObjectExpr::NativeCall(NativeCallObject::InputConstructor(Box::new(
@@ -119,7 +119,23 @@ impl Parser {
                     (Expr::Object(class, _), ValueType::Element)
                         if matches!(
                             class.as_str(),
-                            "Button" | "Text" | "Hyperlink" | "Input" | "Row" | "Column" | "Image"
+                            "Button"
+                                | "Text"
+                                | "Hyperlink"
+                                | "Input"
+                                | "Row"
+                                | "Column"
+                                | "Image"
+                                | "Checkbox"
+                                | "Toggle"
+                                | "RadioGroup"
+                                | "Slider"
+                                | "Dropdown"
+                                | "TextArea"
+                                | "Table"
+                                | "Canvas"
+                                | "Scroll"
+                                | "Stack"
                         ) => {}
                     (Expr::Object(class, _), ValueType::Class(name)) if class == name => (),
                     (expr, _) => {
@@ -195,6 +211,13 @@ impl Parser {
                                 object,
                             ))),
                         )),
+                        "set_style" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SetStyle(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
                         _ => unreachable!(),
                     },
                     "Input" => match name.as_str() {
@@ -213,6 +236,385 @@ impl Parser {
                         )),
                         _ => unreachable!(),
                     },
+                    "Checkbox" => match name.as_str() {
+                        "checked" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CheckboxChecked(Box::new(object)),
+                        ))),
+                        "set_checked" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CheckboxSetChecked(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CheckboxChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Toggle" => match name.as_str() {
+                        "on" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::ToggleOn(Box::new(object)),
+                        ))),
+                        "set_on" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::ToggleSetOn(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::ToggleChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "RadioGroup" => match name.as_str() {
+                        "add_option" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::RadioGroupAddOption(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "selected" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                            NativeCallInteger::RadioGroupSelected(Box::new(object)),
+                        ))),
+                        "selected_option" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::RadioGroupSelectedOption(Box::new(object)),
+                        ))),
+                        "set_selected" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::RadioGroupSetSelected(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::RadioGroupChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Slider" => match name.as_str() {
+                        "value" => Ok(Expr::Float(FloatExpr::NativeCall(
+                            NativeCallFloat::SliderValue(Box::new(object)),
+                        ))),
+                        "set_value" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SliderSetValue(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "set_step" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SliderSetStep(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::SliderChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Dropdown" => match name.as_str() {
+                        "selected" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::DropdownSelected(Box::new(object)),
+                        ))),
+                        "set_selected" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::DropdownSetSelected(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_placeholder" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::DropdownSetPlaceholder(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::DropdownChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "TextArea" => match name.as_str() {
+                        "get_text" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::TextAreaGetText(Box::new(object)),
+                        ))),
+                        "set_text" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextAreaSetText(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::TextAreaChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Table" => match name.as_str() {
+                        "add_row" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TableAddRow(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
+                        "remove_row" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TableRemoveRow(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            )),
+                        )),
+                        "row_count" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                            NativeCallInteger::TableRowCount(Box::new(object)),
+                        ))),
+                        "get_cell" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::TableGetCell(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            ),
+                        ))),
+                        "set_column_width" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TableSetColumnWidth(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            )),
+                        )),
+                        "sort" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TableSort(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            )),
+                        )),
+                        "selected" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                            NativeCallInteger::TableSelected(Box::new(object)),
+                        ))),
+                        "set_selected" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TableSetSelected(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                                self.tokens[token_pos + 1].clone(),
+                            )),
+                        )),
+                        "sort_changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::TableSortChanged(Box::new(object)),
+                        ))),
+                        "selection_changed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::TableSelectionChanged(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Canvas" => match name.as_str() {
+                        "set_fill" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasSetFill(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_stroke" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasSetStroke(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_stroke_width" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasSetStrokeWidth(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "set_font_size" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasSetFontSize(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "line" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasLine(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "rect" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasRect(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "circle" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasCircle(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "path" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasPath(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "text" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasText(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "clear" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::CanvasClear(Box::new(object))),
+                        )),
+                        "pointer_x" => Ok(Expr::Float(FloatExpr::NativeCall(
+                            NativeCallFloat::CanvasPointerX(Box::new(object)),
+                        ))),
+                        "pointer_y" => Ok(Expr::Float(FloatExpr::NativeCall(
+                            NativeCallFloat::CanvasPointerY(Box::new(object)),
+                        ))),
+                        "pointer_down" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CanvasPointerDown(Box::new(object)),
+                        ))),
+                        "pointer_pressed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CanvasPointerPressed(Box::new(object)),
+                        ))),
+                        "pointer_released" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CanvasPointerReleased(Box::new(object)),
+                        ))),
+                        "pointer_moved" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::CanvasPointerMoved(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Scroll" => match name.as_str() {
+                        "add" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::ScrollAddElement(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
+                        "remove" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::ScrollRemoveElement(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
+                        "set_height" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::ScrollSetHeight(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        _ => unreachable!(),
+                    },
+                    "Stack" => match name.as_str() {
+                        "add" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StackAddElement(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
+                        "remove" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StackRemoveElement(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
+                        _ => unreachable!(),
+                    },
+                    "Style" => match name.as_str() {
+                        "set_text_size" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetTextSize(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "set_text_colour" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetTextColour(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_button_colour" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetButtonColour(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_button_text_colour" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetButtonTextColour(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_button_padding" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetButtonPadding(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "set_background_colour" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetBackgroundColour(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_padding" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::StyleSetPadding(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        _ => unreachable!(),
+                    },
                     "Text" => match name.as_str() {
                         "set_text" => Ok(Expr::Object(
                             Rc::clone(&class),
@@ -235,6 +637,55 @@ impl Parser {
                                 Box::new(arguments.remove(0).to_string_expr()),
                             )),
                         )),
+                        "set_bold" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetBold(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "set_italic" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetItalic(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "set_font" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetFont(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_alignment" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetAlignment(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_line_height" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetLineHeight(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_float_expr()),
+                            )),
+                        )),
+                        "set_wrap" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::TextSetWrap(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_boolean_expr()),
+                            )),
+                        )),
+                        "set_style" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SetStyle(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_object_expr()),
+                            )),
+                        )),
                         _ => unreachable!(),
                     },
                     "Page" => match name.as_str() {
@@ -284,7 +735,7 @@ impl Parser {
                                 Box::new(arguments.remove(0).to_string_expr()),
                             )),
                         )),
-                        _ => unreachable!(),
+                        _ => Self::layout_method_call(class, &name, object, arguments),
                     },
                     "Row" => match name.as_str() {
                         "add" => Ok(Expr::Object(
@@ -305,7 +756,7 @@ impl Parser {
                             Rc::clone(&class),
                             ObjectExpr::NativeCall(NativeCallObject::RowCenter(Box::new(object))),
                         )),
-                        _ => unreachable!(),
+                        _ => Self::layout_method_call(class, &name, object, arguments),
                     },
                     "Column" => match name.as_str() {
                         "add" => Ok(Expr::Object(
@@ -329,7 +780,7 @@ impl Parser {
                                 arguments.remove(0).to_float_expr(),
                             )),
                         )),
-                        _ => unreachable!(),
+                        _ => Self::layout_method_call(class, &name, object, arguments),
                     },
                     "Image" => match name.as_str() {
                         "set_max_width" => Ok(Expr::Object(
@@ -339,8 +790,170 @@ impl Parser {
                                 arguments.remove(0).to_float_expr(),
                             )),
                         )),
+                        "loaded" => {
+                            self.subscriptions.image_loaded = true;
+                            Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                                NativeCallBoolean::ImageLoaded(Box::new(object)),
+                            )))
+                        }
+                        _ => unreachable!(),
+                    },
+                    "AsyncRequest" => match name.as_str() {
+                        "finished" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::AsyncRequestFinished(Box::new(object)),
+                        ))),
+                        "failed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::AsyncRequestFailed(Box::new(object)),
+                        ))),
+                        "result" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::AsyncRequestResult(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "Socket" => match name.as_str() {
+                        "send" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SocketSend(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "close" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::SocketClose(Box::new(object))),
+                        )),
+                        "has_message" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::SocketHasMessage(Box::new(object)),
+                        ))),
+                        "next_message" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::SocketNextMessage(Box::new(object)),
+                        ))),
+                        "connected" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::SocketConnected(Box::new(object)),
+                        ))),
+                        "closed" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                            NativeCallBoolean::SocketClosed(Box::new(object)),
+                        ))),
+                        _ => unreachable!(),
+                    },
+                    "HttpRequest" => match name.as_str() {
+                        "set_header" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::HttpRequestSetHeader(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_body" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::HttpRequestSetBody(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            )),
+                        )),
+                        "set_timeout" => Ok(Expr::Object(
+                            Rc::clone(&class),
+                            ObjectExpr::NativeCall(NativeCallObject::HttpRequestSetTimeout(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_integer_expr()),
+                            )),
+                        )),
+                        "send" => Ok(Expr::Object(
+                            Rc::new("HttpResponse".to_string()),
+                            ObjectExpr::NativeCall(NativeCallObject::HttpRequestSend(Box::new(
+                                object,
+                            ))),
+                        )),
+                        _ => unreachable!(),
+                    },
+                    "HttpResponse" => match name.as_str() {
+                        "status" => Ok(Expr::Integer(IntegerExpr::NativeCall(
+                            NativeCallInteger::HttpResponseStatus(Box::new(object)),
+                        ))),
+                        "header" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::HttpResponseHeader(
+                                Box::new(object),
+                                Box::new(arguments.remove(0).to_string_expr()),
+                            ),
+                        ))),
+                        "header_names" => Ok(Expr::Object(
+                            Rc::new("[String]".into()),
+                            ObjectExpr::NativeCall(NativeCallObject::HttpResponseHeaderNames(
+                                Box::new(object),
+                            )),
+                        )),
+                        "body" => Ok(Expr::String(StringExpr::NativeCall(
+                            NativeCallString::HttpResponseBody(Box::new(object)),
+                        ))),
                         _ => unreachable!(),
                     },
+                    "Json" => {
+                        let token = self.tokens[token_pos + 1].clone();
+
+                        // Getters take the key of the value, while the same conversions are
+                        // applied to the value itself by the equivalent "as" methods
+                        let key = match name.as_str() {
+                            "get_string" | "get_integer" | "get_float" | "get_boolean"
+                            | "get_array" => Some(Box::new(arguments.remove(0).to_string_expr())),
+                            _ => None,
+                        };
+
+                        match name.as_str() {
+                            "has" => Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                                NativeCallBoolean::JsonHas(
+                                    Box::new(object),
+                                    Box::new(arguments.remove(0).to_string_expr()),
+                                ),
+                            ))),
+                            "get_string" | "as_string" => Ok(Expr::String(StringExpr::NativeCall(
+                                NativeCallString::JsonGetString(Box::new(object), key, token),
+                            ))),
+                            "get_integer" | "as_integer" => {
+                                Ok(Expr::Integer(IntegerExpr::NativeCall(
+                                    NativeCallInteger::JsonGetInteger(Box::new(object), key, token),
+                                )))
+                            }
+                            "get_float" | "as_float" => Ok(Expr::Float(FloatExpr::NativeCall(
+                                NativeCallFloat::JsonGetFloat(Box::new(object), key, token),
+                            ))),
+                            "get_boolean" | "as_boolean" => {
+                                Ok(Expr::Boolean(BooleanExpr::NativeCall(
+                                    NativeCallBoolean::JsonGetBoolean(Box::new(object), key, token),
+                                )))
+                            }
+                            "get_object" => Ok(Expr::Object(
+                                Rc::clone(&class),
+                                ObjectExpr::NativeCall(NativeCallObject::JsonGetObject(
+                                    Box::new(object),
+                                    Box::new(arguments.remove(0).to_string_expr()),
+                                    token,
+                                )),
+                            )),
+                            "get_array" | "as_array" => Ok(Expr::Object(
+                                Rc::new("[Json]".into()),
+                                ObjectExpr::NativeCall(NativeCallObject::JsonGetArray(
+                                    Box::new(object),
+                                    key,
+                                    token,
+                                )),
+                            )),
+                            "set_string" | "set_integer" | "set_float" | "set_boolean"
+                            | "set_object" | "set_array" => Ok(Expr::Object(
+                                Rc::clone(&class),
+                                ObjectExpr::NativeCall(NativeCallObject::JsonSet(
+                                    Box::new(object),
+                                    Box::new(arguments.remove(0).to_string_expr()),
+                                    Box::new(arguments.remove(0)),
+                                    token,
+                                )),
+                            )),
+                            "to_string" => Ok(Expr::String(StringExpr::NativeCall(
+                                NativeCallString::JsonToString(Box::new(object)),
+                            ))),
+                            _ => unreachable!(),
+                        }
+                    }
                     array_object => {
                         match (name.as_str(), &array_object[1..array_object.len() - 1]) {
                             ("get", "Integer") => Ok(Expr::Integer(IntegerExpr::NativeCall(
@@ -491,4 +1104,67 @@ impl Parser {
             Err(ParserStatus::Unwind)
         }
     }
+
+    fn layout_method_call(
+        class: Rc<String>,
+        name: &str,
+        object: ObjectExpr,
+        mut arguments: Vec<Expr>,
+    ) -> Result<Expr, ParserStatus> {
+        let object = Box::new(object);
+
+        let call = match name {
+            "set_padding" => NativeCallObject::LayoutSetPadding(
+                object,
+                Box::new(arguments.remove(0).to_float_expr()),
+                Box::new(arguments.remove(0).to_float_expr()),
+            ),
+            "set_spacing" => NativeCallObject::LayoutSetSpacing(
+                object,
+                Box::new(arguments.remove(0).to_float_expr()),
+            ),
+            "set_horizontal_alignment" => NativeCallObject::LayoutSetAlignment(
+                object,
+                Box::new(arguments.remove(0).to_string_expr()),
+                true,
+            ),
+            "set_vertical_alignment" => NativeCallObject::LayoutSetAlignment(
+                object,
+                Box::new(arguments.remove(0).to_string_expr()),
+                false,
+            ),
+            "set_width" => NativeCallObject::LayoutSetWidth(
+                object,
+                Some(Box::new(arguments.remove(0).to_float_expr())),
+            ),
+            "set_width_fill" => NativeCallObject::LayoutSetWidth(object, None),
+            "set_height" => NativeCallObject::LayoutSetHeight(
+                object,
+                Some(Box::new(arguments.remove(0).to_float_expr())),
+            ),
+            "set_height_fill" => NativeCallObject::LayoutSetHeight(object, None),
+            "set_background_colour" => NativeCallObject::LayoutSetBackgroundColour(
+                object,
+                Box::new(arguments.remove(0).to_string_expr()),
+            ),
+            "set_border_colour" => NativeCallObject::LayoutSetBorderColour(
+                object,
+                Box::new(arguments.remove(0).to_string_expr()),
+            ),
+            "set_border_width" => NativeCallObject::LayoutSetBorderWidth(
+                object,
+                Box::new(arguments.remove(0).to_float_expr()),
+            ),
+            "set_border_radius" => NativeCallObject::LayoutSetBorderRadius(
+                object,
+                Box::new(arguments.remove(0).to_float_expr()),
+            ),
+            "set_style" => {
+                NativeCallObject::SetStyle(object, Box::new(arguments.remove(0).to_object_expr()))
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(Expr::Object(class, ObjectExpr::NativeCall(call)))
+    }
 }
@@ -5,7 +5,7 @@ mod statement;
 
 use crate::{
     expr::Expr,
-    parser::{production::prelude::*, value::ValueType},
+    parser::{location::Location, production::prelude::*, value::ValueType},
     parser_debug,
     stack::Stack,
 };
@@ -23,6 +23,8 @@ impl Parser {
                 TokenType::Start => self.start(),
                 TokenType::Function => self.function(),
                 TokenType::Class => self.class(),
+                TokenType::Identifier(_) if self.test_definition_ahead() => self.test(),
+                TokenType::Identifier(_) if self.requires_declaration_ahead() => self.requires(),
                 _ => {
                     self.error.add(ParserErrType::UnexpectedTokenInGlobal(
                         self.tokens[self.current].clone(),
@@ -43,6 +45,10 @@ impl Parser {
                             break;
                         }
 
+                        if self.test_definition_ahead() || self.requires_declaration_ahead() {
+                            break;
+                        }
+
                         self.current += 1;
                     }
                     // Clean up any state from unfinished parsing
@@ -86,6 +92,33 @@ impl Parser {
         }
     }
 
+    fn declare_variable(&mut self, name: Rc<String>, location: Location, position: usize) {
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            debug_symbols.add_variable(Rc::clone(&name), location.clone(), position);
+        }
+
+        self.environments.last_mut().unwrap().insert(name, location);
+    }
+
+    // Ends the scope of variables declared since the position for the debug symbols
+    fn close_debug_scope(&mut self, opened: usize) {
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            debug_symbols.close_scope(opened, self.tokens[self.current - 1].end);
+        }
+    }
+
+    fn begin_debug_callable(&mut self, callable: Option<usize>, name: String) {
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            debug_symbols.begin_callable(callable, name);
+        }
+    }
+
+    fn close_debug_callable(&mut self) {
+        if let Some(debug_symbols) = &mut self.debug_symbols {
+            debug_symbols.close_callable(self.tokens[self.current - 1].end);
+        }
+    }
+
     fn stack(&self) -> Stack {
         Stack {
             integer: self.integer_next_id,
@@ -34,6 +34,7 @@ impl Parser {
                 Some(TokenType::RightBrace) => {
                     self.current += 1;
                     self.environments.pop();
+                    self.close_debug_scope(self.tokens[open_brace_pos].start);
 
                     self.integer_next_id = integer_point;
                     self.float_next_id = float_point;
@@ -6,6 +6,8 @@ impl Parser {
     pub fn for_statement(&mut self) -> Result<Stmt, ParserStatus> {
         debug_information!("for_statement");
 
+        let for_position = self.tokens[self.current - 1].start;
+
         match self.tokens.get(self.current) {
             Some(Token {
                 token_type: TokenType::LeftParen,
@@ -124,6 +126,7 @@ impl Parser {
         let block = Stmt::Block(vec![statement, update_statement], self.stack());
 
         self.environments.pop();
+        self.close_debug_scope(for_position);
         self.integer_next_id = integer_point;
         self.float_next_id = float_point;
         self.string_next_id = string_point;
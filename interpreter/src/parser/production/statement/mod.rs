@@ -14,6 +14,32 @@ impl Parser {
     pub fn statement(&mut self) -> Result<Stmt, ParserStatus> {
         debug_information!("statement");
 
+        // Blocks are not traced, as their statements already are
+        let position = match self.tokens.get(self.current) {
+            Some(Token {
+                token_type: TokenType::LeftBrace,
+                ..
+            }) => None,
+            token => token.map(|token| token.start),
+        };
+
+        let branches = matches!(
+            self.current_token_type(),
+            Some(TokenType::If | TokenType::While | TokenType::For)
+        );
+
+        let statement = self.untraced_statement()?;
+
+        match (&mut self.debug_symbols, position) {
+            (Some(debug_symbols), Some(position)) => {
+                debug_symbols.add_statement(position, branches);
+                Ok(Stmt::Traced(position, Box::new(statement)))
+            }
+            _ => Ok(statement),
+        }
+    }
+
+    fn untraced_statement(&mut self) -> Result<Stmt, ParserStatus> {
         match self.current_token_type() {
             Some(TokenType::LeftBrace) => {
                 self.nested_scope_limit += 1;
@@ -4,6 +4,7 @@ use std::rc::Rc;
 impl Parser {
     pub fn variable_init(&mut self) -> Result<Stmt, ParserStatus> {
         debug_information!("variable_init");
+        let let_position = self.tokens[self.current].start;
         self.current += 1;
 
         let name = match self.tokens.get(self.current) {
@@ -62,37 +63,25 @@ impl Parser {
             Expr::Integer(val) => {
                 let id = self.integer_next_id;
                 self.integer_next_id += 1;
-                self.environments
-                    .last_mut()
-                    .unwrap()
-                    .insert(name, Location::Integer(id));
+                self.declare_variable(name, Location::Integer(id), let_position);
                 Ok(Stmt::IntegerVariableInitialisation(val))
             }
             Expr::Float(val) => {
                 let id = self.float_next_id;
                 self.float_next_id += 1;
-                self.environments
-                    .last_mut()
-                    .unwrap()
-                    .insert(name, Location::Float(id));
+                self.declare_variable(name, Location::Float(id), let_position);
                 Ok(Stmt::FloatVariableInitialisation(val))
             }
             Expr::String(val) => {
                 let id = self.string_next_id;
                 self.string_next_id += 1;
-                self.environments
-                    .last_mut()
-                    .unwrap()
-                    .insert(name, Location::String(id));
+                self.declare_variable(name, Location::String(id), let_position);
                 Ok(Stmt::StringVariableInitialisation(val))
             }
             Expr::Boolean(val) => {
                 let id = self.boolean_next_id;
                 self.boolean_next_id += 1;
-                self.environments
-                    .last_mut()
-                    .unwrap()
-                    .insert(name, Location::Boolean(id));
+                self.declare_variable(name, Location::Boolean(id), let_position);
                 Ok(Stmt::BooleanVariableInitialisation(val))
             }
             Expr::None(_) => {
@@ -106,10 +95,7 @@ impl Parser {
             Expr::Object(class, val) => {
                 let id = self.object_next_id;
                 self.object_next_id += 1;
-                self.environments
-                    .last_mut()
-                    .unwrap()
-                    .insert(name, Location::Object(class, id));
+                self.declare_variable(name, Location::Object(class, id), let_position);
                 Ok(Stmt::ObjectVariableInitialisation(val))
             }
         }
@@ -1,15 +1,522 @@
-use crate::{address::AddressType, Address};
+use crate::{
+    address::{Address, AddressType},
+    event::{InterpreterEvent, PermissionRequest},
+    tree_walker::err::TreeWalkerErr,
+};
+use directories_next::ProjectDirs;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Sender},
+        Mutex,
+    },
+};
 
-pub enum PermissionLevel {
-    All,
-    NetworkOnly,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Capability {
+    FileRead,
+    FileWrite,
+    // Requests to an origin other than the one the script was loaded from
+    Network(String),
+    // Connections to a host and port with a Socket
+    Socket(String),
+    Applications,
+    // Listing the applications installed in the browser
+    Settings,
+    Clipboard,
 }
 
-impl PermissionLevel {
+impl Capability {
+    // Parses the name used to declare the capability in a script
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "file_read" => Some(Self::FileRead),
+            "file_write" => Some(Self::FileWrite),
+            "applications" => Some(Self::Applications),
+            "settings" => Some(Self::Settings),
+            "clipboard" => Some(Self::Clipboard),
+            name if name.starts_with("socket:") => {
+                Some(Self::Socket(socket_address(name.strip_prefix("socket:")?)?))
+            }
+            name => {
+                let origin = Address::new(name.strip_prefix("network:")?, vec![]).origin()?;
+                Some(Self::Network(origin))
+            }
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Self::FileRead => String::from("Read files on this computer"),
+            Self::FileWrite => String::from("Write files on this computer"),
+            Self::Network(origin) => format!("Send requests to {origin}"),
+            Self::Socket(address) => format!("Connect to {address}"),
+            Self::Applications => String::from("Install and remove applications"),
+            Self::Settings => String::from("See the applications installed in the browser"),
+            Self::Clipboard => String::from("Read and write the clipboard"),
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FileRead => write!(f, "file_read"),
+            Self::FileWrite => write!(f, "file_write"),
+            Self::Network(origin) => write!(f, "network:{origin}"),
+            Self::Socket(address) => write!(f, "socket:{address}"),
+            Self::Applications => write!(f, "applications"),
+            Self::Settings => write!(f, "settings"),
+            Self::Clipboard => write!(f, "clipboard"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    // Scripts bundled with the browser, such as the settings page
+    Internal,
+    Local(String),
+    Network(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Permissions {
+    source: Source,
+    granted: FxHashSet<Capability>,
+    // Origins that the server of a network script allows it to send requests to
+    allowed_origins: FxHashSet<String>,
+}
+
+impl Permissions {
     pub fn new(address: &Address) -> Self {
-        match address.address_type {
-            AddressType::HTTP { .. } => PermissionLevel::NetworkOnly,
-            _ => PermissionLevel::All,
+        let source = match &address.address_type {
+            AddressType::Zonkey => Source::Internal,
+            AddressType::File | AddressType::Installed => Source::Local(address.to_string()),
+            AddressType::HTTP { .. } => match address.origin() {
+                Some(origin) => Source::Network(origin),
+                None => Source::Network(address.to_string()),
+            },
+        };
+
+        Self {
+            source,
+            granted: FxHashSet::default(),
+            allowed_origins: FxHashSet::default(),
+        }
+    }
+
+    pub fn all() -> Self {
+        Self {
+            source: Source::Internal,
+            granted: FxHashSet::default(),
+            allowed_origins: FxHashSet::default(),
+        }
+    }
+
+    // Reads the allow-list of other origins from the zonkey.json manifest in the same directory
+    // as a network script. A missing or invalid manifest allows no other origins.
+    pub fn load_manifest(&mut self, address: &Address) {
+        let Source::Network(_) = self.source else {
+            return;
+        };
+
+        let Some(manifest) = manifest_address(address) else {
+            return;
+        };
+
+        if let Ok(contents) = manifest.read_string() {
+            self.allowed_origins.extend(parse_manifest(&contents));
+        }
+    }
+
+    // Network scripts can make requests to their own origin and those allowed by their manifest.
+    // Anything else, including file access for local scripts, must be declared and allowed.
+    pub fn allows(&self, capability: &Capability) -> bool {
+        match (&self.source, capability) {
+            (Source::Internal, _) => true,
+            (Source::Network(origin), Capability::Network(target))
+                if origin == target || self.allowed_origins.contains(target) =>
+            {
+                true
+            }
+            _ => self.granted.contains(capability),
+        }
+    }
+
+    pub fn check(&self, capability: Capability) -> Result<(), TreeWalkerErr> {
+        match self.allows(&capability) {
+            true => Ok(()),
+            false => Err(TreeWalkerErr::PermissionDenied(capability)),
+        }
+    }
+
+    pub fn check_read(&self, address: &Address) -> Result<(), TreeWalkerErr> {
+        match &address.address_type {
+            AddressType::HTTP { .. } => self.check_network(address),
+            _ => self.check(Capability::FileRead),
+        }
+    }
+
+    pub fn check_write(&self, address: &Address) -> Result<(), TreeWalkerErr> {
+        match &address.address_type {
+            AddressType::HTTP { .. } => self.check_network(address),
+            _ => self.check(Capability::FileWrite),
+        }
+    }
+
+    pub fn check_network(&self, address: &Address) -> Result<(), TreeWalkerErr> {
+        match address.origin() {
+            Some(origin) => self.check(Capability::Network(origin)),
+            None => Err(TreeWalkerErr::PermissionDenied(Capability::Network(
+                address.to_string(),
+            ))),
+        }
+    }
+
+    pub fn check_socket(&self, address: &str) -> Result<(), TreeWalkerErr> {
+        match socket_address(address) {
+            Some(address) => self.check(Capability::Socket(address)),
+            None => Err(TreeWalkerErr::SocketFailed(format!(
+                "{address} is not a valid host and port"
+            ))),
+        }
+    }
+
+    pub fn grant_all(&mut self, capabilities: &[Capability]) {
+        self.granted.extend(capabilities.iter().cloned());
+    }
+
+    // Asks the user for each declared capability that has not been allowed or denied for this
+    // origin before, and remembers their choice. Capabilities are denied if the user cannot be
+    // asked.
+    pub fn request(&mut self, capabilities: &[Capability], sender: &Sender<InterpreterEvent>) {
+        let key = match &self.source {
+            Source::Internal => return,
+            Source::Local(key) | Source::Network(key) => key.clone(),
+        };
+
+        let store = PermissionStore::load();
+        let mut unanswered = vec![];
+
+        for capability in capabilities {
+            if self.allows(capability) {
+                continue;
+            }
+
+            match store.decision(&key, capability) {
+                Some(true) => {
+                    self.granted.insert(capability.clone());
+                }
+                Some(false) => (),
+                None => unanswered.push(capability.clone()),
+            }
+        }
+
+        if unanswered.is_empty() {
+            return;
+        }
+
+        let (response_sender, response_receiver) = mpsc::channel();
+
+        let request = PermissionRequest {
+            origin: key.clone(),
+            capabilities: unanswered.iter().map(|c| c.description()).collect(),
+            response: response_sender,
+        };
+
+        if sender
+            .send(InterpreterEvent::RequestPermissions(request))
+            .is_err()
+        {
+            return;
+        }
+
+        let Ok(allowed) = response_receiver.recv() else {
+            return;
+        };
+
+        // The choice still applies to this run if it can't be saved, so the user is only asked
+        // again next time
+        if let Err(e) = PermissionStore::remember(&key, &unanswered, allowed) {
+            eprintln!("Failed to remember the permissions chosen for {key}: {e}");
+        }
+
+        if allowed {
+            self.granted.extend(unanswered);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    allowed_origins: Vec<String>,
+}
+
+fn manifest_address(address: &Address) -> Option<Address> {
+    let origin = address.origin()?;
+
+    // The path of the script within its origin, such as 'apps/app.zonk'
+    let path = match address.location.strip_prefix("//")?.split_once('/') {
+        Some((_, path)) => path.split(['?', '#']).next()?,
+        None => "",
+    };
+
+    let directory = match path.rfind('/') {
+        Some(end) => &path[..=end],
+        None => "",
+    };
+
+    Some(Address::new(
+        &format!("{origin}/{directory}zonkey.json"),
+        vec![],
+    ))
+}
+
+// Entries that are not http or https addresses are ignored
+fn parse_manifest(contents: &str) -> Vec<String> {
+    serde_json::from_str::<Manifest>(contents)
+        .map(|manifest| manifest.allowed_origins)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|origin| Address::new(origin, vec![]).origin())
+        .collect()
+}
+
+// Normalises a host and port, such as localhost:9000, so it can be compared with declared ones
+fn socket_address(address: &str) -> Option<String> {
+    let (host, port) = address.rsplit_once(':')?;
+    let port: u16 = port.parse().ok()?;
+
+    match host.is_empty() {
+        true => None,
+        false => Some(format!("{}:{port}", host.to_lowercase())),
+    }
+}
+
+// Held while the stored choices are changed, so tabs answering at the same time do not lose each
+// other's choices
+static CHANGING: Mutex<()> = Mutex::new(());
+
+// Choices made by the user for each origin, which are stored in the data directory
+#[derive(Default, Serialize, Deserialize)]
+struct PermissionStore {
+    origins: FxHashMap<String, FxHashMap<String, bool>>,
+}
+
+impl PermissionStore {
+    fn path() -> Option<PathBuf> {
+        let proj_dirs = ProjectDirs::from("rocks.sambowden", "", "zonkey")?;
+        Some(proj_dirs.data_dir().join("permissions.json"))
+    }
+
+    fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn decision(&self, key: &str, capability: &Capability) -> Option<bool> {
+        self.origins.get(key)?.get(&capability.to_string()).copied()
+    }
+
+    fn remember(key: &str, capabilities: &[Capability], allowed: bool) -> Result<(), String> {
+        let _changing = CHANGING.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Loaded again as other tabs may have saved choices while the user was being asked
+        let mut store = Self::load();
+
+        let choices = store.origins.entry(key.to_string()).or_default();
+        for capability in capabilities {
+            choices.insert(capability.to_string(), allowed);
+        }
+
+        store.save()
+    }
+
+    // The choices are written to another file first and then moved over the old one, so they are
+    // never read while half written
+    fn save(&self) -> Result<(), String> {
+        let path = Self::path().ok_or("Couldn't find the zonkey data directory.")?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|e| e.to_string())?;
         }
+
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+
+        let temporary = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary, contents).map_err(|e| e.to_string())?;
+        fs::rename(&temporary, &path).map_err(|e| {
+            fs::remove_file(&temporary).ok();
+            e.to_string()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{manifest_address, parse_manifest, Capability, Permissions};
+    use crate::{
+        event::InterpreterEvent,
+        tests::{run_failing_test_script, spawn_test_script},
+        Address,
+    };
+    use std::sync::mpsc;
+
+    #[test]
+    fn parse_capabilities() {
+        assert_eq!(Capability::parse("file_read"), Some(Capability::FileRead));
+        assert_eq!(
+            Capability::parse("network:https://example.com/api/items"),
+            Some(Capability::Network(String::from("https://example.com")))
+        );
+        assert_eq!(Capability::parse("network:example.com"), None);
+        assert_eq!(Capability::parse("everything"), None);
+        assert_eq!(Capability::parse("clipboard"), Some(Capability::Clipboard));
+        assert_eq!(
+            Capability::parse("socket:LocalHost:9000"),
+            Some(Capability::Socket(String::from("localhost:9000")))
+        );
+        assert_eq!(Capability::parse("socket:localhost"), None);
+        assert_eq!(
+            Capability::Network(String::from("http://localhost:8000")).to_string(),
+            "network:http://localhost:8000"
+        );
+    }
+
+    #[test]
+    fn defaults_depend_on_address() {
+        let api = Address::new("https://api.example.com/items", vec![]);
+        let own = Address::new("https://example.com/data.txt", vec![]);
+        let file = Address::new("/home/user/notes.txt", vec![]);
+
+        let network = Permissions::new(&Address::new("https://example.com/app.zonk", vec![]));
+        assert!(network.check_read(&own).is_ok());
+        assert!(network.check_write(&api).is_err());
+        assert!(network.check_read(&file).is_err());
+        assert!(network.check(Capability::Applications).is_err());
+        assert!(network.check_socket("example.com:9000").is_err());
+
+        let mut local = Permissions::new(&Address::new("/home/user/app.zonk", vec![]));
+        assert!(local.check_read(&file).is_err());
+        assert!(local.check_write(&api).is_err());
+        assert!(local.check(Capability::Settings).is_err());
+        assert!(local.check_socket("localhost:9000").is_err());
+
+        // Local scripts get what they declare and nothing more
+        local.grant_all(&[Capability::FileRead, Capability::Clipboard]);
+        assert!(local.check_read(&file).is_ok());
+        assert!(local.check_write(&file).is_err());
+        assert!(local.check(Capability::Clipboard).is_ok());
+
+        let mut granted = Permissions::new(&Address::new("https://example.com/app.zonk", vec![]));
+        granted.grant_all(&[Capability::Network(String::from("https://api.example.com"))]);
+        assert!(granted.check_write(&api).is_ok());
+
+        let internal = Permissions::new(&Address::new("zonkey:settings.zonk", vec![]));
+        assert!(internal.check(Capability::Applications).is_ok());
+    }
+
+    #[test]
+    fn manifest_allows_other_origins() {
+        let script = Address::new("https://Example.com/apps/app.zonk?version=2", vec![]);
+
+        assert_eq!(
+            manifest_address(&script).unwrap().to_string(),
+            "https://example.com/apps/zonkey.json"
+        );
+        assert_eq!(
+            manifest_address(&Address::new("http://localhost:8000", vec![]))
+                .unwrap()
+                .to_string(),
+            "http://localhost:8000/zonkey.json"
+        );
+        assert!(manifest_address(&Address::new("/home/user/app.zonk", vec![])).is_none());
+
+        let origins = parse_manifest(
+            r#"{"allowed_origins": ["https://api.example.com/v1", "example.org", "http://cdn.example.com"]}"#,
+        );
+        assert_eq!(
+            origins,
+            ["https://api.example.com", "http://cdn.example.com"]
+        );
+        assert!(parse_manifest("not a manifest").is_empty());
+
+        let mut permissions = Permissions::new(&script);
+        permissions.allowed_origins.extend(origins);
+        assert!(permissions
+            .check_read(&Address::new("https://api.example.com/items", vec![]))
+            .is_ok());
+        assert!(permissions
+            .check_read(&Address::new("https://cdn.example.com/items", vec![]))
+            .is_err());
+        assert!(permissions
+            .check_read(&Address::new("https://tracker.example.net/collect", vec![]))
+            .is_err());
+    }
+
+    #[test]
+    fn images_checked_before_loading() {
+        let error = run_failing_test_script(
+            "start { Image(\"https://tracker.example.net/pixel.png?page=1\"); }",
+            Permissions::new(&Address::new("https://example.com/app.zonk", vec![])),
+        );
+
+        assert!(error.contains("PermissionDenied"));
+        assert!(error.contains("https://tracker.example.net"));
+    }
+
+    #[test]
+    fn clipboard_checked_before_use() {
+        let error = run_failing_test_script(
+            "start { clipboard_set(\"secret\"); }",
+            Permissions::new(&Address::new("/home/user/app.zonk", vec![])),
+        );
+
+        assert!(error.contains("PermissionDenied"));
+        assert!(error.contains("requires \"clipboard\";"));
+    }
+
+    #[test]
+    fn clipboard_read_and_written_by_window() {
+        let source = "\
+start {
+    clipboard_set(\"copied\");
+    assert_eq(clipboard_get(), \"pasted\");
+    assert_eq(clipboard_get(), \"\");
+}
+";
+
+        // Kept open so the page is not treated as closed
+        let (_page_events, page_event_receiver) = mpsc::channel();
+        let (receiver, script) = spawn_test_script(source, page_event_receiver);
+
+        let mut events = receiver.iter();
+
+        match events.next() {
+            Some(InterpreterEvent::WriteClipboard(text)) => assert_eq!(text, "copied"),
+            event => panic!("Expected the clipboard to be written, got {event:?}"),
+        }
+
+        match events.next() {
+            Some(InterpreterEvent::ReadClipboard(response)) => {
+                response.send(String::from("pasted")).unwrap()
+            }
+            event => panic!("Expected the clipboard to be read, got {event:?}"),
+        }
+
+        // The clipboard could not be read
+        match events.next() {
+            Some(InterpreterEvent::ReadClipboard(response)) => drop(response),
+            event => panic!("Expected the clipboard to be read, got {event:?}"),
+        }
+
+        script.join().unwrap();
     }
 }
@@ -0,0 +1,164 @@
+use crate::{
+    address::{Address, AddressType},
+    ast::AST,
+    disk_cache,
+};
+use directories_next::ProjectDirs;
+use sha2::{Digest, Sha256};
+use std::{env, fs, path::PathBuf, process, time::UNIX_EPOCH};
+
+// Scripts which have not been stored for a while are removed once the cache grows past this size
+const MAX_SIZE: u64 = 64 * 1024 * 1024;
+
+// Increase this whenever the serialised form of the AST changes
+const FORMAT: u32 = 2;
+
+// Parsed scripts are stored in the data directory, keyed by a hash of the source, the cache format
+// and the build of Zonkey, as the layout of the AST can change between builds.
+pub struct ScriptCache {
+    path: PathBuf,
+}
+
+impl ScriptCache {
+    // Only scripts that are likely to be opened again without changing are cached, which are
+    // installed applications and pages loaded over the network.
+    pub fn new(address: &Address, source: &str) -> Option<Self> {
+        let directory = directory()?;
+
+        match &address.address_type {
+            AddressType::HTTP { .. } | AddressType::Installed => (),
+            AddressType::File => {
+                let data_dir = directory.parent()?;
+                if !PathBuf::from(&address.location).starts_with(data_dir) {
+                    return None;
+                }
+            }
+            AddressType::Zonkey => return None,
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(FORMAT.to_le_bytes());
+        hasher.update(build_time().to_le_bytes());
+        hasher.update(source);
+
+        let hash = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        Some(Self {
+            path: directory.join(hash),
+        })
+    }
+
+    pub fn load(&self) -> Option<AST> {
+        let bytes = fs::read(&self.path).ok()?;
+
+        // An entry that can't be decoded is treated as a miss and replaced when the script is
+        // stored again
+        let ast = bincode::deserialize(&bytes).ok();
+        if ast.is_none() {
+            fs::remove_file(&self.path).ok();
+        }
+        ast
+    }
+
+    // Failing to store the script only means it will be parsed again next time
+    pub fn store(&self, ast: &AST) {
+        let Ok(bytes) = bincode::serialize(ast) else {
+            return;
+        };
+
+        let Some(directory) = self.path.parent() else {
+            return;
+        };
+
+        // The entry is written in full before being moved into place, so a script being loaded
+        // at the same time never sees half of it
+        let temp = self.path.with_extension(format!("{}.tmp", process::id()));
+
+        fs::create_dir_all(directory).ok();
+        if fs::write(&temp, bytes).is_err() || fs::rename(&temp, &self.path).is_err() {
+            fs::remove_file(&temp).ok();
+            return;
+        }
+        disk_cache::prune(directory, MAX_SIZE);
+    }
+}
+
+// Development builds share a version number, so the time the running executable was built keeps
+// their entries apart
+fn build_time() -> u128 {
+    env::current_exe()
+        .and_then(|path| fs::metadata(path)?.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |time| time.as_nanos())
+}
+
+pub fn directory() -> Option<PathBuf> {
+    let proj_dirs = ProjectDirs::from("rocks.sambowden", "", "zonkey")?;
+    Some(proj_dirs.data_dir().join(".script_cache"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScriptCache;
+    use crate::{lexer::Lexer, parser::Parser, Address};
+
+    #[test]
+    fn only_installed_and_network_scripts_are_cached() {
+        let source = "start {}";
+
+        assert!(
+            ScriptCache::new(&Address::new("installed:App/app.zonk", vec![]), source).is_some()
+        );
+        assert!(ScriptCache::new(
+            &Address::new("https://example.com/app.zonk", vec![]),
+            source
+        )
+        .is_some());
+        assert!(ScriptCache::new(&Address::new("zonkey:home.zonk", vec![]), source).is_none());
+        assert!(ScriptCache::new(&Address::new("/tmp/app.zonk", vec![]), source).is_none());
+    }
+
+    #[test]
+    fn store_and_load() {
+        let source = "function add(Integer a, Integer b) -> Integer { return a + b; } start { println(add(1, 2)); }";
+
+        let address = Address::new("https://example.com/store_and_load.zonk", vec![]);
+        let cache = ScriptCache::new(&address, source).unwrap();
+        let path = cache.path.clone();
+
+        let builder = std::thread::Builder::new().stack_size(crate::REQUIRED_STACK_SIZE);
+
+        let (stored, loaded) = builder
+            .spawn(move || {
+                let (tokens, _) = Lexer::run(source);
+                let ast = Parser::run(tokens.unwrap()).unwrap();
+                cache.store(&ast);
+                (format!("{:?}", ast), format!("{:?}", cache.load().unwrap()))
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        std::fs::remove_file(path).ok();
+
+        assert_eq!(stored, loaded);
+    }
+
+    #[test]
+    fn undecodable_entry_is_a_miss() {
+        let address = Address::new("https://example.com/undecodable_entry.zonk", vec![]);
+        let cache = ScriptCache::new(&address, "start {}").unwrap();
+
+        std::fs::create_dir_all(cache.path.parent().unwrap()).unwrap();
+        std::fs::write(&cache.path, [0xff; 3]).unwrap();
+
+        assert!(cache.load().is_none());
+        assert!(!cache.path.exists());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::event::PageEvent;
+use std::{
+    collections::VecDeque,
+    io::{BufRead, BufReader, Write},
+    net::{Shutdown, TcpStream},
+    sync::{mpsc::Sender, Arc, Mutex},
+    thread,
+};
+
+// A connection to a server where each message is sent and received as a line of text. Messages
+// that arrive are queued, and wait_for_event returns for each of them.
+#[derive(Debug)]
+pub struct Socket {
+    pub address: String,
+    stream: Option<TcpStream>,
+    // Messages sent before the connection was made
+    pending: Vec<String>,
+    pub messages: VecDeque<String>,
+    pub connected: bool,
+    pub closed: bool,
+}
+
+impl Socket {
+    // Connects on another thread, which then receives messages until the connection is closed
+    pub fn connect(
+        address: String,
+        page_event_sender: Sender<Option<PageEvent>>,
+    ) -> Arc<Mutex<Self>> {
+        let socket = Arc::new(Mutex::new(Self {
+            address: address.clone(),
+            stream: None,
+            pending: vec![],
+            messages: VecDeque::new(),
+            connected: false,
+            closed: false,
+        }));
+
+        let socket_ref = Arc::clone(&socket);
+
+        thread::spawn(move || {
+            let changed = || {
+                page_event_sender
+                    .send(Some(PageEvent::SocketChanged(Arc::clone(&socket_ref))))
+                    .is_ok()
+            };
+
+            let reader = match TcpStream::connect(&address)
+                .and_then(|stream| Ok((stream.try_clone()?, stream)))
+            {
+                Ok((reader, mut writer)) => {
+                    let mut socket = socket_ref.lock().unwrap();
+
+                    // The script closed the socket while it was connecting
+                    if socket.closed {
+                        writer.shutdown(Shutdown::Both).ok();
+                        return;
+                    }
+
+                    for message in socket.pending.drain(..) {
+                        writeln!(writer, "{message}").ok();
+                    }
+
+                    socket.stream = Some(writer);
+                    socket.connected = true;
+                    reader
+                }
+                Err(_) => {
+                    socket_ref.lock().unwrap().closed = true;
+                    changed();
+                    return;
+                }
+            };
+
+            if changed() {
+                for line in BufReader::new(reader).lines() {
+                    let Ok(line) = line else {
+                        break;
+                    };
+
+                    socket_ref.lock().unwrap().messages.push_back(line);
+
+                    // The script has finished
+                    if !changed() {
+                        break;
+                    }
+                }
+            }
+
+            socket_ref.lock().unwrap().close();
+            changed();
+        });
+
+        socket
+    }
+
+    pub fn send(&mut self, message: &str) -> Result<(), String> {
+        if self.closed {
+            return Err(format!("The connection to {} is closed", self.address));
+        }
+
+        let Some(stream) = &mut self.stream else {
+            self.pending.push(message.to_string());
+            return Ok(());
+        };
+
+        if let Err(e) = writeln!(stream, "{message}") {
+            self.close();
+            return Err(format!("Failed to send to {}: {e}", self.address));
+        }
+
+        Ok(())
+    }
+
+    // Shutting down the connection also stops the thread receiving messages
+    pub fn close(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            stream.shutdown(Shutdown::Both).ok();
+        }
+
+        self.connected = false;
+        self.closed = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::run_test_script;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    #[test]
+    fn messages_received_as_events() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Echo server which closes the connection after the third message
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+
+            for line in BufReader::new(stream).lines().take(3) {
+                writeln!(writer, "echo {}", line.unwrap()).unwrap();
+            }
+        });
+
+        let source = format!(
+            "\
+start {{
+    let socket = Socket(\"{address}\").send(\"first\");
+    let received = String[];
+    let sent = false;
+
+    while (!socket.closed() & wait_for_event()) {{
+        if (socket.connected() & !sent) {{
+            socket.send(\"second\").send(\"third\");
+            sent = true;
+        }}
+
+        while (socket.has_message()) received.push(socket.next_message());
+    }}
+
+    assert_eq(received.len(), 3);
+    assert_eq(received.get(0), \"echo first\");
+    assert_eq(received.get(2), \"echo third\");
+    assert_eq(socket.next_message(), \"\");
+    assert(!socket.connected());
+}}
+"
+        );
+
+        run_test_script(&source);
+    }
+}
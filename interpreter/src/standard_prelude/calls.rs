@@ -2,9 +2,10 @@ use crate::{
     expr::{BooleanExpr, Expr, FloatExpr, IntegerExpr, ObjectExpr, StringExpr},
     token::Token,
 };
+use serde::{Deserialize, Serialize};
 
 // Standard prelude calls (Separated by return types)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallNone {
     Print(Box<Expr>, bool),
     Sleep(IntegerExpr),
@@ -13,9 +14,17 @@ pub enum NativeCallNone {
     OpenLink(Box<StringExpr>, ObjectExpr),
     InstallApplication(ObjectExpr, BooleanExpr),
     RemoveApplication(StringExpr),
+    Assert(Box<BooleanExpr>, Token),
+    AssertEqual(Box<Expr>, Box<Expr>, Token),
+    Fail(Box<StringExpr>, Token),
+    ClearCookies(Box<StringExpr>),
+    StorageSet(Box<StringExpr>, Box<StringExpr>),
+    StorageRemove(Box<StringExpr>),
+    ClearTimer(Box<IntegerExpr>),
+    ClipboardSet(Box<StringExpr>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallInteger {
     IntegerArrayGet(Box<ObjectExpr>, Box<IntegerExpr>, Token),
     IntegerArrayRemove(Box<ObjectExpr>, Box<IntegerExpr>, Token),
@@ -23,18 +32,34 @@ pub enum NativeCallInteger {
     FromString(Box<StringExpr>, Token),
     FromFloat(Box<FloatExpr>),
     Power(Box<IntegerExpr>, Box<IntegerExpr>),
+    HttpResponseStatus(Box<ObjectExpr>),
+    JsonGetInteger(Box<ObjectExpr>, Option<Box<StringExpr>>, Token),
+    RadioGroupSelected(Box<ObjectExpr>),
+    TableRowCount(Box<ObjectExpr>),
+    TableSelected(Box<ObjectExpr>),
+    StartTimer(Box<IntegerExpr>, bool),
+    // Title, message and button labels
+    Dialog(Box<StringExpr>, Box<StringExpr>, Box<ObjectExpr>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallFloat {
     FloatArrayGet(Box<ObjectExpr>, Box<IntegerExpr>, Token),
     FloatArrayRemove(Box<ObjectExpr>, Box<IntegerExpr>, Token),
     FromString(Box<StringExpr>, Token),
     FromInteger(Box<IntegerExpr>),
     PowerF(Box<FloatExpr>, Box<FloatExpr>),
+    JsonGetFloat(Box<ObjectExpr>, Option<Box<StringExpr>>, Token),
+    SliderValue(Box<ObjectExpr>),
+    CanvasPointerX(Box<ObjectExpr>),
+    CanvasPointerY(Box<ObjectExpr>),
+    MouseX,
+    MouseY,
+    WindowWidth,
+    WindowHeight,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallString {
     Prompt(Box<StringExpr>),
     FromInteger(IntegerExpr),
@@ -45,18 +70,64 @@ pub enum NativeCallString {
     WriteString(Box<StringExpr>, Box<StringExpr>),
     StringArrayGet(Box<ObjectExpr>, IntegerExpr, Token),
     StringArrayRemove(Box<ObjectExpr>, IntegerExpr, Token),
+    HttpResponseHeader(Box<ObjectExpr>, Box<StringExpr>),
+    HttpResponseBody(Box<ObjectExpr>),
+    JsonGetString(Box<ObjectExpr>, Option<Box<StringExpr>>, Token),
+    JsonToString(Box<ObjectExpr>),
+    AsyncRequestResult(Box<ObjectExpr>),
+    SocketNextMessage(Box<ObjectExpr>),
+    GetCookie(Box<StringExpr>, Box<StringExpr>),
+    StorageGet(Box<StringExpr>),
+    RadioGroupSelectedOption(Box<ObjectExpr>),
+    DropdownSelected(Box<ObjectExpr>),
+    TextAreaGetText(Box<ObjectExpr>),
+    TableGetCell(Box<ObjectExpr>, Box<IntegerExpr>, Box<IntegerExpr>, Token),
+    KeyName,
+    MouseButton,
+    PaletteColour(Box<StringExpr>),
+    ClipboardGet,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallBoolean {
     WaitForEvent,
     ButtonClicked(ObjectExpr),
     InputConfirmed(ObjectExpr),
     BooleanArrayGet(Box<ObjectExpr>, IntegerExpr, Token),
     BooleanArrayRemove(Box<ObjectExpr>, IntegerExpr, Token),
+    JsonHas(Box<ObjectExpr>, Box<StringExpr>),
+    JsonGetBoolean(Box<ObjectExpr>, Option<Box<StringExpr>>, Token),
+    ImageLoaded(Box<ObjectExpr>),
+    AsyncRequestFinished(Box<ObjectExpr>),
+    AsyncRequestFailed(Box<ObjectExpr>),
+    SocketHasMessage(Box<ObjectExpr>),
+    SocketConnected(Box<ObjectExpr>),
+    SocketClosed(Box<ObjectExpr>),
+    CheckboxChecked(Box<ObjectExpr>),
+    CheckboxChanged(Box<ObjectExpr>),
+    ToggleOn(Box<ObjectExpr>),
+    ToggleChanged(Box<ObjectExpr>),
+    RadioGroupChanged(Box<ObjectExpr>),
+    SliderChanged(Box<ObjectExpr>),
+    DropdownChanged(Box<ObjectExpr>),
+    TextAreaChanged(Box<ObjectExpr>),
+    TableSortChanged(Box<ObjectExpr>),
+    TableSelectionChanged(Box<ObjectExpr>),
+    CanvasPointerDown(Box<ObjectExpr>),
+    CanvasPointerPressed(Box<ObjectExpr>),
+    CanvasPointerReleased(Box<ObjectExpr>),
+    CanvasPointerMoved(Box<ObjectExpr>),
+    KeyPressed,
+    ShiftHeld,
+    ControlHeld,
+    AltHeld,
+    MouseMoved,
+    MouseClicked,
+    WindowResized,
+    TimerFired(Box<IntegerExpr>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NativeCallObject {
     ButtonConstructor(Box<StringExpr>),
     ButtonSetText(Box<ObjectExpr>, Box<StringExpr>),
@@ -69,6 +140,12 @@ pub enum NativeCallObject {
     TextSetValue(Box<ObjectExpr>, Box<StringExpr>),
     TextSetSize(Box<ObjectExpr>, Box<FloatExpr>),
     TextSetColour(Box<ObjectExpr>, Box<StringExpr>),
+    TextSetBold(Box<ObjectExpr>, Box<BooleanExpr>),
+    TextSetItalic(Box<ObjectExpr>, Box<BooleanExpr>),
+    TextSetFont(Box<ObjectExpr>, Box<StringExpr>),
+    TextSetAlignment(Box<ObjectExpr>, Box<StringExpr>),
+    TextSetLineHeight(Box<ObjectExpr>, Box<FloatExpr>),
+    TextSetWrap(Box<ObjectExpr>, Box<BooleanExpr>),
 
     HyperlinkConstructor(Box<StringExpr>, Box<StringExpr>),
     HyperlinkAddArg(Box<ObjectExpr>, Box<StringExpr>),
@@ -95,9 +172,118 @@ pub enum NativeCallObject {
     ColumnRemoveElement(Box<ObjectExpr>, Box<ObjectExpr>),
     ColumnSetMaxWidth(Box<ObjectExpr>, FloatExpr),
 
+    // Shared by Row, Column and Page, where a width or height of None fills the space
+    LayoutSetPadding(Box<ObjectExpr>, Box<FloatExpr>, Box<FloatExpr>),
+    LayoutSetSpacing(Box<ObjectExpr>, Box<FloatExpr>),
+    LayoutSetAlignment(Box<ObjectExpr>, Box<StringExpr>, bool),
+    LayoutSetWidth(Box<ObjectExpr>, Option<Box<FloatExpr>>),
+    LayoutSetHeight(Box<ObjectExpr>, Option<Box<FloatExpr>>),
+    LayoutSetBackgroundColour(Box<ObjectExpr>, Box<StringExpr>),
+    LayoutSetBorderColour(Box<ObjectExpr>, Box<StringExpr>),
+    LayoutSetBorderWidth(Box<ObjectExpr>, Box<FloatExpr>),
+    LayoutSetBorderRadius(Box<ObjectExpr>, Box<FloatExpr>),
+
+    ScrollConstructor(Box<FloatExpr>),
+    ScrollAddElement(Box<ObjectExpr>, Box<ObjectExpr>),
+    ScrollRemoveElement(Box<ObjectExpr>, Box<ObjectExpr>),
+    ScrollSetHeight(Box<ObjectExpr>, Box<FloatExpr>),
+
+    StackConstructor,
+    StackAddElement(Box<ObjectExpr>, Box<ObjectExpr>),
+    StackRemoveElement(Box<ObjectExpr>, Box<ObjectExpr>),
+
+    StyleConstructor,
+    StyleSetTextSize(Box<ObjectExpr>, Box<FloatExpr>),
+    StyleSetTextColour(Box<ObjectExpr>, Box<StringExpr>),
+    StyleSetButtonColour(Box<ObjectExpr>, Box<StringExpr>),
+    StyleSetButtonTextColour(Box<ObjectExpr>, Box<StringExpr>),
+    StyleSetButtonPadding(Box<ObjectExpr>, Box<FloatExpr>, Box<FloatExpr>),
+    StyleSetBackgroundColour(Box<ObjectExpr>, Box<StringExpr>),
+    StyleSetPadding(Box<ObjectExpr>, Box<FloatExpr>, Box<FloatExpr>),
+    // Sets the style of a Text, Button, Row, Column or Page
+    SetStyle(Box<ObjectExpr>, Box<ObjectExpr>),
+
     ImageConstructor(Box<StringExpr>),
     ImageSetMaxWidth(Box<ObjectExpr>, FloatExpr),
 
+    CheckboxConstructor(Box<StringExpr>),
+    CheckboxSetChecked(Box<ObjectExpr>, Box<BooleanExpr>),
+    ToggleConstructor(Box<StringExpr>),
+    ToggleSetOn(Box<ObjectExpr>, Box<BooleanExpr>),
+    RadioGroupConstructor,
+    RadioGroupAddOption(Box<ObjectExpr>, Box<StringExpr>),
+    RadioGroupSetSelected(Box<ObjectExpr>, Box<IntegerExpr>, Token),
+    SliderConstructor(Box<FloatExpr>, Box<FloatExpr>),
+    SliderSetValue(Box<ObjectExpr>, Box<FloatExpr>),
+    SliderSetStep(Box<ObjectExpr>, Box<FloatExpr>),
+    DropdownConstructor(Box<ObjectExpr>),
+    DropdownSetSelected(Box<ObjectExpr>, Box<StringExpr>),
+    DropdownSetPlaceholder(Box<ObjectExpr>, Box<StringExpr>),
+    TextAreaConstructor(Box<StringExpr>),
+    TextAreaSetText(Box<ObjectExpr>, Box<StringExpr>),
+    TableConstructor(Box<ObjectExpr>),
+    TableAddRow(Box<ObjectExpr>, Box<ObjectExpr>),
+    TableRemoveRow(Box<ObjectExpr>, Box<IntegerExpr>, Token),
+    TableSetColumnWidth(Box<ObjectExpr>, Box<IntegerExpr>, Box<FloatExpr>, Token),
+    TableSort(Box<ObjectExpr>, Box<IntegerExpr>, Box<BooleanExpr>, Token),
+    TableSetSelected(Box<ObjectExpr>, Box<IntegerExpr>, Token),
+    CanvasConstructor(Box<FloatExpr>, Box<FloatExpr>),
+    CanvasSetFill(Box<ObjectExpr>, Box<StringExpr>),
+    CanvasSetStroke(Box<ObjectExpr>, Box<StringExpr>),
+    CanvasSetStrokeWidth(Box<ObjectExpr>, Box<FloatExpr>),
+    CanvasSetFontSize(Box<ObjectExpr>, Box<FloatExpr>),
+    CanvasLine(
+        Box<ObjectExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+    ),
+    CanvasRect(
+        Box<ObjectExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+    ),
+    CanvasCircle(
+        Box<ObjectExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+    ),
+    CanvasPath(Box<ObjectExpr>, Box<ObjectExpr>, Box<BooleanExpr>),
+    CanvasText(
+        Box<ObjectExpr>,
+        Box<FloatExpr>,
+        Box<FloatExpr>,
+        Box<StringExpr>,
+    ),
+    CanvasClear(Box<ObjectExpr>),
+
+    HttpRequestConstructor(Box<StringExpr>, Box<StringExpr>),
+    HttpRequestSetHeader(Box<ObjectExpr>, Box<StringExpr>, Box<StringExpr>),
+    HttpRequestSetBody(Box<ObjectExpr>, Box<StringExpr>),
+    HttpRequestSetTimeout(Box<ObjectExpr>, Box<IntegerExpr>),
+    HttpRequestSend(Box<ObjectExpr>),
+    HttpResponseHeaderNames(Box<ObjectExpr>),
+
+    ReadStringAsync(Box<StringExpr>),
+    WriteStringAsync(Box<StringExpr>, Box<StringExpr>),
+
+    SocketConstructor(Box<StringExpr>),
+    SocketSend(Box<ObjectExpr>, Box<StringExpr>),
+    SocketClose(Box<ObjectExpr>),
+
+    CookieNames(Box<StringExpr>),
+    StorageKeys,
+
+    JsonConstructor,
+    JsonParse(Box<StringExpr>, Token),
+    JsonGetObject(Box<ObjectExpr>, Box<StringExpr>, Token),
+    JsonGetArray(Box<ObjectExpr>, Option<Box<StringExpr>>, Token),
+    JsonSet(Box<ObjectExpr>, Box<StringExpr>, Box<Expr>, Token),
+
     IntegerArrayConstructor(Vec<Expr>),
     IntegerArrayPush(Box<ObjectExpr>, IntegerExpr),
 
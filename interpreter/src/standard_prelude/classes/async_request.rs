@@ -0,0 +1,34 @@
+use super::prelude::*;
+
+pub fn new() -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "finished".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "failed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "result".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
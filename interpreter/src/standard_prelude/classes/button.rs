@@ -66,5 +66,14 @@ pub fn new(button: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    methods.insert(
+        "set_style".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Class(Rc::new("Style".to_string()))],
+            return_type: Some(ValueType::Class(Rc::clone(&button))),
+        }),
+    );
+
     ClassDeclaration { methods }
 }
@@ -0,0 +1,164 @@
+use super::prelude::*;
+
+pub fn new(canvas: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "set_fill".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "set_stroke".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "set_stroke_width".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "set_font_size".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "line".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![
+                ValueType::Float,
+                ValueType::Float,
+                ValueType::Float,
+                ValueType::Float,
+            ],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "rect".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![
+                ValueType::Float,
+                ValueType::Float,
+                ValueType::Float,
+                ValueType::Float,
+            ],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "circle".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float, ValueType::Float, ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "path".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![
+                ValueType::Class(Rc::new("[Float]".into())),
+                ValueType::Boolean,
+            ],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "text".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float, ValueType::Float, ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "clear".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::clone(&canvas))),
+        }),
+    );
+
+    methods.insert(
+        "pointer_x".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        }),
+    );
+
+    methods.insert(
+        "pointer_y".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        }),
+    );
+
+    methods.insert(
+        "pointer_down".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "pointer_pressed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "pointer_released".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "pointer_moved".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
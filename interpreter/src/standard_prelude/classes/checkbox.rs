@@ -0,0 +1,34 @@
+use super::prelude::*;
+
+pub fn new(checkbox: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "checked".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "set_checked".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Boolean],
+            return_type: Some(ValueType::Class(Rc::clone(&checkbox))),
+        }),
+    );
+
+    methods.insert(
+        "changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
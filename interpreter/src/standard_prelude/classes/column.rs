@@ -1,4 +1,4 @@
-use super::prelude::*;
+use super::{layout, prelude::*};
 
 pub fn new(column: Rc<String>) -> ClassDeclaration {
     let mut methods = FxHashMap::default();
@@ -30,5 +30,7 @@ pub fn new(column: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    layout::add_methods(&column, &mut methods);
+
     ClassDeclaration { methods }
 }
@@ -0,0 +1,43 @@
+use super::prelude::*;
+
+pub fn new(dropdown: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "set_selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&dropdown))),
+        }),
+    );
+
+    methods.insert(
+        "set_placeholder".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&dropdown))),
+        }),
+    );
+
+    methods.insert(
+        "changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
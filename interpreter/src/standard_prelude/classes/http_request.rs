@@ -0,0 +1,43 @@
+use super::prelude::*;
+
+pub fn new(http_request: Rc<String>, http_response: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "set_header".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&http_request))),
+        }),
+    );
+
+    methods.insert(
+        "set_body".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&http_request))),
+        }),
+    );
+
+    methods.insert(
+        "set_timeout".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Class(Rc::clone(&http_request))),
+        }),
+    );
+
+    methods.insert(
+        "send".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(http_response)),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -0,0 +1,43 @@
+use super::prelude::*;
+
+pub fn new() -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "status".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "header".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "header_names".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("[String]".into()))),
+        }),
+    );
+
+    methods.insert(
+        "body".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -12,5 +12,14 @@ pub fn new(image: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    methods.insert(
+        "loaded".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
     ClassDeclaration { methods }
 }
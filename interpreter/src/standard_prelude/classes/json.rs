@@ -0,0 +1,178 @@
+use super::prelude::*;
+
+pub fn new(json: Rc<String>, json_array: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "has".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "get_string".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "get_integer".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "get_float".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Float),
+        }),
+    );
+
+    methods.insert(
+        "get_boolean".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "get_object".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "get_array".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&json_array))),
+        }),
+    );
+
+    methods.insert(
+        "as_string".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "as_integer".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "as_float".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        }),
+    );
+
+    methods.insert(
+        "as_boolean".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "as_array".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::clone(&json_array))),
+        }),
+    );
+
+    methods.insert(
+        "set_string".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "set_integer".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::Integer],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "set_float".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "set_boolean".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::Boolean],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "set_object".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::Class(Rc::clone(&json))],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "set_array".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::Class(Rc::clone(&json_array))],
+            return_type: Some(ValueType::Class(Rc::clone(&json))),
+        }),
+    );
+
+    methods.insert(
+        "to_string".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -0,0 +1,35 @@
+use super::prelude::*;
+
+// Styling methods shared by Row, Column and Page, which all return the element they are called on
+pub fn add_methods(
+    class: &Rc<String>,
+    methods: &mut FxHashMap<Rc<String>, Rc<CallableDeclaration>>,
+) {
+    for (name, parameters) in [
+        ("set_padding", vec![ValueType::Float, ValueType::Float]),
+        ("set_spacing", vec![ValueType::Float]),
+        ("set_horizontal_alignment", vec![ValueType::String]),
+        ("set_vertical_alignment", vec![ValueType::String]),
+        ("set_width", vec![ValueType::Float]),
+        ("set_width_fill", vec![]),
+        ("set_height", vec![ValueType::Float]),
+        ("set_height_fill", vec![]),
+        ("set_background_colour", vec![ValueType::String]),
+        ("set_border_colour", vec![ValueType::String]),
+        ("set_border_width", vec![ValueType::Float]),
+        ("set_border_radius", vec![ValueType::Float]),
+        (
+            "set_style",
+            vec![ValueType::Class(Rc::new("Style".to_string()))],
+        ),
+    ] {
+        methods.insert(
+            name.to_string().into(),
+            Rc::new(CallableDeclaration {
+                callable_type: CallableType::Native,
+                parameters,
+                return_type: Some(ValueType::Class(Rc::clone(class))),
+            }),
+        );
+    }
+}
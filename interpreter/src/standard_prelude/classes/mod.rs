@@ -1,15 +1,32 @@
 use prelude::*;
 
 pub mod array;
+mod async_request;
 mod button;
+mod canvas;
+mod checkbox;
 mod column;
+mod dropdown;
+mod http_request;
+mod http_response;
 mod hyperlink;
 mod image;
 mod input;
+mod json;
+mod layout;
 mod page;
 mod prelude;
+mod radio_group;
 mod row;
+mod scroll;
+mod slider;
+mod socket;
+mod stack;
+mod style;
+mod table;
 mod text;
+mod text_area;
+mod toggle;
 
 pub fn new() -> FxHashMap<Rc<String>, ClassDeclaration> {
     let page = Rc::new("Page".to_string());
@@ -20,6 +37,22 @@ pub fn new() -> FxHashMap<Rc<String>, ClassDeclaration> {
     let row = Rc::new("Row".to_string());
     let column = Rc::new("Column".to_string());
     let image = Rc::new("Image".to_string());
+    let checkbox = Rc::new("Checkbox".to_string());
+    let toggle = Rc::new("Toggle".to_string());
+    let radio_group = Rc::new("RadioGroup".to_string());
+    let slider = Rc::new("Slider".to_string());
+    let dropdown = Rc::new("Dropdown".to_string());
+    let text_area = Rc::new("TextArea".to_string());
+    let table = Rc::new("Table".to_string());
+    let canvas = Rc::new("Canvas".to_string());
+    let scroll = Rc::new("Scroll".to_string());
+    let stack = Rc::new("Stack".to_string());
+    let style = Rc::new("Style".to_string());
+    let http_request = Rc::new("HttpRequest".to_string());
+    let http_response = Rc::new("HttpResponse".to_string());
+    let json = Rc::new("Json".to_string());
+    let async_request = Rc::new("AsyncRequest".to_string());
+    let socket = Rc::new("Socket".to_string());
 
     let mut classes = FxHashMap::default();
 
@@ -31,6 +64,34 @@ pub fn new() -> FxHashMap<Rc<String>, ClassDeclaration> {
     classes.insert(Rc::clone(&row), row::new(Rc::clone(&row)));
     classes.insert(Rc::clone(&column), column::new(Rc::clone(&column)));
     classes.insert(Rc::clone(&image), image::new(Rc::clone(&image)));
+    classes.insert(Rc::clone(&checkbox), checkbox::new(checkbox));
+    classes.insert(Rc::clone(&toggle), toggle::new(toggle));
+    classes.insert(Rc::clone(&radio_group), radio_group::new(radio_group));
+    classes.insert(Rc::clone(&slider), slider::new(slider));
+    classes.insert(Rc::clone(&dropdown), dropdown::new(dropdown));
+    classes.insert(Rc::clone(&text_area), text_area::new(text_area));
+    classes.insert(Rc::clone(&table), table::new(table));
+    classes.insert(Rc::clone(&canvas), canvas::new(canvas));
+    classes.insert(Rc::clone(&scroll), scroll::new(scroll));
+    classes.insert(Rc::clone(&stack), stack::new(stack));
+    classes.insert(Rc::clone(&style), style::new(style));
+    classes.insert(
+        Rc::clone(&http_request),
+        http_request::new(Rc::clone(&http_request), Rc::clone(&http_response)),
+    );
+    classes.insert(Rc::clone(&http_response), http_response::new());
+    classes.insert(async_request, async_request::new());
+    classes.insert(Rc::clone(&socket), socket::new(socket));
+
+    let json_array_type = Rc::new("[Json]".to_string());
+    classes.insert(
+        Rc::clone(&json),
+        json::new(Rc::clone(&json), Rc::clone(&json_array_type)),
+    );
+    classes.insert(
+        Rc::clone(&json_array_type),
+        array::new(json_array_type, ValueType::Class(json)),
+    );
 
     let string_array_type = Rc::new("[String]".into());
     classes.insert(
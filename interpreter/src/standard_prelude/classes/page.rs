@@ -1,4 +1,4 @@
-use super::prelude::*;
+use super::{layout, prelude::*};
 
 pub fn new(page: Rc<String>) -> ClassDeclaration {
     let mut methods = FxHashMap::default();
@@ -66,5 +66,7 @@ pub fn new(page: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    layout::add_methods(&page, &mut methods);
+
     ClassDeclaration { methods }
 }
@@ -0,0 +1,52 @@
+use super::prelude::*;
+
+pub fn new(radio_group: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "add_option".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::clone(&radio_group))),
+        }),
+    );
+
+    methods.insert(
+        "selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "selected_option".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "set_selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Class(Rc::clone(&radio_group))),
+        }),
+    );
+
+    methods.insert(
+        "changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -1,4 +1,4 @@
-use super::prelude::*;
+use super::{layout, prelude::*};
 
 pub fn new(row: Rc<String>) -> ClassDeclaration {
     let mut methods = FxHashMap::default();
@@ -30,5 +30,7 @@ pub fn new(row: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    layout::add_methods(&row, &mut methods);
+
     ClassDeclaration { methods }
 }
@@ -0,0 +1,34 @@
+use super::prelude::*;
+
+pub fn new(scroll: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "add".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Element],
+            return_type: Some(ValueType::Class(Rc::clone(&scroll))),
+        }),
+    );
+
+    methods.insert(
+        "remove".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Element],
+            return_type: Some(ValueType::Class(Rc::clone(&scroll))),
+        }),
+    );
+
+    methods.insert(
+        "set_height".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&scroll))),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -0,0 +1,43 @@
+use super::prelude::*;
+
+pub fn new(slider: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "value".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        }),
+    );
+
+    methods.insert(
+        "set_value".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&slider))),
+        }),
+    );
+
+    methods.insert(
+        "set_step".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&slider))),
+        }),
+    );
+
+    methods.insert(
+        "changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
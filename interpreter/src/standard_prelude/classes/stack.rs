@@ -0,0 +1,25 @@
+use super::prelude::*;
+
+pub fn new(stack: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "add".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Element],
+            return_type: Some(ValueType::Class(Rc::clone(&stack))),
+        }),
+    );
+
+    methods.insert(
+        "remove".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Element],
+            return_type: Some(ValueType::Class(Rc::clone(&stack))),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
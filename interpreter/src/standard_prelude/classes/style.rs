@@ -0,0 +1,29 @@
+use super::prelude::*;
+
+pub fn new(style: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    for (name, parameters) in [
+        ("set_text_size", vec![ValueType::Float]),
+        ("set_text_colour", vec![ValueType::String]),
+        ("set_button_colour", vec![ValueType::String]),
+        ("set_button_text_colour", vec![ValueType::String]),
+        (
+            "set_button_padding",
+            vec![ValueType::Float, ValueType::Float],
+        ),
+        ("set_background_colour", vec![ValueType::String]),
+        ("set_padding", vec![ValueType::Float, ValueType::Float]),
+    ] {
+        methods.insert(
+            name.to_string().into(),
+            Rc::new(CallableDeclaration {
+                callable_type: CallableType::Native,
+                parameters,
+                return_type: Some(ValueType::Class(Rc::clone(&style))),
+            }),
+        );
+    }
+
+    ClassDeclaration { methods }
+}
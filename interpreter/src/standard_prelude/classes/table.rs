@@ -0,0 +1,97 @@
+use super::prelude::*;
+
+pub fn new(table: Rc<String>) -> ClassDeclaration {
+    let mut methods = FxHashMap::default();
+
+    methods.insert(
+        "add_row".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Class(Rc::new("[String]".into()))],
+            return_type: Some(ValueType::Class(Rc::clone(&table))),
+        }),
+    );
+
+    methods.insert(
+        "remove_row".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Class(Rc::clone(&table))),
+        }),
+    );
+
+    methods.insert(
+        "row_count".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "get_cell".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer, ValueType::Integer],
+            return_type: Some(ValueType::String),
+        }),
+    );
+
+    methods.insert(
+        "set_column_width".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer, ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::clone(&table))),
+        }),
+    );
+
+    methods.insert(
+        "sort".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer, ValueType::Boolean],
+            return_type: Some(ValueType::Class(Rc::clone(&table))),
+        }),
+    );
+
+    methods.insert(
+        "selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Integer),
+        }),
+    );
+
+    methods.insert(
+        "set_selected".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Class(Rc::clone(&table))),
+        }),
+    );
+
+    methods.insert(
+        "sort_changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    methods.insert(
+        "selection_changed".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        }),
+    );
+
+    ClassDeclaration { methods }
+}
@@ -30,5 +30,32 @@ pub fn new(text: Rc<String>) -> ClassDeclaration {
         }),
     );
 
+    for (name, parameter) in [
+        ("set_bold", ValueType::Boolean),
+        ("set_italic", ValueType::Boolean),
+        ("set_font", ValueType::String),
+        ("set_alignment", ValueType::String),
+        ("set_line_height", ValueType::Float),
+        ("set_wrap", ValueType::Boolean),
+    ] {
+        methods.insert(
+            name.to_string().into(),
+            Rc::new(CallableDeclaration {
+                callable_type: CallableType::Native,
+                parameters: vec![parameter],
+                return_type: Some(ValueType::Class(Rc::clone(&text))),
+            }),
+        );
+    }
+
+    methods.insert(
+        "set_style".to_string().into(),
+        Rc::new(CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Class(Rc::new("Style".to_string()))],
+            return_type: Some(ValueType::Class(Rc::clone(&text))),
+        }),
+    );
+
     ClassDeclaration { methods }
 }
@@ -62,6 +62,33 @@ pub fn new() -> FxHashMap<Rc<String>, CallableDeclaration> {
         },
     );
 
+    functions.insert(
+        Rc::new("assert".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Boolean],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("assert_eq".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Printable, ValueType::Printable],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("fail".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: None,
+        },
+    );
+
     functions.insert(
         Rc::new("sleep".to_string()),
         CallableDeclaration {
@@ -110,6 +137,69 @@ pub fn new() -> FxHashMap<Rc<String>, CallableDeclaration> {
         },
     );
 
+    functions.insert(
+        Rc::new("storage_get".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("storage_set".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("storage_remove".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("storage_keys".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("[String]".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("get_cookie".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("cookie_names".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("[String]".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("clear_cookies".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: None,
+        },
+    );
+
     functions.insert(
         Rc::new("write_string".to_string()),
         CallableDeclaration {
@@ -119,6 +209,24 @@ pub fn new() -> FxHashMap<Rc<String>, CallableDeclaration> {
         },
     );
 
+    functions.insert(
+        Rc::new("read_string_async".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("AsyncRequest".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("write_string_async".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("AsyncRequest".to_string()))),
+        },
+    );
+
     functions.insert(
         Rc::new("close_tab".to_string()),
         CallableDeclaration {
@@ -158,6 +266,159 @@ pub fn new() -> FxHashMap<Rc<String>, CallableDeclaration> {
         },
     );
 
+    functions.insert(
+        Rc::new("set_timeout".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Integer),
+        },
+    );
+
+    functions.insert(
+        Rc::new("set_interval".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Integer),
+        },
+    );
+
+    functions.insert(
+        Rc::new("timer_fired".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("clear_timer".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Integer],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("key_pressed".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("key_name".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("shift_held".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("control_held".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("alt_held".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("mouse_moved".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("mouse_clicked".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("mouse_button".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("mouse_x".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        },
+    );
+
+    functions.insert(
+        Rc::new("mouse_y".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        },
+    );
+
+    functions.insert(
+        Rc::new("window_resized".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Boolean),
+        },
+    );
+
+    functions.insert(
+        Rc::new("window_width".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        },
+    );
+
+    functions.insert(
+        Rc::new("window_height".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Float),
+        },
+    );
+
     functions.insert(
         Rc::new("integer_to_string".to_string()),
         CallableDeclaration {
@@ -248,6 +509,181 @@ pub fn new() -> FxHashMap<Rc<String>, CallableDeclaration> {
         },
     );
 
+    functions.insert(
+        Rc::new("HttpRequest".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String, ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("HttpRequest".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Socket".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("Socket".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Json".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("Json".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("json_parse".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("Json".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Checkbox".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("Checkbox".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Toggle".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("Toggle".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("RadioGroup".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("RadioGroup".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Slider".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float, ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::new("Slider".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Dropdown".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Class(Rc::new("[String]".into()))],
+            return_type: Some(ValueType::Class(Rc::new("Dropdown".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("TextArea".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::Class(Rc::new("TextArea".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Table".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Class(Rc::new("[String]".into()))],
+            return_type: Some(ValueType::Class(Rc::new("Table".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Scroll".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::new("Scroll".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Stack".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("Stack".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("dialog".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![
+                ValueType::String,
+                ValueType::String,
+                ValueType::Class(Rc::new("[String]".into())),
+            ],
+            return_type: Some(ValueType::Integer),
+        },
+    );
+
+    functions.insert(
+        Rc::new("clipboard_get".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("clipboard_set".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: None,
+        },
+    );
+
+    functions.insert(
+        Rc::new("Style".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![],
+            return_type: Some(ValueType::Class(Rc::new("Style".to_string()))),
+        },
+    );
+
+    functions.insert(
+        Rc::new("palette_colour".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::String],
+            return_type: Some(ValueType::String),
+        },
+    );
+
+    functions.insert(
+        Rc::new("Canvas".to_string()),
+        CallableDeclaration {
+            callable_type: CallableType::Native,
+            parameters: vec![ValueType::Float, ValueType::Float],
+            return_type: Some(ValueType::Class(Rc::new("Canvas".to_string()))),
+        },
+    );
+
     functions.insert(
         Rc::new("Input".to_string()),
         CallableDeclaration {
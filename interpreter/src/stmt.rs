@@ -1,6 +1,7 @@
 use crate::{expr::*, stack::Stack};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum Stmt {
     Expression(Expr),
     IntegerVariableInitialisation(IntegerExpr),
@@ -26,4 +27,6 @@ pub enum Stmt {
     Break,
     Continue,
     Return(Option<Expr>),
+    // Source position of the statement, only emitted when debug symbols are requested
+    Traced(usize, Box<Stmt>),
 }
@@ -0,0 +1,192 @@
+use crate::address::{Address, AddressType};
+use directories_next::ProjectDirs;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+// The most each origin can store, measured as the size of its file
+const MAX_SIZE: usize = 5 * 1024 * 1024;
+
+// Held while the values of an origin are changed, so tabs changing the same origin at the same
+// time do not lose each other's changes
+static CHANGING: Mutex<()> = Mutex::new(());
+
+// Values kept by scripts between sessions, stored in one file for each origin. Scripts loaded
+// over the network share the storage of their origin, and local scripts share the storage of
+// the directory they are in, so the scripts of an application see the same values.
+pub struct Storage {
+    path: PathBuf,
+}
+
+impl Storage {
+    pub fn new(address: &Address) -> Option<Self> {
+        let proj_dirs = ProjectDirs::from("rocks.sambowden", "", "zonkey")?;
+        Some(Self::in_directory(
+            &proj_dirs.data_dir().join(".storage"),
+            address,
+        ))
+    }
+
+    fn in_directory(directory: &Path, address: &Address) -> Self {
+        let hash = Sha256::digest(origin(address).as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        Self {
+            path: directory.join(hash + ".json"),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.load().remove(key)
+    }
+
+    pub fn set(&self, key: String, value: String) -> Result<(), String> {
+        self.change(|values| {
+            values.insert(key, value);
+            true
+        })
+    }
+
+    pub fn remove(&self, key: &str) -> Result<(), String> {
+        self.change(|values| values.remove(key).is_some())
+    }
+
+    // Keys are returned in alphabetical order
+    pub fn keys(&self) -> Vec<String> {
+        self.load().into_keys().collect()
+    }
+
+    // The file is read each time, so changes made by scripts in other tabs are seen
+    fn load(&self) -> BTreeMap<String, String> {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    // Saves the values if the change reports that it modified them
+    fn change(
+        &self,
+        change: impl FnOnce(&mut BTreeMap<String, String>) -> bool,
+    ) -> Result<(), String> {
+        let _changing = CHANGING.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut values = self.load();
+
+        match change(&mut values) {
+            true => self.save(&values),
+            false => Ok(()),
+        }
+    }
+
+    // The values are written to another file first and then moved over the old one, so they are
+    // never read while half written
+    fn save(&self, values: &BTreeMap<String, String>) -> Result<(), String> {
+        let contents = serde_json::to_string(values).map_err(|e| e.to_string())?;
+
+        if contents.len() > MAX_SIZE {
+            return Err(format!(
+                "Storage is limited to {} MB for each origin.",
+                MAX_SIZE / 1024 / 1024
+            ));
+        }
+
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(|e| e.to_string())?;
+        }
+
+        let temporary = self
+            .path
+            .with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary, contents).map_err(|e| e.to_string())?;
+        fs::rename(&temporary, &self.path).map_err(|e| {
+            fs::remove_file(&temporary).ok();
+            e.to_string()
+        })
+    }
+}
+
+fn origin(address: &Address) -> String {
+    match &address.address_type {
+        AddressType::HTTP { .. } => address.origin().unwrap_or_else(|| address.to_string()),
+        AddressType::File | AddressType::Installed => Path::new(&address.location)
+            .parent()
+            .map(|directory| format!("file:{}", directory.display()))
+            .unwrap_or_else(|| address.to_string()),
+        AddressType::Zonkey => String::from("zonkey:"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Storage;
+    use crate::Address;
+
+    #[test]
+    fn values_kept_per_origin() {
+        let directory = std::env::temp_dir().join("zonkey_storage_test");
+        std::fs::remove_dir_all(&directory).ok();
+
+        let storage =
+            |address: &str| Storage::in_directory(&directory, &Address::new(address, vec![]));
+
+        let app = storage("https://example.com/app.zonk");
+        app.set("theme".into(), "dark".into()).unwrap();
+        app.set("name".into(), "Sam".into()).unwrap();
+
+        // Other scripts from the same origin share the values
+        let other = storage("https://example.com/apps/other.zonk?page=2");
+        assert_eq!(other.get("theme").as_deref(), Some("dark"));
+        assert_eq!(other.keys(), vec!["name", "theme"]);
+
+        assert_eq!(storage("https://example.org/app.zonk").get("theme"), None);
+        assert_eq!(storage("http://example.com/app.zonk").get("theme"), None);
+
+        let local = storage("file:/home/user/app/main.zonk");
+        local.set("theme".into(), "light".into()).unwrap();
+        assert_eq!(
+            storage("/home/user/app/settings.zonk")
+                .get("theme")
+                .as_deref(),
+            Some("light")
+        );
+        assert_eq!(storage("/home/user/other/main.zonk").get("theme"), None);
+
+        other.remove("theme").unwrap();
+        other.remove("missing").unwrap();
+        assert_eq!(app.get("theme"), None);
+        assert_eq!(app.keys(), vec!["name"]);
+
+        // Values that would take the origin over its limit are refused without losing the others
+        assert!(app
+            .set("large".into(), "x".repeat(super::MAX_SIZE))
+            .is_err());
+        assert_eq!(app.keys(), vec!["name"]);
+
+        // Tabs setting values for the same origin at the same time keep every value
+        let threads = (0..8)
+            .map(|tab| {
+                let path = app.path.clone();
+                std::thread::spawn(move || {
+                    Storage { path }
+                        .set(format!("tab {tab}"), tab.to_string())
+                        .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(app.keys().len(), 9);
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+}
@@ -0,0 +1,80 @@
+use crate::canvas::Colour;
+
+// Styling defined once and set on many elements, which is shared so changing it restyles all of
+// them. The text and button styling set on a page, row or column is inherited by the elements
+// inside it, while the background and padding only apply to the element it is set on. Styling
+// set on an element itself always takes priority over its style.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Style {
+    pub text_size: Option<f32>,
+    pub text_colour: Option<Colour>,
+    pub button_colour: Option<Colour>,
+    pub button_text_colour: Option<Colour>,
+    pub button_padding: Option<(f32, f32)>,
+    pub background_colour: Option<Colour>,
+    // Vertical and horizontal
+    pub padding: Option<(f32, f32)>,
+}
+
+impl Style {
+    // The style of an element, where what it does not set is inherited from its parent
+    pub fn cascade(&self, parent: &Style) -> Style {
+        Style {
+            text_size: self.text_size.or(parent.text_size),
+            text_colour: self.text_colour.or(parent.text_colour),
+            button_colour: self.button_colour.or(parent.button_colour),
+            button_text_colour: self.button_text_colour.or(parent.button_text_colour),
+            button_padding: self.button_padding.or(parent.button_padding),
+            background_colour: self.background_colour,
+            padding: self.padding,
+        }
+    }
+}
+
+// The colours of the browser's own interface, which pages can use to match it
+pub const PALETTE: [(&str, Colour); 5] = [
+    ("background", (0xff, 0xff, 0xff)),
+    ("primary", (0xe1, 0xe2, 0xe2)),
+    ("text", (0x00, 0x00, 0x00)),
+    ("success", (0xff, 0xff, 0xff)),
+    ("danger", (0x00, 0x00, 0x00)),
+];
+
+pub fn palette_colour(name: &str) -> Option<Colour> {
+    PALETTE
+        .iter()
+        .find(|(colour_name, _)| *colour_name == name)
+        .map(|(_, colour)| *colour)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Style;
+
+    #[test]
+    fn text_and_button_styling_inherited() {
+        let parent = Style {
+            text_size: Some(30.),
+            text_colour: Some((255, 0, 0)),
+            button_colour: Some((0, 0, 255)),
+            background_colour: Some((0, 255, 0)),
+            padding: Some((5., 5.)),
+            ..Style::default()
+        };
+
+        let child = Style {
+            text_colour: Some((0, 0, 0)),
+            ..Style::default()
+        };
+
+        assert_eq!(
+            child.cascade(&parent),
+            Style {
+                text_size: Some(30.),
+                text_colour: Some((0, 0, 0)),
+                button_colour: Some((0, 0, 255)),
+                ..Style::default()
+            }
+        );
+    }
+}
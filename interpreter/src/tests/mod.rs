@@ -1,9 +1,16 @@
 use crate::{
     err::{InterpreterErr, InterpreterErrType},
+    event::{InterpreterEvent, PageEvent},
     lexer::Lexer,
     parser::{err::ParserErr, Parser},
+    run,
     token::Token,
-    tree_walker::state::NullableReference,
+    tree_walker::{state::NullableReference, Instrumentation},
+    Permissions,
+};
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread::JoinHandle,
 };
 
 fn get_failed_parser_err(tokens: Vec<Token>) -> ParserErr {
@@ -50,6 +57,73 @@ macro_rules! test_script_error {
     };
 }
 
+// Runs a script with every permission until it finishes, failing the test with its error messages
+// if it does not succeed, and returns the events it sent to the browser
+pub fn run_test_script(source: &str) -> Receiver<InterpreterEvent> {
+    // Kept open so the page is not treated as closed
+    let (_page_events, page_event_receiver) = mpsc::channel();
+
+    let (interpreter_events, script) = spawn_test_script(source, page_event_receiver);
+    script.join().unwrap();
+
+    interpreter_events
+}
+
+// Runs a script that is expected to fail with the given permissions, returning its error messages
+pub fn run_failing_test_script(source: &str, permissions: Permissions) -> String {
+    let source = source.to_string();
+
+    std::thread::Builder::new()
+        .stack_size(crate::REQUIRED_STACK_SIZE)
+        .spawn(move || {
+            let (mut sender, _interpreter_events) = mpsc::channel();
+            let (_page_events, page_event_receiver) = mpsc::channel();
+
+            match run(
+                &source,
+                &mut sender,
+                page_event_receiver,
+                permissions,
+                vec![],
+                Instrumentation::default(),
+            ) {
+                Ok(()) => panic!("Expected script to fail"),
+                Err(e) => e.get_err_messages(),
+            }
+        })
+        .expect("Failed to create thread to run test script.")
+        .join()
+        .expect("Failed to join thread running test.")
+}
+
+// Starts a script which receives the given page events, for tests that respond to the browser
+// events while the script is still running
+pub fn spawn_test_script(
+    source: &str,
+    page_events: Receiver<PageEvent>,
+) -> (Receiver<InterpreterEvent>, JoinHandle<()>) {
+    let source = source.to_string();
+    let (mut sender, receiver) = mpsc::channel();
+
+    let script = std::thread::Builder::new()
+        .stack_size(crate::REQUIRED_STACK_SIZE)
+        .spawn(move || {
+            if let Err(e) = run(
+                &source,
+                &mut sender,
+                page_events,
+                Permissions::all(),
+                vec![],
+                Instrumentation::default(),
+            ) {
+                panic!("{}", e.get_err_messages());
+            }
+        })
+        .expect("Failed to create thread to run test script.");
+
+    (receiver, script)
+}
+
 #[test]
 fn test_sizes() {
     #[allow(dead_code)]
@@ -181,6 +255,16 @@ fn start_redeclared() {
     test_script_error!("start_redec");
 }
 
+#[test]
+fn test_redeclared() {
+    test_script_error!("test_redec");
+}
+
+#[test]
+fn requires_invalid_capability() {
+    test_script_error!("requires_inv_capability");
+}
+
 #[test]
 fn variable_redeclared() {
     test_script_error!("var_redec");
@@ -525,3 +609,27 @@ fn float_more_than_one_decimal_point() {
 fn failed_to_parse_integer() {
     test_script_error!("failed_to_parse_integer");
 }
+
+#[test]
+fn slider_values_must_be_finite() {
+    for source in [
+        "start { Slider(0., 0. / 0.).set_value(1.); }",
+        "start { Slider(1. / 0., 0.); }",
+        "start { Slider(0., 10.).set_value(-1. / 0.); }",
+        "start { Slider(0., 10.).set_step(0. / 0.); }",
+    ] {
+        let error = run_failing_test_script(source, Permissions::all());
+        assert!(error.contains("InvalidSliderValue"), "{source}: {error}");
+    }
+}
+
+#[test]
+fn installed_files_need_full_addresses() {
+    let error = run_failing_test_script(
+        "start { install_application(String[\"Relative App\", \"zonkey:app.lnk\", \"zonkey:app.desktop\", \"app.zonk\"], false); }",
+        Permissions::all(),
+    );
+
+    assert!(error.contains("InstallFailed"));
+    assert!(error.contains("'app.zonk' must be a full address"));
+}
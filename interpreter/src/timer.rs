@@ -0,0 +1,119 @@
+use crate::event::PageEvent;
+use std::{
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// A timer started with set_timeout or set_interval, which fires on another thread so the script
+// keeps reacting to the page while it waits. wait_for_event returns each time it fires.
+#[derive(Debug)]
+pub struct Timer {
+    // Set when the fired event is received, and cleared when the script checks it
+    pub fired: bool,
+    // Set while a fired event is waiting to be received, so an interval which fires faster than
+    // the script handles it does not fill the queue
+    queued: bool,
+    // Dropped to stop the timer
+    stop: Option<Sender<()>>,
+}
+
+impl Timer {
+    pub fn start(
+        delay: Duration,
+        repeat: bool,
+        page_event_sender: Sender<Option<PageEvent>>,
+    ) -> Arc<Mutex<Self>> {
+        let (stop, stopped) = mpsc::channel();
+
+        let timer = Arc::new(Mutex::new(Self {
+            fired: false,
+            queued: false,
+            stop: Some(stop),
+        }));
+
+        let timer_ref = Arc::clone(&timer);
+
+        thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(delay) {
+                let mut timer = timer_ref.lock().unwrap();
+
+                if !timer.queued {
+                    timer.queued = true;
+
+                    if page_event_sender
+                        .send(Some(PageEvent::TimerFired(Arc::clone(&timer_ref))))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+
+                if !repeat {
+                    return;
+                }
+            }
+        });
+
+        timer
+    }
+
+    pub fn received(&mut self) {
+        self.queued = false;
+
+        // The script may have stopped the timer after the event was sent
+        if self.stop.is_some() {
+            self.fired = true;
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.stop = None;
+        self.fired = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::run_test_script;
+
+    #[test]
+    fn timers_fire_as_events() {
+        let source = "\
+start {
+    let timeout = set_timeout(100);
+    let interval = set_interval(10);
+    let cleared = set_timeout(20);
+    clear_timer(cleared);
+
+    let ticks = 0;
+    let timeouts = 0;
+
+    while (wait_for_event()) {
+        assert(!timer_fired(cleared));
+
+        if (timer_fired(interval)) {
+            ticks += 1;
+        }
+
+        if (timer_fired(timeout)) {
+            timeouts += 1;
+            break;
+        }
+    }
+
+    clear_timer(interval);
+
+    assert(ticks > 0);
+    assert(timeouts == 1);
+    assert(!timer_fired(timeout));
+    assert(!timer_fired(42));
+}
+";
+
+        run_test_script(source);
+    }
+}
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::{fmt::Display, rc::Rc};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Token {
     pub token_type: TokenType,
     pub start: usize,
@@ -9,7 +10,7 @@ pub struct Token {
 
 // Adapted from TokenType template from Crating Interpreters
 // https://craftinginterpreters.com/scanning.html#token-type
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum TokenType {
     LeftParen,
     RightParen,
@@ -0,0 +1,216 @@
+use crate::debug_symbols::DebugSymbols;
+use rustc_hash::FxHashMap;
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf, rc::Rc};
+
+pub struct Coverage {
+    symbols: Rc<DebugSymbols>,
+    report_path: PathBuf,
+    source_name: String,
+    statement_hits: FxHashMap<usize, u64>,
+    // Times the condition of the statement at each position was true and false
+    branch_hits: FxHashMap<usize, [u64; 2]>,
+    statements: Vec<usize>,
+}
+
+impl Coverage {
+    pub fn new(report_path: PathBuf, source_name: String) -> Self {
+        Self {
+            symbols: Rc::default(),
+            report_path,
+            source_name,
+            statement_hits: FxHashMap::default(),
+            branch_hits: FxHashMap::default(),
+            statements: vec![],
+        }
+    }
+
+    pub fn load_symbols(&mut self, symbols: Rc<DebugSymbols>) {
+        self.symbols = symbols;
+    }
+
+    pub fn enter_statement(&mut self, position: usize) {
+        *self.statement_hits.entry(position).or_default() += 1;
+        self.statements.push(position);
+    }
+
+    pub fn exit_statement(&mut self) {
+        self.statements.pop();
+    }
+
+    // Conditions are only evaluated by if, while and for statements, so the branch belongs to
+    // the innermost statement being executed
+    pub fn branch(&mut self, taken: bool) {
+        if let Some(position) = self.statements.last() {
+            let hits = self.branch_hits.entry(*position).or_default();
+            hits[usize::from(!taken)] += 1;
+        }
+    }
+
+    pub fn finish(&self) {
+        if let Err(e) = std::fs::write(&self.report_path, self.report()) {
+            eprintln!(
+                "Failed to write coverage report to '{}': {e}",
+                self.report_path.display()
+            );
+        }
+    }
+
+    // Report in the lcov tracefile format, where the branches of each condition are numbered 0
+    // when it is true and 1 when it is false
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        writeln!(report, "TN:").unwrap();
+        writeln!(report, "SF:{}", self.source_name).unwrap();
+
+        // Lines with several statements report the hits of the most executed one
+        let mut lines = BTreeMap::new();
+
+        for position in &self.symbols.statements {
+            let hits = self.statement_hits.get(position).copied().unwrap_or(0);
+            let line = lines.entry(self.symbols.line(*position)).or_insert(0);
+            *line = hits.max(*line);
+        }
+
+        let mut branches: BTreeMap<usize, Vec<Option<[u64; 2]>>> = BTreeMap::new();
+
+        for position in &self.symbols.branches {
+            branches
+                .entry(self.symbols.line(*position))
+                .or_default()
+                .push(self.branch_hits.get(position).copied());
+        }
+
+        let (mut branches_found, mut branches_hit) = (0, 0);
+
+        for (line, conditions) in &branches {
+            for (block, hits) in conditions.iter().enumerate() {
+                for branch in 0..2 {
+                    let taken = match hits {
+                        Some(hits) => hits[branch].to_string(),
+                        None => String::from("-"),
+                    };
+
+                    branches_found += 1;
+                    if hits.is_some_and(|hits| hits[branch] > 0) {
+                        branches_hit += 1;
+                    }
+
+                    writeln!(report, "BRDA:{line},{block},{branch},{taken}").unwrap();
+                }
+            }
+        }
+
+        writeln!(report, "BRF:{branches_found}").unwrap();
+        writeln!(report, "BRH:{branches_hit}").unwrap();
+
+        for (line, hits) in &lines {
+            writeln!(report, "DA:{line},{hits}").unwrap();
+        }
+
+        writeln!(report, "LF:{}", lines.len()).unwrap();
+        writeln!(
+            report,
+            "LH:{}",
+            lines.values().filter(|hits| **hits > 0).count()
+        )
+        .unwrap();
+        writeln!(report, "end_of_record").unwrap();
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Coverage;
+    use crate::{run, tree_walker::Instrumentation, Permissions};
+    use std::sync::mpsc;
+
+    #[test]
+    fn statements_and_branches() {
+        let report_path =
+            std::env::temp_dir().join(format!("zonkey-coverage-{}.info", std::process::id()));
+
+        let source = "\
+function sign(Integer n) -> String {
+    if (n < 0) {
+        return \"negative\";
+    } else if (n == 0) {
+        return \"zero\";
+    }
+    return \"positive\";
+}
+
+start {
+    for (let i = 1, i <= 2, i += 1) {
+        sign(i);
+    }
+    if (false) {
+        print(\"never\");
+    }
+}
+";
+
+        let report_path_copy = report_path.clone();
+
+        std::thread::Builder::new()
+            .stack_size(crate::REQUIRED_STACK_SIZE)
+            .spawn(move || {
+                let (mut sender, _) = mpsc::channel();
+                let (_, receiver) = mpsc::channel();
+
+                let instrumentation = Instrumentation {
+                    coverage: Some(Coverage::new(report_path_copy, String::from("sign.zonk"))),
+                    ..Default::default()
+                };
+
+                if let Err(e) = run(
+                    source,
+                    &mut sender,
+                    receiver,
+                    Permissions::all(),
+                    vec![],
+                    instrumentation,
+                ) {
+                    panic!("{}", e.get_err_messages());
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        let report = std::fs::read_to_string(&report_path).unwrap();
+        std::fs::remove_file(&report_path).unwrap();
+
+        assert_eq!(
+            report,
+            "\
+TN:
+SF:sign.zonk
+BRDA:2,0,0,0
+BRDA:2,0,1,2
+BRDA:4,0,0,0
+BRDA:4,0,1,2
+BRDA:11,0,0,2
+BRDA:11,0,1,1
+BRDA:14,0,0,0
+BRDA:14,0,1,1
+BRF:8
+BRH:5
+DA:2,2
+DA:3,0
+DA:4,2
+DA:5,0
+DA:7,2
+DA:11,1
+DA:12,2
+DA:14,1
+DA:15,0
+LF:9
+LH:6
+end_of_record
+"
+        );
+    }
+}
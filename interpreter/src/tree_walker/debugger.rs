@@ -0,0 +1,412 @@
+use super::{
+    object::{NativeObject, Object},
+    state::State,
+    TreeWalker,
+};
+use crate::{
+    debug_symbols::DebugSymbols, parser::location::Location, tree_walker::err::TreeWalkerErr,
+};
+use rustc_hash::FxHashSet;
+use std::{
+    io::{stdin, stdout, BufRead, BufReader, Write},
+    rc::Rc,
+};
+
+const HELP: &str = "\
+Commands:
+  break <line>     (b)  Pause when a statement on the line is reached
+  delete <line>    (d)  Remove the breakpoint on the line
+  step             (s)  Run until the next statement, entering calls
+  next             (n)  Run until the next statement, stepping over calls
+  out              (o)  Run until the current callable returns
+  continue         (c)  Run until a breakpoint is reached
+  print <name>     (p)  Print the value of a variable in scope
+  locals           (l)  Print every variable in scope
+  backtrace        (bt) Print the callables being executed
+  list                  Print the source surrounding the current line
+  quit             (q)  Stop the script
+An empty line repeats the last command.";
+
+pub struct Debugger {
+    symbols: Rc<DebugSymbols>,
+    breakpoints: FxHashSet<usize>,
+    step: Step,
+    frames: Vec<Frame>,
+    last_command: String,
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+}
+
+enum Step {
+    Into,
+    Over(usize),
+    Out(usize),
+    Continue,
+}
+
+struct Frame {
+    callable: Option<usize>,
+    position: usize,
+}
+
+impl Default for Debugger {
+    fn default() -> Self {
+        Self::with_io(Box::new(BufReader::new(stdin())), Box::new(stdout()))
+    }
+}
+
+impl Debugger {
+    pub fn with_io(input: Box<dyn BufRead>, output: Box<dyn Write>) -> Self {
+        Self {
+            symbols: Rc::default(),
+            breakpoints: FxHashSet::default(),
+            // Pause before the first statement so breakpoints can be set
+            step: Step::Into,
+            frames: vec![Frame {
+                callable: None,
+                position: 0,
+            }],
+            last_command: String::new(),
+            input,
+            output,
+        }
+    }
+
+    pub fn load_symbols(&mut self, symbols: Rc<DebugSymbols>) {
+        self.symbols = symbols;
+    }
+
+    pub fn enter_callable(&mut self, callable: usize) {
+        self.frames.push(Frame {
+            callable: Some(callable),
+            position: 0,
+        });
+    }
+
+    pub fn exit_callable(&mut self) {
+        self.frames.pop();
+    }
+
+    fn reached(&mut self, position: usize) -> bool {
+        let depth = self.frames.len();
+        self.frames.last_mut().unwrap().position = position;
+
+        let stepped = match self.step {
+            Step::Into => true,
+            Step::Over(start_depth) => depth <= start_depth,
+            Step::Out(start_depth) => depth < start_depth,
+            Step::Continue => false,
+        };
+
+        stepped || self.breakpoints.contains(&self.symbols.line(position))
+    }
+
+    fn prompt(&mut self, state: &State) -> Result<(), TreeWalkerErr> {
+        let frame = self.frames.last().unwrap();
+        let line = self.symbols.line(frame.position);
+
+        writeln!(
+            self.output,
+            "Paused at line {} in {}",
+            line,
+            self.symbols.callable_name(frame.callable)
+        )
+        .unwrap();
+        self.write_source_line(line, true);
+
+        loop {
+            write!(self.output, "(debug) ").unwrap();
+            self.output.flush().unwrap();
+
+            let mut command = String::new();
+
+            // Run the rest of the script without pausing once there is no more input
+            if let Ok(0) | Err(_) = self.input.read_line(&mut command) {
+                writeln!(self.output).unwrap();
+                self.breakpoints.clear();
+                self.step = Step::Continue;
+                return Ok(());
+            }
+
+            if command.trim().is_empty() {
+                command = self.last_command.clone();
+            } else {
+                self.last_command = command.clone();
+            }
+
+            let mut words = command.split_whitespace();
+            let depth = self.frames.len();
+
+            match (words.next(), words.next()) {
+                (Some("s" | "step"), None) => self.step = Step::Into,
+                (Some("n" | "next"), None) => self.step = Step::Over(depth),
+                (Some("o" | "out"), None) => self.step = Step::Out(depth),
+                (Some("c" | "continue"), None) => self.step = Step::Continue,
+                (Some("q" | "quit"), None) => return Err(TreeWalkerErr::Exit),
+                (Some("b" | "break"), Some(line)) => {
+                    if let Some(line) = self.parse_line(line) {
+                        self.breakpoints.insert(line);
+                        writeln!(self.output, "Breakpoint set at line {line}").unwrap();
+                    }
+                    continue;
+                }
+                (Some("d" | "delete"), Some(line)) => {
+                    if let Some(line) = self.parse_line(line) {
+                        if self.breakpoints.remove(&line) {
+                            writeln!(self.output, "Breakpoint removed from line {line}").unwrap();
+                        } else {
+                            writeln!(self.output, "There is no breakpoint on line {line}").unwrap();
+                        }
+                    }
+                    continue;
+                }
+                (Some("p" | "print"), Some(name)) => {
+                    let frame = self.frames.last().unwrap();
+
+                    match self
+                        .symbols
+                        .find_variable(frame.callable, frame.position, name)
+                    {
+                        Some(variable) => {
+                            let value = self.format_location(state, &variable.location, true);
+                            writeln!(self.output, "{name} = {value}").unwrap();
+                        }
+                        None => {
+                            writeln!(self.output, "No variable named '{name}' is in scope").unwrap()
+                        }
+                    }
+                    continue;
+                }
+                (Some("l" | "locals"), None) => {
+                    let frame = self.frames.last().unwrap();
+                    let mut variables = self
+                        .symbols
+                        .visible_variables(frame.callable, frame.position);
+
+                    if variables.is_empty() {
+                        writeln!(self.output, "No variables are in scope").unwrap();
+                    }
+
+                    variables.reverse();
+
+                    for variable in variables {
+                        let value = self.format_location(state, &variable.location, true);
+                        writeln!(self.output, "{} = {}", variable.name, value).unwrap();
+                    }
+                    continue;
+                }
+                (Some("bt" | "backtrace"), None) => {
+                    for (i, frame) in self.frames.iter().rev().enumerate() {
+                        writeln!(
+                            self.output,
+                            "#{} {} at line {}",
+                            i,
+                            self.symbols.callable_name(frame.callable),
+                            self.symbols.line(frame.position)
+                        )
+                        .unwrap();
+                    }
+                    continue;
+                }
+                (Some("list"), None) => {
+                    let first = line.saturating_sub(3).max(1);
+                    let last = (line + 3).min(self.symbols.line_count());
+
+                    for current in first..=last {
+                        self.write_source_line(current, current == line);
+                    }
+                    continue;
+                }
+                (Some("h" | "help"), None) => {
+                    writeln!(self.output, "{HELP}").unwrap();
+                    continue;
+                }
+                _ => {
+                    writeln!(
+                        self.output,
+                        "Unknown command '{}'. Type 'help' for a list of commands.",
+                        command.trim()
+                    )
+                    .unwrap();
+                    continue;
+                }
+            }
+
+            return Ok(());
+        }
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<usize> {
+        match line.parse::<usize>() {
+            Ok(line) if line > 0 && line <= self.symbols.line_count() => Some(line),
+            _ => {
+                writeln!(self.output, "'{line}' is not a line in the script").unwrap();
+                None
+            }
+        }
+    }
+
+    fn write_source_line(&mut self, line: usize, current: bool) {
+        if let Some(source) = self.symbols.source_line(line) {
+            let marker = if current { ">" } else { " " };
+            writeln!(self.output, "{marker}{line:>4} | {source}").unwrap();
+        }
+    }
+
+    fn format_location(&self, state: &State, location: &Location, expand: bool) -> String {
+        let stack = state.stack();
+
+        match location {
+            Location::Integer(id) if *id < stack.integer => state.get_int(*id).to_string(),
+            Location::Float(id) if *id < stack.float => format!("{:?}", state.get_float(*id)),
+            Location::String(id) if *id < stack.string => format!("{:?}", state.get_string(*id)),
+            Location::Boolean(id) if *id < stack.boolean => state.get_boolean(*id).to_string(),
+            Location::Object(class, id) if *id < stack.object => match state.get_object(*id) {
+                Ok(Object::Zonkey(object)) => match (expand, self.symbols.classes.get(class)) {
+                    (true, Some(properties)) => {
+                        let object = object.borrow();
+                        let properties = properties
+                            .iter()
+                            .map(|(name, location)| {
+                                format!(
+                                    "{}: {}",
+                                    name,
+                                    self.format_location(&object, location, false)
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        format!("{} {{ {} }}", class, properties.join(", "))
+                    }
+                    _ => format!("<{class}>"),
+                },
+                Ok(Object::Native(object)) => match object {
+                    NativeObject::IntegerArray(array) => format!("{:?}", array.lock().unwrap()),
+                    NativeObject::FloatArray(array) => format!("{:?}", array.lock().unwrap()),
+                    NativeObject::StringArray(array) => format!("{:?}", array.lock().unwrap()),
+                    NativeObject::BooleanArray(array) => format!("{:?}", array.lock().unwrap()),
+                    _ => format!("<{class}>"),
+                },
+                Err(_) => String::from("<not initialised>"),
+            },
+            _ => String::from("<unavailable>"),
+        }
+    }
+}
+
+impl<'a> TreeWalker<'a> {
+    pub fn debug_statement(&mut self, position: usize) -> Result<(), TreeWalkerErr> {
+        let debugger = self.debugger.as_mut().unwrap();
+
+        if !debugger.reached(position) {
+            return Ok(());
+        }
+
+        // Output of the script so far should appear before the prompt
+        stdout().write_all(&self.stdout).unwrap();
+        stdout().flush().unwrap();
+        self.stdout.clear();
+
+        debugger.prompt(&self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Debugger;
+    use crate::{run, tree_walker::Instrumentation, Permissions};
+    use std::{
+        cell::RefCell,
+        io::{Cursor, Write},
+        rc::Rc,
+        sync::mpsc,
+    };
+
+    #[derive(Clone, Default)]
+    struct Transcript(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Transcript {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn debug_script(source: &'static str, commands: &'static str) -> String {
+        let builder = std::thread::Builder::new().stack_size(crate::REQUIRED_STACK_SIZE);
+
+        builder
+            .spawn(move || {
+                let transcript = Transcript::default();
+                let debugger = Debugger::with_io(
+                    Box::new(Cursor::new(commands)),
+                    Box::new(transcript.clone()),
+                );
+                let (mut sender, _) = mpsc::channel();
+                let (_, receiver) = mpsc::channel();
+
+                if let Err(e) = run(
+                    source,
+                    &mut sender,
+                    receiver,
+                    Permissions::all(),
+                    vec![],
+                    Instrumentation {
+                        debugger: Some(debugger),
+                        ..Default::default()
+                    },
+                ) {
+                    panic!("{}", e.get_err_messages());
+                }
+
+                let output = String::from_utf8(transcript.0.borrow().clone()).unwrap();
+                output
+            })
+            .expect("Failed to create thread to run debugger.")
+            .join()
+            .expect("Failed to join thread running debugger.")
+    }
+
+    const SCRIPT: &str = "\
+function square(Integer x) -> Integer {
+    let result = x * x;
+    return result;
+}
+
+start {
+    let total = 0;
+    for (let i = 1, i <= 3, i += 1) {
+        total += square(i);
+    }
+    let name = \"done\";
+}
+";
+
+    #[test]
+    fn breakpoints_and_locals() {
+        let output = debug_script(SCRIPT, "break 3\ncontinue\nlocals\nbacktrace\nquit\n");
+
+        assert!(output.contains("Paused at line 7 in start"));
+        assert!(output.contains("Breakpoint set at line 3"));
+        assert!(output.contains("Paused at line 3 in square"));
+        assert!(output.contains("x = 1\nresult = 1\n"));
+        assert!(output.contains("#0 square at line 3\n#1 start at line 9\n"));
+    }
+
+    #[test]
+    fn stepping() {
+        let output = debug_script(
+            SCRIPT,
+            "next\nnext\nstep\nstep\nprint x\nout\nprint total\nprint result\nc\n",
+        );
+
+        assert!(output.contains("Paused at line 8 in start"));
+        assert!(output.contains("Paused at line 2 in square"));
+        assert!(output.contains("x = 1\n"));
+        assert!(output.contains("Paused at line 9 in start"));
+        assert!(output.contains("total = 1\n"));
+        assert!(output.contains("No variable named 'result' is in scope"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::token::Token;
+use crate::{permission::Capability, token::Token};
 
 #[derive(Debug)]
 pub enum TreeWalkerErr {
@@ -8,10 +8,20 @@ pub enum TreeWalkerErr {
     FailedStringToIntegerCast(Token),
     FailedStringToFloatCast(Token),
     Exit,
-    InsufficientPermissionLevel,
+    PermissionDenied(Capability),
     InstallFailed(String),
     SettingsFailed(String),
     ReadAddressFailed(String),
     WriteAddressFailed(String),
+    HttpRequestFailed(String),
+    SocketFailed(String),
+    StorageFailed(String),
+    JsonParseFailed(String, Token),
+    JsonAccessFailed(String, Token),
     InvalidHexColour(String),
+    InvalidAlignment(String),
+    InvalidFontFamily(String),
+    InvalidPaletteColour(String),
+    InvalidSliderValue(f64),
+    AssertionFailed(String, Token),
 }
@@ -1,29 +1,41 @@
 use self::{
+    coverage::Coverage,
+    debugger::Debugger,
     err::TreeWalkerErr,
     object::{NativeObject, Object},
+    profiler::Profiler,
     state::{NullableReference, State},
     status::TreeWalkerStatus,
 };
 use crate::{
     ast::AST,
+    debug_symbols::DebugSymbols,
     element::*,
     event::{InterpreterEvent, PageEvent},
     expr::*,
+    input::{InputState, Subscriptions},
     parser::declaration::ConstructionType,
+    socket::Socket,
     stmt::Stmt,
-    tree_walker_debug, PermissionLevel,
+    storage::Storage,
+    timer::Timer,
+    tree_walker_debug, Address, Permissions,
 };
 use std::{
     cell::RefCell,
     io::{stdout, Write},
     rc::Rc,
-    sync::mpsc::Receiver,
+    sync::mpsc::{self, Receiver},
     sync::{mpsc::Sender, Arc, Mutex},
+    thread,
 };
 
+pub mod coverage;
+pub mod debugger;
 pub mod err;
 mod native_call;
 mod object;
+pub mod profiler;
 pub mod state;
 pub mod status;
 
@@ -32,10 +44,56 @@ pub struct TreeWalker<'a> {
     callables: Vec<Rc<Stmt>>,
     stdout: Vec<u8>,
     interpreter_event_sender: &'a mut Sender<InterpreterEvent>,
-    page_event_receiver: Receiver<PageEvent>,
+    // Events from the page are forwarded here along with those from background work, where None
+    // means the page was closed
+    page_event_receiver: Receiver<Option<PageEvent>>,
+    page_event_sender: Sender<Option<PageEvent>>,
+    // Closed when the script finishes, as they would otherwise stay connected
+    sockets: Vec<Arc<Mutex<Socket>>>,
+    // Stopped when the script finishes, where a timer's id is its index
+    timers: Vec<Arc<Mutex<Timer>>>,
+    // The latest keyboard, mouse and window events from the page
+    input: InputState,
+    // Input the script never checks for doesn't wake wait_for_event
+    subscriptions: Subscriptions,
     element_id: u64,
-    permission_level: PermissionLevel,
+    permissions: Permissions,
+    // Relative addresses used by the script are resolved against its own address
+    address: Address,
     arguments: Arc<Mutex<Vec<String>>>,
+    debugger: Option<Debugger>,
+    profiler: Option<Profiler>,
+    coverage: Option<Coverage>,
+}
+
+// Optional tools which observe the script as it is interpreted
+#[derive(Default)]
+pub struct Instrumentation {
+    pub debugger: Option<Debugger>,
+    pub profiler: Option<Profiler>,
+    pub coverage: Option<Coverage>,
+}
+
+impl Instrumentation {
+    pub fn needs_debug_symbols(&self) -> bool {
+        self.debugger.is_some() || self.profiler.is_some() || self.coverage.is_some()
+    }
+
+    pub fn load_symbols(&mut self, debug_symbols: DebugSymbols) {
+        let debug_symbols = Rc::new(debug_symbols);
+
+        if let Some(debugger) = &mut self.debugger {
+            debugger.load_symbols(Rc::clone(&debug_symbols));
+        }
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.load_symbols(Rc::clone(&debug_symbols));
+        }
+
+        if let Some(coverage) = &mut self.coverage {
+            coverage.load_symbols(debug_symbols);
+        }
+    }
 }
 
 impl<'a> TreeWalker<'a> {
@@ -43,32 +101,85 @@ impl<'a> TreeWalker<'a> {
         ast: AST,
         interpreter_event_sender: &'a mut Sender<InterpreterEvent>,
         page_event_receiver: Receiver<PageEvent>,
-        permission_level: PermissionLevel,
-        arguments: Vec<String>,
+        permissions: Permissions,
+        address: Address,
+        instrumentation: Instrumentation,
     ) -> Result<TreeWalkerStatus, TreeWalkerErr> {
+        let (page_event_sender, forwarded_page_event_receiver) = mpsc::channel();
+        let forwarder = page_event_sender.clone();
+
+        thread::spawn(move || {
+            for event in page_event_receiver.iter() {
+                if forwarder.send(Some(event)).is_err() {
+                    return;
+                }
+            }
+            forwarder.send(None).ok();
+        });
+
         let mut tree_walker = Self {
             state: State::new(),
             callables: ast.callable,
             stdout: vec![],
             interpreter_event_sender,
-            page_event_receiver,
+            page_event_receiver: forwarded_page_event_receiver,
+            page_event_sender,
+            sockets: vec![],
+            timers: vec![],
+            input: InputState::default(),
+            subscriptions: ast.subscriptions,
             element_id: 0,
-            permission_level,
-            arguments: Arc::new(Mutex::new(arguments)),
+            permissions,
+            arguments: Arc::new(Mutex::new(address.arguments.clone())),
+            address,
+            debugger: instrumentation.debugger,
+            profiler: instrumentation.profiler,
+            coverage: instrumentation.coverage,
         };
 
+        if let Some(profiler) = &mut tree_walker.profiler {
+            profiler.start();
+        }
+
         let result = tree_walker.interpret(&ast.start);
         stdout().write_all(&tree_walker.stdout).unwrap();
         tree_walker.stdout.clear();
+
+        if let Some(profiler) = &mut tree_walker.profiler {
+            profiler.finish();
+        }
+
+        if let Some(coverage) = &tree_walker.coverage {
+            coverage.finish();
+        }
+
+        for socket in &tree_walker.sockets {
+            socket.lock().unwrap().close();
+        }
+
+        for timer in &tree_walker.timers {
+            timer.lock().unwrap().stop();
+        }
+
         result
     }
 
+    fn timer(&self, id: i64) -> Option<&Arc<Mutex<Timer>>> {
+        usize::try_from(id).ok().and_then(|id| self.timers.get(id))
+    }
+
     fn next_element_id(&mut self) -> u64 {
         let id = self.element_id;
         self.element_id += 1;
         id
     }
 
+    fn storage(&self) -> Result<Storage, TreeWalkerErr> {
+        Storage::new(&self.address).ok_or_else(|| {
+            TreeWalkerErr::StorageFailed(String::from("Couldn't find zonkey data directory."))
+        })
+    }
+
     pub fn interpret(&mut self, statement: &Stmt) -> Result<TreeWalkerStatus, TreeWalkerErr> {
         tree_walker_debug!(format!("Interpret statement: {:?}", statement).as_str());
 
@@ -202,7 +313,7 @@ impl<'a> TreeWalker<'a> {
                 return_value
             }
             Stmt::If(condition, true_branch, false_branch) => {
-                if self.eval_boolean(condition)? {
+                if self.eval_condition(condition)? {
                     self.interpret(&true_branch)
                 } else if let Some(false_branch) = false_branch {
                     self.interpret(&false_branch)
@@ -235,7 +346,7 @@ impl<'a> TreeWalker<'a> {
                 Ok(TreeWalkerStatus::Ok)
             }
             Stmt::While(condition, block) => {
-                while self.eval_boolean(condition)? {
+                while self.eval_condition(condition)? {
                     match self.interpret(block) {
                         Ok(TreeWalkerStatus::Ok) => (),
                         Ok(TreeWalkerStatus::Continue) => (),
@@ -258,6 +369,31 @@ impl<'a> TreeWalker<'a> {
 
                 Ok(TreeWalkerStatus::Ok)
             }
+            Stmt::Traced(position, statement) => {
+                if self.debugger.is_some() {
+                    self.debug_statement(*position)?;
+                }
+
+                if let Some(profiler) = &mut self.profiler {
+                    profiler.enter_statement(*position);
+                }
+
+                if let Some(coverage) = &mut self.coverage {
+                    coverage.enter_statement(*position);
+                }
+
+                let result = self.interpret(statement);
+
+                if let Some(profiler) = &mut self.profiler {
+                    profiler.exit_statement();
+                }
+
+                if let Some(coverage) = &mut self.coverage {
+                    coverage.exit_statement();
+                }
+
+                result
+            }
             Stmt::Break => Ok(TreeWalkerStatus::Break),
             Stmt::Continue => Ok(TreeWalkerStatus::Continue),
             Stmt::Return(expr) => match expr {
@@ -319,7 +455,7 @@ impl<'a> TreeWalker<'a> {
                 .extract_zonkey_object()
                 .borrow_mut()
                 .get_int(*id)),
-            IntegerExpr::NativeCall(call) => self.native_call_integer(call),
+            IntegerExpr::NativeCall(call) => self.native_call(call, Self::native_call_integer),
         }
     }
 
@@ -344,7 +480,7 @@ impl<'a> TreeWalker<'a> {
                 TreeWalkerStatus::ReturnFloat(v) => Ok(v),
                 _ => panic!("Call did not return correct type"),
             },
-            FloatExpr::NativeCall(call) => self.native_call_float(call),
+            FloatExpr::NativeCall(call) => self.native_call(call, Self::native_call_float),
             FloatExpr::Property(obj_id, id) => Ok(self
                 .state
                 .get_object(*obj_id)?
@@ -369,7 +505,7 @@ impl<'a> TreeWalker<'a> {
                 TreeWalkerStatus::ReturnString(v) => Ok(v),
                 _ => panic!("Call did not return correct type"),
             },
-            StringExpr::NativeCall(call) => self.native_call_string(call),
+            StringExpr::NativeCall(call) => self.native_call(call, Self::native_call_string),
             StringExpr::Property(obj_id, id) => Ok(self
                 .state
                 .get_object(*obj_id)?
@@ -379,6 +515,17 @@ impl<'a> TreeWalker<'a> {
         }
     }
 
+    // Evaluates the condition of an if or while statement, recording the branch taken
+    fn eval_condition(&mut self, condition: &BooleanExpr) -> Result<bool, TreeWalkerErr> {
+        let result = self.eval_boolean(condition)?;
+
+        if let Some(coverage) = &mut self.coverage {
+            coverage.branch(result);
+        }
+
+        Ok(result)
+    }
+
     fn eval_boolean(&mut self, expression: &BooleanExpr) -> Result<bool, TreeWalkerErr> {
         match expression {
             BooleanExpr::IntegerBinary {
@@ -446,7 +593,7 @@ impl<'a> TreeWalker<'a> {
             BooleanExpr::Unary(unary_operator, expr) => match unary_operator {
                 BooleanUnaryOperator::Bang => Ok(!self.eval_boolean(expr)?),
             },
-            BooleanExpr::NativeCall(call) => self.native_call_boolean(call),
+            BooleanExpr::NativeCall(call) => self.native_call(call, Self::native_call_boolean),
             BooleanExpr::Property(obj_id, id) => Ok(self
                 .state
                 .get_object(*obj_id)?
@@ -458,7 +605,7 @@ impl<'a> TreeWalker<'a> {
 
     fn eval_none(&mut self, expression: &NoneExpr) -> Result<(), TreeWalkerErr> {
         match expression {
-            NoneExpr::NativeCall(call) => self.native_call_none(call),
+            NoneExpr::NativeCall(call) => self.native_call(call, Self::native_call_none),
             NoneExpr::Call(id, expressions) => match self.eval_call(*id, expressions)? {
                 TreeWalkerStatus::ReturnNone | TreeWalkerStatus::Ok => Ok(()),
                 _ => panic!("Call did not return correct type"),
@@ -473,7 +620,7 @@ impl<'a> TreeWalker<'a> {
                 TreeWalkerStatus::ReturnObject(v) => Ok(v),
                 v => panic!("Call did not return correct type - {:?} was returned", v),
             },
-            ObjectExpr::NativeCall(call) => self.native_call_object(call),
+            ObjectExpr::NativeCall(call) => self.native_call(call, Self::native_call_object),
             ObjectExpr::Constructor(properties) => {
                 let mut object = State::new();
 
@@ -510,6 +657,18 @@ impl<'a> TreeWalker<'a> {
             NativeObject::Row(row) => ElementType::Row(Arc::clone(row)),
             NativeObject::Column(column) => ElementType::Column(Arc::clone(column)),
             NativeObject::Image(image) => ElementType::Image(Arc::clone(image)),
+            NativeObject::Checkbox(checkbox) => ElementType::Checkbox(Arc::clone(checkbox)),
+            NativeObject::Toggle(toggle) => ElementType::Toggle(Arc::clone(toggle)),
+            NativeObject::RadioGroup(radio_group) => {
+                ElementType::RadioGroup(Arc::clone(radio_group))
+            }
+            NativeObject::Slider(slider) => ElementType::Slider(Arc::clone(slider)),
+            NativeObject::Dropdown(dropdown) => ElementType::Dropdown(Arc::clone(dropdown)),
+            NativeObject::TextArea(text_area) => ElementType::TextArea(Arc::clone(text_area)),
+            NativeObject::Table(table) => ElementType::Table(Arc::clone(table)),
+            NativeObject::Canvas(canvas) => ElementType::Canvas(Arc::clone(canvas)),
+            NativeObject::Scroll(scroll) => ElementType::Scroll(Arc::clone(scroll)),
+            NativeObject::Stack(stack) => ElementType::Stack(Arc::clone(stack)),
             _ => unreachable!("Not applicable for this object"),
         }
     }
@@ -552,8 +711,24 @@ impl<'a> TreeWalker<'a> {
 
         let callable = &self.callables[id];
 
+        if let Some(debugger) = &mut self.debugger {
+            debugger.enter_callable(id);
+        }
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter_callable(id);
+        }
+
         let result = self.interpret(&Rc::clone(callable));
 
+        if let Some(debugger) = &mut self.debugger {
+            debugger.exit_callable();
+        }
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.exit();
+        }
+
         std::mem::swap(&mut state, &mut self.state);
 
         result
@@ -1,5 +1,5 @@
 use super::prelude::*;
-use crate::{event::PageEvent, standard_prelude::calls::NativeCallBoolean};
+use crate::{canvas::Pointer, event::PageEvent, json, standard_prelude::calls::NativeCallBoolean};
 
 impl<'a> TreeWalker<'a> {
     pub fn native_call_boolean(&mut self, call: &NativeCallBoolean) -> Result<bool, TreeWalkerErr> {
@@ -8,19 +8,412 @@ impl<'a> TreeWalker<'a> {
                 self.interpreter_event_sender
                     .send(InterpreterEvent::Update)
                     .ok();
-                match self.page_event_receiver.recv() {
-                    Ok(PageEvent::ButtonPress(button)) => {
-                        button.lock().unwrap().clicked = true;
-                        Ok(true)
-                    }
-                    Ok(PageEvent::InputConfirmed(input)) => {
-                        input.lock().unwrap().confirmed = true;
-                        Ok(true)
+                // Input the script doesn't check for is recorded without waking it
+                loop {
+                    let woken = match self.page_event_receiver.recv() {
+                        Ok(Some(PageEvent::ButtonPress(button))) => {
+                            button.lock().unwrap().clicked = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::InputConfirmed(input))) => {
+                            input.lock().unwrap().confirmed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::CheckboxChanged(checkbox))) => {
+                            checkbox.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::ToggleChanged(toggle))) => {
+                            toggle.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::RadioGroupChanged(radio_group))) => {
+                            radio_group.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::SliderChanged(slider))) => {
+                            slider.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::DropdownChanged(dropdown))) => {
+                            dropdown.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::TextAreaChanged(text_area))) => {
+                            text_area.lock().unwrap().changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::TableSorted(table))) => {
+                            table.lock().unwrap().sort_changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::TableRowSelected(table))) => {
+                            table.lock().unwrap().selection_changed = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::CanvasPointer(canvas, pointer))) => {
+                            let mut canvas = canvas.lock().unwrap();
+
+                            let (x, y) = match pointer {
+                                Pointer::Pressed(x, y) => {
+                                    canvas.pointer_down = true;
+                                    canvas.pointer_pressed = true;
+                                    (x, y)
+                                }
+                                Pointer::Released(x, y) => {
+                                    canvas.pointer_down = false;
+                                    canvas.pointer_released = true;
+                                    (x, y)
+                                }
+                                Pointer::Moved(x, y) => {
+                                    canvas.pointer_moved = true;
+                                    (x, y)
+                                }
+                            };
+
+                            canvas.pointer_x = x;
+                            canvas.pointer_y = y;
+                            true
+                        }
+                        Ok(Some(PageEvent::KeyPressed(key))) => {
+                            self.input.key = key;
+                            self.input.key_pressed = true;
+                            self.subscriptions.key_pressed
+                        }
+                        Ok(Some(PageEvent::MouseMoved(x, y))) => {
+                            self.input.mouse_x = x;
+                            self.input.mouse_y = y;
+                            self.input.mouse_moved = true;
+                            self.subscriptions.mouse_moved
+                        }
+                        Ok(Some(PageEvent::MouseClicked(button))) => {
+                            self.input.mouse_button = button;
+                            self.input.mouse_clicked = true;
+                            self.subscriptions.mouse_clicked
+                        }
+                        Ok(Some(PageEvent::WindowResized(width, height))) => {
+                            self.input.window_width = width;
+                            self.input.window_height = height;
+                            self.input.window_resized = true;
+                            self.subscriptions.window_resized
+                        }
+                        Ok(Some(PageEvent::RequestFinished(request))) => {
+                            request.lock().unwrap().finished = true;
+                            true
+                        }
+                        Ok(Some(PageEvent::ImageLoaded(image))) => {
+                            image.lock().unwrap().loaded = true;
+                            self.subscriptions.image_loaded
+                        }
+                        Ok(Some(PageEvent::SocketChanged(_))) => true,
+                        Ok(Some(PageEvent::TimerFired(timer))) => {
+                            timer.lock().unwrap().received();
+                            true
+                        }
+                        Ok(None) | Err(_) => break Ok(false),
+                    };
+
+                    if woken {
+                        break Ok(true);
                     }
-                    Err(_) => Ok(false),
                 }
             }
 
+            NativeCallBoolean::JsonHas(json, key) => {
+                let mut json = self.eval_object(json)?;
+                let key = self.eval_string(key)?;
+
+                let has = json::has(
+                    &json.extract_native_object().extract_json().lock().unwrap(),
+                    &key,
+                );
+
+                Ok(has)
+            }
+
+            NativeCallBoolean::JsonGetBoolean(json, key, token) => {
+                self.json_convert(json, key, "a Boolean", json::as_boolean, token)
+            }
+
+            NativeCallBoolean::ImageLoaded(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut image = object
+                    .extract_native_object()
+                    .extract_image()
+                    .lock()
+                    .unwrap();
+
+                if image.loaded {
+                    image.loaded = false;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+
+            NativeCallBoolean::AsyncRequestFinished(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut request = object
+                    .extract_native_object()
+                    .extract_async_request()
+                    .lock()
+                    .unwrap();
+
+                if request.finished {
+                    request.finished = false;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+
+            NativeCallBoolean::AsyncRequestFailed(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let request = object
+                    .extract_native_object()
+                    .extract_async_request()
+                    .lock()
+                    .unwrap();
+
+                Ok(matches!(request.result, Some(Err(_))))
+            }
+
+            NativeCallBoolean::CheckboxChecked(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let checked = object
+                    .extract_native_object()
+                    .extract_checkbox()
+                    .lock()
+                    .unwrap()
+                    .checked;
+
+                Ok(checked)
+            }
+
+            NativeCallBoolean::CheckboxChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut checkbox = object
+                    .extract_native_object()
+                    .extract_checkbox()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut checkbox.changed))
+            }
+
+            NativeCallBoolean::ToggleOn(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let on = object
+                    .extract_native_object()
+                    .extract_toggle()
+                    .lock()
+                    .unwrap()
+                    .on;
+
+                Ok(on)
+            }
+
+            NativeCallBoolean::ToggleChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut toggle = object
+                    .extract_native_object()
+                    .extract_toggle()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut toggle.changed))
+            }
+
+            NativeCallBoolean::RadioGroupChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut radio_group = object
+                    .extract_native_object()
+                    .extract_radio_group()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut radio_group.changed))
+            }
+
+            NativeCallBoolean::SliderChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut slider = object
+                    .extract_native_object()
+                    .extract_slider()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut slider.changed))
+            }
+
+            NativeCallBoolean::DropdownChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut dropdown = object
+                    .extract_native_object()
+                    .extract_dropdown()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut dropdown.changed))
+            }
+
+            NativeCallBoolean::TextAreaChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut text_area = object
+                    .extract_native_object()
+                    .extract_text_area()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut text_area.changed))
+            }
+
+            NativeCallBoolean::TableSortChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut table = object
+                    .extract_native_object()
+                    .extract_table()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut table.sort_changed))
+            }
+
+            NativeCallBoolean::TableSelectionChanged(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut table = object
+                    .extract_native_object()
+                    .extract_table()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut table.selection_changed))
+            }
+
+            NativeCallBoolean::CanvasPointerDown(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let down = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .pointer_down;
+
+                Ok(down)
+            }
+
+            NativeCallBoolean::CanvasPointerPressed(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut canvas = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut canvas.pointer_pressed))
+            }
+
+            NativeCallBoolean::CanvasPointerReleased(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut canvas = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut canvas.pointer_released))
+            }
+
+            NativeCallBoolean::CanvasPointerMoved(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let mut canvas = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap();
+
+                Ok(std::mem::take(&mut canvas.pointer_moved))
+            }
+
+            NativeCallBoolean::TimerFired(id) => {
+                let id = self.eval_int(id)?;
+
+                // Ids that were never returned by set_timeout or set_interval have not fired
+                match self.timer(id) {
+                    Some(timer) => Ok(std::mem::take(&mut timer.lock().unwrap().fired)),
+                    None => Ok(false),
+                }
+            }
+
+            NativeCallBoolean::KeyPressed => Ok(std::mem::take(&mut self.input.key_pressed)),
+
+            NativeCallBoolean::ShiftHeld => Ok(self.input.key.shift),
+
+            NativeCallBoolean::ControlHeld => Ok(self.input.key.control),
+
+            NativeCallBoolean::AltHeld => Ok(self.input.key.alt),
+
+            NativeCallBoolean::MouseMoved => Ok(std::mem::take(&mut self.input.mouse_moved)),
+
+            NativeCallBoolean::MouseClicked => Ok(std::mem::take(&mut self.input.mouse_clicked)),
+
+            NativeCallBoolean::WindowResized => Ok(std::mem::take(&mut self.input.window_resized)),
+
+            NativeCallBoolean::SocketHasMessage(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let socket = object
+                    .extract_native_object()
+                    .extract_socket()
+                    .lock()
+                    .unwrap();
+
+                Ok(!socket.messages.is_empty())
+            }
+
+            NativeCallBoolean::SocketConnected(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let connected = object
+                    .extract_native_object()
+                    .extract_socket()
+                    .lock()
+                    .unwrap()
+                    .connected;
+
+                Ok(connected)
+            }
+
+            NativeCallBoolean::SocketClosed(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let closed = object
+                    .extract_native_object()
+                    .extract_socket()
+                    .lock()
+                    .unwrap()
+                    .closed;
+
+                Ok(closed)
+            }
+
             NativeCallBoolean::ButtonClicked(object) => {
                 let mut object = self.eval_object(object)?;
 
@@ -1,15 +1,58 @@
 use super::prelude::*;
-use crate::standard_prelude::calls::NativeCallFloat;
+use crate::{json, standard_prelude::calls::NativeCallFloat};
 
 impl<'a> TreeWalker<'a> {
     pub fn native_call_float(&mut self, call: &NativeCallFloat) -> Result<f64, TreeWalkerErr> {
         match call {
+            NativeCallFloat::SliderValue(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let value = object
+                    .extract_native_object()
+                    .extract_slider()
+                    .lock()
+                    .unwrap()
+                    .value;
+
+                Ok(value)
+            }
+            NativeCallFloat::CanvasPointerX(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let x = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .pointer_x;
+
+                Ok(x as f64)
+            }
+            NativeCallFloat::CanvasPointerY(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let y = object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .pointer_y;
+
+                Ok(y as f64)
+            }
+            NativeCallFloat::MouseX => Ok(self.input.mouse_x as f64),
+            NativeCallFloat::MouseY => Ok(self.input.mouse_y as f64),
+            NativeCallFloat::WindowWidth => Ok(self.input.window_width as f64),
+            NativeCallFloat::WindowHeight => Ok(self.input.window_height as f64),
             NativeCallFloat::PowerF(base, exponent) => {
                 let base = self.eval_float(base)?;
                 let exponent = self.eval_float(exponent)?;
 
                 Ok(f64::powf(base, exponent))
             }
+            NativeCallFloat::JsonGetFloat(json, key, token) => {
+                self.json_convert(json, key, "a Float", json::as_float, token)
+            }
             NativeCallFloat::FloatArrayGet(array, index, token) => {
                 let mut array_obj = self.eval_object(&array)?;
                 let index = self.eval_int(index)? as usize;
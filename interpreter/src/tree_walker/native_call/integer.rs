@@ -1,10 +1,91 @@
-use crate::{standard_prelude::calls::NativeCallInteger, tree_walker::object::NativeObject};
+use crate::{
+    event::DialogRequest, json, standard_prelude::calls::NativeCallInteger, timer::Timer,
+    tree_walker::object::NativeObject,
+};
+use std::{sync::mpsc, time::Duration};
 
 use super::prelude::*;
 
 impl<'a> TreeWalker<'a> {
     pub fn native_call_integer(&mut self, call: &NativeCallInteger) -> Result<i64, TreeWalkerErr> {
         match call {
+            NativeCallInteger::StartTimer(delay, repeat) => {
+                // Timers fire after at least a millisecond, so an interval cannot spin
+                let delay = Duration::from_millis(self.eval_int(delay)?.max(1) as u64);
+
+                self.timers
+                    .push(Timer::start(delay, *repeat, self.page_event_sender.clone()));
+
+                Ok(self.timers.len() as i64 - 1)
+            }
+            NativeCallInteger::Dialog(title, message, buttons) => {
+                let title = self.eval_string(title)?;
+                let message = self.eval_string(message)?;
+                let mut buttons_obj = self.eval_object(buttons)?;
+
+                let mut buttons = buttons_obj
+                    .extract_native_object()
+                    .extract_string_array()
+                    .lock()
+                    .unwrap()
+                    .clone();
+
+                // There is always a way to close the dialog
+                if buttons.is_empty() {
+                    buttons.push(String::from("OK"));
+                }
+
+                let (response, chosen) = mpsc::channel();
+
+                self.interpreter_event_sender
+                    .send(InterpreterEvent::ShowDialog(DialogRequest {
+                        title,
+                        message,
+                        buttons,
+                        response,
+                    }))
+                    .ok();
+
+                // The dialog was closed without a choice, such as by the tab being closed
+                Ok(chosen.recv().map_or(-1, |index| index as i64))
+            }
+            NativeCallInteger::RadioGroupSelected(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let selected = object
+                    .extract_native_object()
+                    .extract_radio_group()
+                    .lock()
+                    .unwrap()
+                    .selected;
+
+                Ok(selected.map_or(-1, |index| index as i64))
+            }
+            NativeCallInteger::TableRowCount(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let rows = object
+                    .extract_native_object()
+                    .extract_table()
+                    .lock()
+                    .unwrap()
+                    .rows
+                    .len();
+
+                Ok(rows as i64)
+            }
+            NativeCallInteger::TableSelected(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let selected = object
+                    .extract_native_object()
+                    .extract_table()
+                    .lock()
+                    .unwrap()
+                    .selected;
+
+                Ok(selected.map_or(-1, |index| index as i64))
+            }
             NativeCallInteger::Power(base, exponent) => {
                 let base = self.eval_int(base)?;
                 let exponent = self.eval_int(exponent)?;
@@ -68,6 +149,23 @@ impl<'a> TreeWalker<'a> {
                 } as i64)
             }
 
+            NativeCallInteger::HttpResponseStatus(response) => {
+                let mut response = self.eval_object(response)?;
+
+                let status = response
+                    .extract_native_object()
+                    .extract_http_response()
+                    .lock()
+                    .unwrap()
+                    .status;
+
+                Ok(status as i64)
+            }
+
+            NativeCallInteger::JsonGetInteger(json, key, token) => {
+                self.json_convert(json, key, "an Integer", json::as_integer, token)
+            }
+
             NativeCallInteger::FromString(string, token) => {
                 let string = self.eval_string(string)?;
 
@@ -0,0 +1,75 @@
+use super::prelude::*;
+use crate::{
+    expr::{ObjectExpr, StringExpr},
+    json,
+    token::Token,
+    tree_walker::object::{NativeObject, Object},
+};
+use serde_json::Value;
+
+impl<'a> TreeWalker<'a> {
+    // Converts the value of the key in the Json object, or the Json value itself when there is no
+    // key, which is how the "as" methods are called
+    pub fn json_convert<T>(
+        &mut self,
+        json: &ObjectExpr,
+        key: &Option<Box<StringExpr>>,
+        expected: &str,
+        accessor: fn(&Value) -> Option<T>,
+        token: &Token,
+    ) -> Result<T, TreeWalkerErr> {
+        let mut json = self.eval_object(json)?;
+        let key = match key {
+            Some(key) => Some(self.eval_string(key)?),
+            None => None,
+        };
+
+        let json = json.extract_native_object().extract_json().lock().unwrap();
+
+        let value = match &key {
+            Some(key) => json::get(&json, key),
+            None => Ok(&*json),
+        };
+
+        value
+            .and_then(|value| json::convert(value, key.as_deref(), expected, accessor))
+            .map_err(|e| TreeWalkerErr::JsonAccessFailed(e, token.clone()))
+    }
+
+    pub fn json_from_expr(&mut self, expr: &Expr) -> Result<Value, TreeWalkerErr> {
+        Ok(match expr {
+            Expr::Integer(expr) => Value::from(self.eval_int(expr)?),
+            Expr::Float(expr) => Value::from(self.eval_float(expr)?),
+            Expr::String(expr) => Value::from(self.eval_string(expr)?),
+            Expr::Boolean(expr) => Value::from(self.eval_boolean(expr)?),
+            Expr::Object(_, expr) => {
+                let mut object = self.eval_object(expr)?;
+
+                match object.extract_native_object() {
+                    NativeObject::Json(json) => json.lock().unwrap().clone(),
+                    NativeObject::ObjectArray(array) => Value::Array(
+                        array
+                            .lock()
+                            .unwrap()
+                            .iter_mut()
+                            .map(|element| {
+                                element
+                                    .extract_native_object()
+                                    .extract_json()
+                                    .lock()
+                                    .unwrap()
+                                    .clone()
+                            })
+                            .collect(),
+                    ),
+                    _ => unreachable!("Only Json and [Json] objects can be converted to JSON"),
+                }
+            }
+            _ => unreachable!("This expression cannot be converted to JSON"),
+        })
+    }
+
+    pub fn json_to_object(value: Value) -> Object {
+        Object::Native(NativeObject::Json(Arc::new(Mutex::new(value))))
+    }
+}
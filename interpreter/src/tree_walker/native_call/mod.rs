@@ -1,6 +1,7 @@
 pub mod boolean;
 pub mod float;
 pub mod integer;
+mod json;
 pub mod none;
 pub mod object;
 mod prelude;
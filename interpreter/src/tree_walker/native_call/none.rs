@@ -1,5 +1,8 @@
 use super::prelude::*;
-use crate::{permission::PermissionLevel, standard_prelude::calls::NativeCallNone, Address};
+use crate::{
+    address::has_scheme, cookie_jar, permission::Capability,
+    standard_prelude::calls::NativeCallNone, Address,
+};
 use directories_next::{ProjectDirs, UserDirs};
 use numtoa::NumToA;
 use std::{
@@ -40,6 +43,49 @@ impl<'a> TreeWalker<'a> {
                 _ => panic!("Unprintable type"),
             },
 
+            NativeCallNone::Assert(condition, location) => {
+                if !self.eval_boolean(condition)? {
+                    return Err(TreeWalkerErr::AssertionFailed(
+                        String::from("The asserted condition was false."),
+                        location.clone(),
+                    ));
+                }
+            }
+
+            NativeCallNone::AssertEqual(left, right, location) => {
+                let (equal, left, right) = match (&**left, &**right) {
+                    (Expr::Integer(left), Expr::Integer(right)) => {
+                        let (left, right) = (self.eval_int(left)?, self.eval_int(right)?);
+                        (left == right, left.to_string(), right.to_string())
+                    }
+                    (Expr::Float(left), Expr::Float(right)) => {
+                        let (left, right) = (self.eval_float(left)?, self.eval_float(right)?);
+                        (left == right, left.to_string(), right.to_string())
+                    }
+                    (Expr::String(left), Expr::String(right)) => {
+                        let (left, right) = (self.eval_string(left)?, self.eval_string(right)?);
+                        (left == right, format!("{:?}", left), format!("{:?}", right))
+                    }
+                    (Expr::Boolean(left), Expr::Boolean(right)) => {
+                        let (left, right) = (self.eval_boolean(left)?, self.eval_boolean(right)?);
+                        (left == right, left.to_string(), right.to_string())
+                    }
+                    _ => panic!("Unmatching assertion types"),
+                };
+
+                if !equal {
+                    return Err(TreeWalkerErr::AssertionFailed(
+                        format!("Expected the values to be equal, but the left was {left} and the right was {right}."),
+                        location.clone(),
+                    ));
+                }
+            }
+
+            NativeCallNone::Fail(message, location) => {
+                let message = self.eval_string(message)?;
+                return Err(TreeWalkerErr::AssertionFailed(message, location.clone()));
+            }
+
             NativeCallNone::Sleep(duration) => {
                 let duration = self.eval_int(duration)?;
                 sleep(Duration::from_millis(duration as u64));
@@ -51,6 +97,53 @@ impl<'a> TreeWalker<'a> {
                     .ok();
             }
 
+            NativeCallNone::ClearTimer(id) => {
+                let id = self.eval_int(id)?;
+
+                if let Some(timer) = self.timer(id) {
+                    timer.lock().unwrap().stop();
+                }
+            }
+
+            NativeCallNone::StorageSet(key, value) => {
+                let key = self.eval_string(key)?;
+                let value = self.eval_string(value)?;
+
+                self.storage()?
+                    .set(key, value)
+                    .map_err(TreeWalkerErr::StorageFailed)?;
+            }
+
+            NativeCallNone::StorageRemove(key) => {
+                let key = self.eval_string(key)?;
+
+                self.storage()?
+                    .remove(&key)
+                    .map_err(TreeWalkerErr::StorageFailed)?;
+            }
+
+            NativeCallNone::ClearCookies(location) => {
+                let location = self.eval_string(location)?;
+                let address = self.address.resolve(&location, vec![]);
+
+                self.permissions.check_network(&address)?;
+
+                cookie_jar::shared()
+                    .lock()
+                    .unwrap()
+                    .clear(&address.to_string());
+            }
+
+            NativeCallNone::ClipboardSet(text) => {
+                let text = self.eval_string(text)?;
+
+                self.permissions.check(Capability::Clipboard)?;
+
+                self.interpreter_event_sender
+                    .send(InterpreterEvent::WriteClipboard(text))
+                    .ok();
+            }
+
             NativeCallNone::SetPage(page) => {
                 let mut page = self.eval_object(page)?;
 
@@ -79,16 +172,17 @@ impl<'a> TreeWalker<'a> {
                     .unwrap();
 
                 self.interpreter_event_sender
-                    .send(InterpreterEvent::OpenLink(link, arguments.clone()))
+                    .send(InterpreterEvent::OpenLink(
+                        self.address.resolve(&link, vec![]).to_string(),
+                        arguments.clone(),
+                    ))
                     .ok();
             }
 
             NativeCallNone::RemoveApplication(application_location) => {
                 let application_location = self.eval_string(application_location)?;
 
-                if let PermissionLevel::NetworkOnly = self.permission_level {
-                    return Err(TreeWalkerErr::InsufficientPermissionLevel);
-                }
+                self.permissions.check(Capability::Applications)?;
 
                 let error = |msg: &str| {
                     Err(TreeWalkerErr::SettingsFailed(format!(
@@ -141,9 +235,7 @@ impl<'a> TreeWalker<'a> {
 
                 let shortcut_desired = self.eval_boolean(shortcut_desired)?;
 
-                if let PermissionLevel::NetworkOnly = self.permission_level {
-                    return Err(TreeWalkerErr::InsufficientPermissionLevel);
-                }
+                self.permissions.check(Capability::Applications)?;
 
                 let error = |msg: &str| {
                     Err(TreeWalkerErr::InstallFailed(format!(
@@ -151,12 +243,28 @@ impl<'a> TreeWalker<'a> {
                     )))
                 };
 
+                // The installer is opened as a zonkey asset and can't tell which page opened it,
+                // so the files must be given with their full address
+                let address = |location: &str| match has_scheme(location) {
+                    true => Ok(Address::new(location, vec![])),
+                    false => Err(TreeWalkerErr::InstallFailed(format!(
+                        "Failed to install application: '{location}' must be a full address starting with a scheme, such as https: or zonkey:."
+                    ))),
+                };
+
                 let Some(application_id) = arguments_iter.next() else { return error("Expected application id as argument to install application.") };
 
                 let Some(_windows_shortcut) = arguments_iter.next() else { return error("Expected windows shortcut as argument to install application.") };
 
                 let Some(_linux_shortcut) = arguments_iter.next() else { return error("Expected linux shortcut to install application.") };
 
+                // Every address is checked before anything is installed
+                address(_windows_shortcut)?;
+                address(_linux_shortcut)?;
+                let files = arguments_iter
+                    .map(|file| Ok((file, address(file)?)))
+                    .collect::<Result<Vec<_>, TreeWalkerErr>>()?;
+
                 let Some(proj_dirs) = ProjectDirs::from("rocks.sambowden", "",  "zonkey") else { return error("Couldn't find zonkey project directory.") };
 
                 let data_dir = proj_dirs.data_dir().join(application_id);
@@ -164,9 +272,9 @@ impl<'a> TreeWalker<'a> {
                 println!("Ensuring data directory is created at {:?}", data_dir);
                 let Ok(()) = std::fs::create_dir_all(data_dir.clone()) else { return error("Couldn't create zonkey data directory.") };
 
-                for file in arguments_iter {
+                for (file, file_address) in files {
                     let path = PathBuf::from(file);
-                    let data = match Address::new(file, vec![]).load_bytes() {
+                    let data = match file_address.load_bytes() {
                         Ok(d) => d,
                         Err(e) => return Err(TreeWalkerErr::InstallFailed(e.to_string())),
                     };
@@ -191,7 +299,7 @@ impl<'a> TreeWalker<'a> {
 
                     let path = PathBuf::from(shortcut);
 
-                    let data = match Address::new(&shortcut, vec![]).load_bytes() {
+                    let data = match address(shortcut)?.load_bytes() {
                         Ok(d) => d,
                         Err(e) => return Err(TreeWalkerErr::InstallFailed(e.to_string())),
                     };
@@ -1,14 +1,25 @@
 use super::prelude::*;
-use crate::address::Address;
-use crate::permission::PermissionLevel;
+use crate::address::AddressType;
+use crate::async_request::AsyncRequest;
+use crate::canvas::{self, DrawCommand};
+use crate::cookie_jar;
+use crate::event::PageEvent;
+use crate::http::HttpRequest;
+use crate::json;
+use crate::permission::Capability;
+use crate::socket::Socket;
+use crate::style::Style;
 use crate::{
     element::*,
+    expr::FloatExpr,
     standard_prelude::calls::NativeCallObject,
     tree_walker::object::{NativeObject, Object},
 };
 use colorsys::Rgb;
 use directories_next::ProjectDirs;
-use std::thread;
+use iced::{Alignment, Length};
+use serde_json::{Map, Value};
+use std::{thread, time::Duration};
 
 impl<'a> TreeWalker<'a> {
     pub fn native_call_object(&mut self, call: &NativeCallObject) -> Result<Object, TreeWalkerErr> {
@@ -161,14 +172,11 @@ impl<'a> TreeWalker<'a> {
                     id: self.next_element_id(),
                     title: "Unnamed Application".to_string(),
                     elements: vec![],
-                    bg_red: 255,
-                    bg_green: 255,
-                    bg_blue: 255,
-                    txt_red: 0,
-                    txt_green: 0,
-                    txt_blue: 0,
-                    center: false,
+                    background_colour: None,
+                    text_colour: None,
                     max_width: None,
+                    layout: Layout::default(),
+                    style: None,
                 }));
 
                 Ok(Object::Native(NativeObject::Page(page)))
@@ -351,16 +359,12 @@ impl<'a> TreeWalker<'a> {
                 let button = Arc::new(Mutex::new(Button {
                     id: self.next_element_id(),
                     text,
-                    bg_red: 127,
-                    bg_green: 127,
-                    bg_blue: 127,
-                    txt_red: 255,
-                    txt_green: 255,
-                    txt_blue: 255,
+                    background_colour: None,
+                    text_colour: None,
                     clicked: false,
-                    vertical_padding: 10.,
-                    horizontal_padding: 10.,
+                    padding: None,
                     width_fill: false,
+                    style: None,
                 }));
                 Ok(Object::Native(NativeObject::Button(button)))
             }
@@ -383,9 +387,16 @@ impl<'a> TreeWalker<'a> {
                 let value = self.eval_string(value)?;
                 let text = Arc::new(Mutex::new(Text {
                     id: self.next_element_id(),
-                    size: 20.,
+                    size: None,
                     value,
                     colour: None,
+                    style: None,
+                    bold: false,
+                    italic: false,
+                    font_family: None,
+                    alignment: None,
+                    line_height: None,
+                    wrap: true,
                 }));
                 Ok(Object::Native(NativeObject::Text(text)))
             }
@@ -409,7 +420,7 @@ impl<'a> TreeWalker<'a> {
                 let link = self.eval_string(link)?;
                 let hyperlink = Arc::new(Mutex::new(Hyperlink {
                     id: self.next_element_id(),
-                    link,
+                    link: self.address.resolve(&link, vec![]).to_string(),
                     text,
                     arguments: vec![],
                 }));
@@ -444,243 +455,1397 @@ impl<'a> TreeWalker<'a> {
                 Ok(Object::Native(NativeObject::Input(input)))
             }
 
+            NativeCallObject::CheckboxConstructor(label) => {
+                let label = self.eval_string(label)?;
+                let checkbox = Arc::new(Mutex::new(Checkbox {
+                    id: self.next_element_id(),
+                    label,
+                    checked: false,
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::Checkbox(checkbox)))
+            }
+
+            NativeCallObject::CheckboxSetChecked(checkbox, checked) => {
+                let mut object = self.eval_object(checkbox)?;
+                let checked = self.eval_boolean(checked)?;
+
+                object
+                    .extract_native_object()
+                    .extract_checkbox()
+                    .lock()
+                    .unwrap()
+                    .checked = checked;
+
+                Ok(object)
+            }
+
+            NativeCallObject::ToggleConstructor(label) => {
+                let label = self.eval_string(label)?;
+                let toggle = Arc::new(Mutex::new(Toggle {
+                    id: self.next_element_id(),
+                    label,
+                    on: false,
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::Toggle(toggle)))
+            }
+
+            NativeCallObject::ToggleSetOn(toggle, on) => {
+                let mut object = self.eval_object(toggle)?;
+                let on = self.eval_boolean(on)?;
+
+                object
+                    .extract_native_object()
+                    .extract_toggle()
+                    .lock()
+                    .unwrap()
+                    .on = on;
+
+                Ok(object)
+            }
+
+            NativeCallObject::RadioGroupConstructor => {
+                let radio_group = Arc::new(Mutex::new(RadioGroup {
+                    id: self.next_element_id(),
+                    options: vec![],
+                    selected: None,
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::RadioGroup(radio_group)))
+            }
+
+            NativeCallObject::RadioGroupAddOption(radio_group, option) => {
+                let mut object = self.eval_object(radio_group)?;
+                let option = self.eval_string(option)?;
+
+                object
+                    .extract_native_object()
+                    .extract_radio_group()
+                    .lock()
+                    .unwrap()
+                    .options
+                    .push(option);
+
+                Ok(object)
+            }
+
+            NativeCallObject::RadioGroupSetSelected(radio_group, index, token) => {
+                let mut object = self.eval_object(radio_group)?;
+                let index = self.eval_int(index)?;
+
+                {
+                    let mut radio_group = object
+                        .extract_native_object()
+                        .extract_radio_group()
+                        .lock()
+                        .unwrap();
+
+                    // -1 clears the selection
+                    radio_group.selected = match index {
+                        -1 => None,
+                        index if (0..radio_group.options.len() as i64).contains(&index) => {
+                            Some(index as usize)
+                        }
+                        index => {
+                            return Err(TreeWalkerErr::IndexOutOfRange(
+                                index as usize,
+                                radio_group.options.len(),
+                                token.clone(),
+                            ))
+                        }
+                    };
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::SliderConstructor(min, max) => {
+                let min = Self::slider_value(self.eval_float(min)?)?;
+                let max = Self::slider_value(self.eval_float(max)?)?;
+                let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+                let slider = Arc::new(Mutex::new(Slider {
+                    id: self.next_element_id(),
+                    min,
+                    max,
+                    step: 1.,
+                    value: min,
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::Slider(slider)))
+            }
+
+            NativeCallObject::SliderSetValue(slider, value) => {
+                let mut object = self.eval_object(slider)?;
+                let value = Self::slider_value(self.eval_float(value)?)?;
+
+                {
+                    let mut slider = object
+                        .extract_native_object()
+                        .extract_slider()
+                        .lock()
+                        .unwrap();
+
+                    slider.value = value.clamp(slider.min, slider.max);
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::SliderSetStep(slider, step) => {
+                let mut object = self.eval_object(slider)?;
+                let step = Self::slider_value(self.eval_float(step)?)?;
+
+                // Steps that are not positive are ignored, as the slider could not move
+                if step > 0. {
+                    object
+                        .extract_native_object()
+                        .extract_slider()
+                        .lock()
+                        .unwrap()
+                        .step = step;
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::DropdownConstructor(options) => {
+                let mut options = self.eval_object(options)?;
+
+                let options = options
+                    .extract_native_object()
+                    .extract_string_array()
+                    .lock()
+                    .unwrap()
+                    .clone();
+
+                let dropdown = Arc::new(Mutex::new(Dropdown {
+                    id: self.next_element_id(),
+                    options,
+                    selected: None,
+                    placeholder: String::new(),
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::Dropdown(dropdown)))
+            }
+
+            NativeCallObject::DropdownSetSelected(dropdown, value) => {
+                let mut object = self.eval_object(dropdown)?;
+                let value = self.eval_string(value)?;
+
+                {
+                    let mut dropdown = object
+                        .extract_native_object()
+                        .extract_dropdown()
+                        .lock()
+                        .unwrap();
+
+                    // Values that are not one of the options clear the selection
+                    dropdown.selected = match dropdown.options.contains(&value) {
+                        true => Some(value),
+                        false => None,
+                    };
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::DropdownSetPlaceholder(dropdown, placeholder) => {
+                let mut object = self.eval_object(dropdown)?;
+                let placeholder = self.eval_string(placeholder)?;
+
+                object
+                    .extract_native_object()
+                    .extract_dropdown()
+                    .lock()
+                    .unwrap()
+                    .placeholder = placeholder;
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextAreaConstructor(placeholder) => {
+                let placeholder = self.eval_string(placeholder)?;
+                let text_area = Arc::new(Mutex::new(TextArea {
+                    id: self.next_element_id(),
+                    placeholder,
+                    text: String::new(),
+                    changed: false,
+                }));
+                Ok(Object::Native(NativeObject::TextArea(text_area)))
+            }
+
+            NativeCallObject::TextAreaSetText(text_area, text) => {
+                let mut object = self.eval_object(text_area)?;
+                let text = self.eval_string(text)?;
+
+                object
+                    .extract_native_object()
+                    .extract_text_area()
+                    .lock()
+                    .unwrap()
+                    .text = text;
+
+                Ok(object)
+            }
+
+            NativeCallObject::TableConstructor(headers) => {
+                let mut headers = self.eval_object(headers)?;
+
+                let headers = headers
+                    .extract_native_object()
+                    .extract_string_array()
+                    .lock()
+                    .unwrap()
+                    .clone();
+
+                let table = Arc::new(Mutex::new(Table {
+                    id: self.next_element_id(),
+                    column_widths: vec![None; headers.len()],
+                    headers,
+                    rows: vec![],
+                    sort: None,
+                    selected: None,
+                    sort_changed: false,
+                    selection_changed: false,
+                }));
+                Ok(Object::Native(NativeObject::Table(table)))
+            }
+
+            NativeCallObject::TableAddRow(table, cells) => {
+                let mut object = self.eval_object(table)?;
+                let mut cells = self.eval_object(cells)?;
+
+                let cells = cells
+                    .extract_native_object()
+                    .extract_string_array()
+                    .lock()
+                    .unwrap()
+                    .clone();
+
+                {
+                    let mut table = object
+                        .extract_native_object()
+                        .extract_table()
+                        .lock()
+                        .unwrap();
+
+                    table.rows.push(cells);
+
+                    // Keep the new row in order if the table is sorted
+                    if let Some((column, ascending)) = table.sort {
+                        table.sort(column, ascending);
+                    }
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::TableRemoveRow(table, index, token) => {
+                let mut object = self.eval_object(table)?;
+                let index = self.eval_int(index)? as usize;
+
+                {
+                    let mut table = object
+                        .extract_native_object()
+                        .extract_table()
+                        .lock()
+                        .unwrap();
+
+                    if index >= table.rows.len() {
+                        return Err(TreeWalkerErr::IndexOutOfRange(
+                            index,
+                            table.rows.len(),
+                            token.clone(),
+                        ));
+                    }
+
+                    table.rows.remove(index);
+
+                    table.selected = match table.selected {
+                        Some(selected) if selected == index => None,
+                        Some(selected) if selected > index => Some(selected - 1),
+                        selected => selected,
+                    };
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::TableSetColumnWidth(table, column, width, token) => {
+                let mut object = self.eval_object(table)?;
+                let column = self.eval_int(column)? as usize;
+                let width = self.eval_float(width)?;
+
+                {
+                    let mut table = object
+                        .extract_native_object()
+                        .extract_table()
+                        .lock()
+                        .unwrap();
+
+                    let columns = table.column_widths.len();
+
+                    let Some(column_width) = table.column_widths.get_mut(column) else {
+                        return Err(TreeWalkerErr::IndexOutOfRange(column, columns, token.clone()));
+                    };
+
+                    // Widths that are not positive make the column share the remaining space again
+                    *column_width = match width > 0. {
+                        true => Some(width as f32),
+                        false => None,
+                    };
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::TableSort(table, column, ascending, token) => {
+                let mut object = self.eval_object(table)?;
+                let column = self.eval_int(column)? as usize;
+                let ascending = self.eval_boolean(ascending)?;
+
+                {
+                    let mut table = object
+                        .extract_native_object()
+                        .extract_table()
+                        .lock()
+                        .unwrap();
+
+                    if column >= table.headers.len() {
+                        return Err(TreeWalkerErr::IndexOutOfRange(
+                            column,
+                            table.headers.len(),
+                            token.clone(),
+                        ));
+                    }
+
+                    table.sort(column, ascending);
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::TableSetSelected(table, index, token) => {
+                let mut object = self.eval_object(table)?;
+                let index = self.eval_int(index)?;
+
+                {
+                    let mut table = object
+                        .extract_native_object()
+                        .extract_table()
+                        .lock()
+                        .unwrap();
+
+                    // -1 clears the selection
+                    table.selected = match index {
+                        -1 => None,
+                        index if (0..table.rows.len() as i64).contains(&index) => {
+                            Some(index as usize)
+                        }
+                        index => {
+                            return Err(TreeWalkerErr::IndexOutOfRange(
+                                index as usize,
+                                table.rows.len(),
+                                token.clone(),
+                            ))
+                        }
+                    };
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasConstructor(width, height) => {
+                let width = self.eval_float(width)?;
+                let height = self.eval_float(height)?;
+
+                let canvas = Arc::new(Mutex::new(Canvas {
+                    id: self.next_element_id(),
+                    width: width.max(0.) as f32,
+                    height: height.max(0.) as f32,
+                    commands: vec![],
+                    style: canvas::Style::default(),
+                    font_size: 16.,
+                    pointer_x: 0.,
+                    pointer_y: 0.,
+                    pointer_down: false,
+                    pointer_pressed: false,
+                    pointer_released: false,
+                    pointer_moved: false,
+                }));
+                Ok(Object::Native(NativeObject::Canvas(canvas)))
+            }
+
+            NativeCallObject::CanvasSetFill(canvas, hex) => {
+                let mut object = self.eval_object(canvas)?;
+                let fill = Self::optional_colour(self.eval_string(hex)?)?;
+
+                object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .style
+                    .fill = fill;
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasSetStroke(canvas, hex) => {
+                let mut object = self.eval_object(canvas)?;
+                let stroke = Self::optional_colour(self.eval_string(hex)?)?;
+
+                object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .style
+                    .stroke = stroke;
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasSetStrokeWidth(canvas, width) => {
+                let mut object = self.eval_object(canvas)?;
+                let width = self.eval_float(width)?;
+
+                object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .style
+                    .stroke_width = width.max(0.) as f32;
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasSetFontSize(canvas, size) => {
+                let mut object = self.eval_object(canvas)?;
+                let size = self.eval_float(size)?;
+
+                object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .font_size = size.max(0.) as f32;
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasLine(canvas, x1, y1, x2, y2) => {
+                let mut object = self.eval_object(canvas)?;
+                let from = (self.eval_float(x1)? as f32, self.eval_float(y1)? as f32);
+                let to = (self.eval_float(x2)? as f32, self.eval_float(y2)? as f32);
+
+                Self::draw(&mut object, |style, _| DrawCommand::Line { from, to, style });
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasRect(canvas, x, y, width, height) => {
+                let mut object = self.eval_object(canvas)?;
+                let x = self.eval_float(x)? as f32;
+                let y = self.eval_float(y)? as f32;
+                let width = self.eval_float(width)? as f32;
+                let height = self.eval_float(height)? as f32;
+
+                Self::draw(&mut object, |style, _| DrawCommand::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    style,
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasCircle(canvas, x, y, radius) => {
+                let mut object = self.eval_object(canvas)?;
+                let x = self.eval_float(x)? as f32;
+                let y = self.eval_float(y)? as f32;
+                let radius = self.eval_float(radius)?.max(0.) as f32;
+
+                Self::draw(&mut object, |style, _| DrawCommand::Circle {
+                    x,
+                    y,
+                    radius,
+                    style,
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasPath(canvas, points, closed) => {
+                let mut object = self.eval_object(canvas)?;
+                let mut points = self.eval_object(points)?;
+                let closed = self.eval_boolean(closed)?;
+
+                // The array holds the x and y of each point in turn
+                let points = points
+                    .extract_native_object()
+                    .extract_float_array()
+                    .lock()
+                    .unwrap()
+                    .chunks_exact(2)
+                    .map(|point| (point[0] as f32, point[1] as f32))
+                    .collect();
+
+                Self::draw(&mut object, |style, _| DrawCommand::Path {
+                    points,
+                    closed,
+                    style,
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasText(canvas, x, y, content) => {
+                let mut object = self.eval_object(canvas)?;
+                let x = self.eval_float(x)? as f32;
+                let y = self.eval_float(y)? as f32;
+                let content = self.eval_string(content)?;
+
+                Self::draw(&mut object, |style, size| DrawCommand::Text {
+                    x,
+                    y,
+                    content,
+                    size,
+                    colour: style.fill,
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::CanvasClear(canvas) => {
+                let mut object = self.eval_object(canvas)?;
+
+                object
+                    .extract_native_object()
+                    .extract_canvas()
+                    .lock()
+                    .unwrap()
+                    .commands
+                    .clear();
+
+                Ok(object)
+            }
+
             NativeCallObject::ImageConstructor(link) => {
                 let link = self.eval_string(link)?;
+                let address = self.address.resolve(&link, vec![]);
+
+                self.permissions.check_read(&address)?;
+
                 let image = Arc::new(Mutex::new(Image {
                     data: None,
                     id: self.next_element_id(),
                     max_width: None,
+                    loaded: false,
+                }));
+
+                let image_ref = Arc::clone(&image);
+
+                let sender_clone = self.interpreter_event_sender.clone();
+                let page_event_sender = self.page_event_sender.clone();
+                let permissions = self.permissions.clone();
+
+                thread::spawn(move || {
+                    let data = match address.load_bytes_offline(Some(&permissions)) {
+                        Ok((data, offline)) => {
+                            if offline {
+                                sender_clone.send(InterpreterEvent::Offline).ok();
+                            }
+                            data
+                        }
+                        Err(_) => crate::address::PROJECT_DIR
+                            .get_file("image_load_failed.png")
+                            .unwrap()
+                            .contents()
+                            .to_vec(),
+                    };
+                    image_ref.lock().unwrap().data = Some(data);
+                    sender_clone.send(InterpreterEvent::Update).unwrap();
+                    page_event_sender
+                        .send(Some(PageEvent::ImageLoaded(image_ref)))
+                        .ok();
+                });
+
+                Ok(Object::Native(NativeObject::Image(image)))
+            }
+
+            NativeCallObject::TextSetSize(object, size) => {
+                let mut object = self.eval_object(object)?;
+                let size = self.eval_float(size)?;
+
+                object
+                    .extract_native_object()
+                    .extract_text()
+                    .lock()
+                    .unwrap()
+                    .size = Some(size as f32);
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetBold(object, bold) => {
+                let mut object = self.eval_object(object)?;
+                let bold = self.eval_boolean(bold)?;
+
+                Self::text(&mut object, |text| text.bold = bold);
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetItalic(object, italic) => {
+                let mut object = self.eval_object(object)?;
+                let italic = self.eval_boolean(italic)?;
+
+                Self::text(&mut object, |text| text.italic = italic);
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetFont(object, font_family) => {
+                let mut object = self.eval_object(object)?;
+
+                let font_family = match self.eval_string(font_family)?.as_str() {
+                    "sans" => FontFamily::Sans,
+                    "serif" => FontFamily::Serif,
+                    "monospace" => FontFamily::Monospace,
+                    font_family => {
+                        return Err(TreeWalkerErr::InvalidFontFamily(font_family.to_string()))
+                    }
+                };
+
+                Self::text(&mut object, |text| text.font_family = Some(font_family));
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetAlignment(object, alignment) => {
+                let mut object = self.eval_object(object)?;
+                let alignment = Self::alignment(self.eval_string(alignment)?)?;
+
+                Self::text(&mut object, |text| text.alignment = Some(alignment));
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetLineHeight(object, line_height) => {
+                let mut object = self.eval_object(object)?;
+                let line_height = self.eval_float(line_height)?.max(0.) as f32;
+
+                Self::text(&mut object, |text| text.line_height = Some(line_height));
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetWrap(object, wrap) => {
+                let mut object = self.eval_object(object)?;
+                let wrap = self.eval_boolean(wrap)?;
+
+                Self::text(&mut object, |text| text.wrap = wrap);
+
+                Ok(object)
+            }
+
+            NativeCallObject::TextSetColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let rgb = Self::colour(self.eval_string(hex)?)?;
+
+                {
+                    let mut text = object
+                        .extract_native_object()
+                        .extract_text()
+                        .lock()
+                        .unwrap();
+                    text.colour = Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8))
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::ButtonSetBackgroundColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let rgb = Self::colour(self.eval_string(hex)?)?;
+
+                {
+                    let mut button = object
+                        .extract_native_object()
+                        .extract_button()
+                        .lock()
+                        .unwrap();
+
+                    button.background_colour =
+                        Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8));
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::ButtonSetTextColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let rgb = Self::colour(self.eval_string(hex)?)?;
+
+                {
+                    let mut button = object
+                        .extract_native_object()
+                        .extract_button()
+                        .lock()
+                        .unwrap();
+
+                    button.text_colour =
+                        Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8));
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::ButtonSetPadding(object, vertical, horizontal) => {
+                let mut object = self.eval_object(object)?;
+                let vertical = self.eval_float(vertical)?;
+                let horizontal = self.eval_float(horizontal)?;
+
+                {
+                    let mut button = object
+                        .extract_native_object()
+                        .extract_button()
+                        .lock()
+                        .unwrap();
+
+                    button.padding = Some((vertical as f32, horizontal as f32));
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::PageSetTitle(page, title) => {
+                let mut object = self.eval_object(page)?;
+                let title = self.eval_string(title)?;
+
+                object
+                    .extract_native_object()
+                    .extract_page()
+                    .lock()
+                    .unwrap()
+                    .title = title;
+
+                Ok(object)
+            }
+
+            NativeCallObject::PageSetBackgroundColour(page, hex) => {
+                let mut object = self.eval_object(page)?;
+                let rgb = Self::colour(self.eval_string(hex)?)?;
+
+                {
+                    let mut page = object
+                        .extract_native_object()
+                        .extract_page()
+                        .lock()
+                        .unwrap();
+
+                    page.background_colour =
+                        Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8));
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::PageSetTextColour(page, hex) => {
+                let mut object = self.eval_object(page)?;
+                let rgb = Self::colour(self.eval_string(hex)?)?;
+
+                {
+                    let mut page = object
+                        .extract_native_object()
+                        .extract_page()
+                        .lock()
+                        .unwrap();
+
+                    page.text_colour =
+                        Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8));
+                }
+
+                Ok(object)
+            }
+
+            NativeCallObject::RowConstructor => {
+                let row = Arc::new(Mutex::new(Row {
+                    id: self.next_element_id(),
+                    elements: vec![],
+                    layout: Layout::default(),
+                    style: None,
+                }));
+                Ok(Object::Native(NativeObject::Row(row)))
+            }
+
+            NativeCallObject::ColumnConstructor => {
+                let column = Arc::new(Mutex::new(Column {
+                    id: self.next_element_id(),
+                    elements: vec![],
+                    max_width: None,
+                    layout: Layout::default(),
+                    style: None,
+                }));
+                Ok(Object::Native(NativeObject::Column(column)))
+            }
+
+            NativeCallObject::ButtonSetWidthFill(obj) => {
+                let mut button = self.eval_object(obj)?;
+
+                button
+                    .extract_native_object()
+                    .extract_button()
+                    .lock()
+                    .unwrap()
+                    .width_fill = true;
+
+                Ok(button)
+            }
+
+            NativeCallObject::ColumnSetMaxWidth(obj, width) => {
+                let mut button = self.eval_object(obj)?;
+                let width = self.eval_float(width)?;
+
+                button
+                    .extract_native_object()
+                    .extract_column()
+                    .lock()
+                    .unwrap()
+                    .max_width = Some(width as f32);
+
+                Ok(button)
+            }
+
+            NativeCallObject::LayoutSetPadding(object, vertical, horizontal) => {
+                let mut object = self.eval_object(object)?;
+                let vertical = self.eval_float(vertical)? as f32;
+                let horizontal = self.eval_float(horizontal)? as f32;
+
+                Self::layout(&mut object, |layout| {
+                    layout.padding = Some((vertical, horizontal))
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetSpacing(object, spacing) => {
+                let mut object = self.eval_object(object)?;
+                let spacing = self.eval_float(spacing)? as f32;
+
+                Self::layout(&mut object, |layout| layout.spacing = Some(spacing));
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetAlignment(object, alignment, horizontal) => {
+                let mut object = self.eval_object(object)?;
+
+                let alignment = Self::alignment(self.eval_string(alignment)?)?;
+
+                Self::layout(&mut object, |layout| match horizontal {
+                    true => layout.horizontal_alignment = Some(alignment),
+                    false => layout.vertical_alignment = Some(alignment),
+                });
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetWidth(object, width) => {
+                let mut object = self.eval_object(object)?;
+                let width = self.length(width)?;
+
+                Self::layout(&mut object, |layout| layout.width = Some(width));
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetHeight(object, height) => {
+                let mut object = self.eval_object(object)?;
+                let height = self.length(height)?;
+
+                Self::layout(&mut object, |layout| layout.height = Some(height));
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetBackgroundColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
+
+                Self::layout(&mut object, |layout| layout.background_colour = colour);
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetBorderColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
+
+                Self::layout(&mut object, |layout| layout.border_colour = colour);
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetBorderWidth(object, width) => {
+                let mut object = self.eval_object(object)?;
+                let width = self.eval_float(width)? as f32;
+
+                Self::layout(&mut object, |layout| layout.border_width = width.max(0.));
+
+                Ok(object)
+            }
+
+            NativeCallObject::LayoutSetBorderRadius(object, radius) => {
+                let mut object = self.eval_object(object)?;
+                let radius = self.eval_float(radius)? as f32;
+
+                Self::layout(&mut object, |layout| layout.border_radius = radius.max(0.));
+
+                Ok(object)
+            }
+
+            NativeCallObject::ScrollConstructor(height) => {
+                let height = self.eval_float(height)?;
+
+                let scroll = Arc::new(Mutex::new(Scroll {
+                    id: self.next_element_id(),
+                    elements: vec![],
+                    height: height.max(0.) as f32,
+                }));
+                Ok(Object::Native(NativeObject::Scroll(scroll)))
+            }
+
+            NativeCallObject::ScrollSetHeight(object, height) => {
+                let mut object = self.eval_object(object)?;
+                let height = self.eval_float(height)?;
+
+                object
+                    .extract_native_object()
+                    .extract_scroll()
+                    .lock()
+                    .unwrap()
+                    .height = height.max(0.) as f32;
+
+                Ok(object)
+            }
+
+            NativeCallObject::StackConstructor => {
+                let stack = Arc::new(Mutex::new(Stack {
+                    id: self.next_element_id(),
+                    elements: vec![],
                 }));
+                Ok(Object::Native(NativeObject::Stack(stack)))
+            }
+
+            NativeCallObject::ScrollAddElement(object, element)
+            | NativeCallObject::StackAddElement(object, element) => {
+                let mut element_obj = self.eval_object(element)?;
+                let mut object = self.eval_object(object)?;
+
+                let element = element_obj.extract_native_object();
+                let added = (element.get_id(), Self::native_obj_to_element(element));
+
+                Self::elements(&mut object, |elements| elements.push(added));
+
+                Ok(object)
+            }
 
-                let image_ref = Arc::clone(&image);
+            NativeCallObject::ScrollRemoveElement(object, element)
+            | NativeCallObject::StackRemoveElement(object, element) => {
+                let mut element_obj = self.eval_object(element)?;
+                let mut object = self.eval_object(object)?;
 
-                let sender_clone = self.interpreter_event_sender.clone();
+                let id = element_obj.extract_native_object().get_id();
 
-                thread::spawn(move || {
-                    let data = Address::new(&link, vec![])
-                        .load_bytes()
-                        .unwrap_or_else(|_| {
-                            crate::address::PROJECT_DIR
-                                .get_file("image_load_failed.png")
-                                .unwrap()
-                                .contents()
-                                .to_vec()
-                        });
-                    image_ref.lock().unwrap().data = Some(data);
-                    sender_clone.send(InterpreterEvent::Update).unwrap();
+                Self::elements(&mut object, |elements| {
+                    if let Some(pos) = elements.iter().position(|&(element, _)| element == id) {
+                        elements.remove(pos);
+                    }
                 });
 
-                Ok(Object::Native(NativeObject::Image(image)))
+                Ok(object)
             }
 
-            NativeCallObject::TextSetSize(object, size) => {
+            NativeCallObject::StyleConstructor => Ok(Object::Native(NativeObject::Style(Arc::new(
+                Mutex::new(Style::default()),
+            )))),
+
+            NativeCallObject::StyleSetTextSize(object, size) => {
                 let mut object = self.eval_object(object)?;
-                let size = self.eval_float(size)?;
+                let size = self.eval_float(size)? as f32;
 
-                object
-                    .extract_native_object()
-                    .extract_text()
-                    .lock()
-                    .unwrap()
-                    .size = size as f32;
+                Self::style(&mut object, |style| style.text_size = Some(size.max(0.)));
 
                 Ok(object)
             }
 
-            NativeCallObject::TextSetColour(object, hex) => {
+            NativeCallObject::StyleSetTextColour(object, hex) => {
                 let mut object = self.eval_object(object)?;
-                let rgb = Self::colour(self.eval_string(hex)?)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
 
-                {
-                    let mut text = object
-                        .extract_native_object()
-                        .extract_text()
-                        .lock()
-                        .unwrap();
-                    text.colour = Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8))
-                }
+                Self::style(&mut object, |style| style.text_colour = colour);
 
                 Ok(object)
             }
 
-            NativeCallObject::ButtonSetBackgroundColour(object, hex) => {
+            NativeCallObject::StyleSetButtonColour(object, hex) => {
                 let mut object = self.eval_object(object)?;
-                let rgb = Self::colour(self.eval_string(hex)?)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
 
-                {
-                    let mut button = object
-                        .extract_native_object()
-                        .extract_button()
-                        .lock()
-                        .unwrap();
+                Self::style(&mut object, |style| style.button_colour = colour);
 
-                    button.bg_red = rgb.red() as u8;
-                    button.bg_green = rgb.green() as u8;
-                    button.bg_blue = rgb.blue() as u8;
-                }
+                Ok(object)
+            }
+
+            NativeCallObject::StyleSetButtonTextColour(object, hex) => {
+                let mut object = self.eval_object(object)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
+
+                Self::style(&mut object, |style| style.button_text_colour = colour);
 
                 Ok(object)
             }
 
-            NativeCallObject::ButtonSetTextColour(object, hex) => {
+            NativeCallObject::StyleSetBackgroundColour(object, hex) => {
                 let mut object = self.eval_object(object)?;
-                let rgb = Self::colour(self.eval_string(hex)?)?;
+                let colour = Self::optional_colour(self.eval_string(hex)?)?;
 
-                {
-                    let mut button = object
-                        .extract_native_object()
-                        .extract_button()
-                        .lock()
-                        .unwrap();
+                Self::style(&mut object, |style| style.background_colour = colour);
 
-                    button.txt_red = rgb.red() as u8;
-                    button.txt_green = rgb.green() as u8;
-                    button.txt_blue = rgb.blue() as u8;
-                }
+                Ok(object)
+            }
+
+            NativeCallObject::StyleSetButtonPadding(object, vertical, horizontal) => {
+                let mut object = self.eval_object(object)?;
+                let vertical = self.eval_float(vertical)?.max(0.) as f32;
+                let horizontal = self.eval_float(horizontal)?.max(0.) as f32;
+
+                Self::style(&mut object, |style| style.button_padding = Some((vertical, horizontal)));
 
                 Ok(object)
             }
 
-            NativeCallObject::ButtonSetPadding(object, vertical, horizontal) => {
+            NativeCallObject::StyleSetPadding(object, vertical, horizontal) => {
                 let mut object = self.eval_object(object)?;
-                let vertical = self.eval_float(vertical)?;
-                let horizontal = self.eval_float(horizontal)?;
+                let vertical = self.eval_float(vertical)?.max(0.) as f32;
+                let horizontal = self.eval_float(horizontal)?.max(0.) as f32;
 
-                {
-                    let mut button = object
-                        .extract_native_object()
-                        .extract_button()
-                        .lock()
-                        .unwrap();
+                Self::style(&mut object, |style| style.padding = Some((vertical, horizontal)));
+
+                Ok(object)
+            }
 
-                    button.vertical_padding = vertical as f32;
-                    button.horizontal_padding = horizontal as f32;
+            NativeCallObject::SetStyle(object, style) => {
+                let mut object = self.eval_object(object)?;
+                let style = Some(Arc::clone(
+                    self.eval_object(style)?.extract_native_object().extract_style(),
+                ));
+
+                match object.extract_native_object() {
+                    NativeObject::Text(text) => text.lock().unwrap().style = style,
+                    NativeObject::Button(button) => button.lock().unwrap().style = style,
+                    NativeObject::Row(row) => row.lock().unwrap().style = style,
+                    NativeObject::Column(column) => column.lock().unwrap().style = style,
+                    NativeObject::Page(page) => page.lock().unwrap().style = style,
+                    _ => unreachable!("Only elements which are styled have a set_style method"),
                 }
 
                 Ok(object)
             }
 
-            NativeCallObject::PageSetTitle(page, title) => {
-                let mut object = self.eval_object(page)?;
-                let title = self.eval_string(title)?;
+            NativeCallObject::RowCenter(obj) => {
+                let mut row = self.eval_object(obj)?;
 
-                object
+                row.extract_native_object()
+                    .extract_row()
+                    .lock()
+                    .unwrap()
+                    .layout
+                    .horizontal_alignment = Some(Alignment::Center);
+
+                Ok(row)
+            }
+
+            NativeCallObject::ImageSetMaxWidth(obj, width) => {
+                let mut image = self.eval_object(obj)?;
+                let width = self.eval_float(width)?;
+
+                image
                     .extract_native_object()
-                    .extract_page()
+                    .extract_image()
                     .lock()
                     .unwrap()
-                    .title = title;
+                    .max_width = Some(width as f32);
 
-                Ok(object)
+                Ok(image)
             }
 
-            NativeCallObject::PageSetBackgroundColour(page, hex) => {
-                let mut object = self.eval_object(page)?;
-                let rgb = Self::colour(self.eval_string(hex)?)?;
+            NativeCallObject::HttpRequestConstructor(method, location) => {
+                let method = self.eval_string(method)?;
+                let location = self.eval_string(location)?;
+                let request = Arc::new(Mutex::new(HttpRequest::new(&method, location)));
+                Ok(Object::Native(NativeObject::HttpRequest(request)))
+            }
+
+            NativeCallObject::HttpRequestSetHeader(obj, name, value) => {
+                let mut request = self.eval_object(obj)?;
+                let name = self.eval_string(name)?;
+                let value = self.eval_string(value)?;
 
                 {
-                    let mut page = object
+                    let mut request = request
                         .extract_native_object()
-                        .extract_page()
+                        .extract_http_request()
                         .lock()
                         .unwrap();
 
-                    page.bg_red = rgb.red() as u8;
-                    page.bg_green = rgb.green() as u8;
-                    page.bg_blue = rgb.blue() as u8;
+                    // Setting a header again replaces its value
+                    request
+                        .headers
+                        .retain(|(header, _)| !header.eq_ignore_ascii_case(&name));
+                    request.headers.push((name, value));
                 }
 
-                Ok(object)
+                Ok(request)
             }
 
-            NativeCallObject::PageSetTextColour(page, hex) => {
-                let mut object = self.eval_object(page)?;
-                let rgb = Self::colour(self.eval_string(hex)?)?;
+            NativeCallObject::HttpRequestSetBody(obj, body) => {
+                let mut request = self.eval_object(obj)?;
+                let body = self.eval_string(body)?;
 
-                {
-                    let mut page = object
-                        .extract_native_object()
-                        .extract_page()
-                        .lock()
-                        .unwrap();
+                request
+                    .extract_native_object()
+                    .extract_http_request()
+                    .lock()
+                    .unwrap()
+                    .body = body;
+
+                Ok(request)
+            }
+
+            NativeCallObject::HttpRequestSetTimeout(obj, milliseconds) => {
+                let mut request = self.eval_object(obj)?;
+                let milliseconds = self.eval_int(milliseconds)?;
+
+                request
+                    .extract_native_object()
+                    .extract_http_request()
+                    .lock()
+                    .unwrap()
+                    .timeout = Some(Duration::from_millis(milliseconds.max(0) as u64));
+
+                Ok(request)
+            }
+
+            NativeCallObject::HttpRequestSend(obj) => {
+                let mut request = self.eval_object(obj)?;
+                let mut request = request
+                    .extract_native_object()
+                    .extract_http_request()
+                    .lock()
+                    .unwrap()
+                    .clone();
 
-                    page.txt_red = rgb.red() as u8;
-                    page.txt_green = rgb.green() as u8;
-                    page.txt_blue = rgb.blue() as u8;
+                let address = self.address.resolve(&request.url, vec![]);
+
+                if !matches!(address.address_type, AddressType::HTTP { .. }) {
+                    return Err(TreeWalkerErr::HttpRequestFailed(format!(
+                        "'{}' is not a http or https address",
+                        request.url
+                    )));
                 }
 
-                Ok(object)
+                self.permissions.check_network(&address)?;
+                request.url = address.to_string();
+                request.permissions = Some(self.permissions.clone());
+
+                match request.send() {
+                    Ok(response) => Ok(Object::Native(NativeObject::HttpResponse(Arc::new(
+                        Mutex::new(response),
+                    )))),
+                    Err(e) => Err(TreeWalkerErr::HttpRequestFailed(e)),
+                }
             }
 
-            NativeCallObject::RowConstructor => {
-                let row = Arc::new(Mutex::new(Row {
-                    id: self.next_element_id(),
-                    elements: vec![],
-                    center: false,
-                }));
-                Ok(Object::Native(NativeObject::Row(row)))
+            NativeCallObject::ReadStringAsync(location) => {
+                let location = self.eval_string(location)?;
+                let address = self.address.resolve(&location, vec![]);
+
+                self.permissions.check_read(&address)?;
+
+                let request = Arc::new(Mutex::new(AsyncRequest::new(false)));
+                let permissions = self.permissions.clone();
+
+                self.spawn_request(Arc::clone(&request), move || {
+                    address
+                        .read_string_offline(Some(&permissions))
+                        .map_err(|e| e.to_string())
+                });
+
+                Ok(Object::Native(NativeObject::AsyncRequest(request)))
             }
 
-            NativeCallObject::ColumnConstructor => {
-                let column = Arc::new(Mutex::new(Column {
-                    id: self.next_element_id(),
-                    elements: vec![],
-                    max_width: None,
-                }));
-                Ok(Object::Native(NativeObject::Column(column)))
+            NativeCallObject::WriteStringAsync(location, string) => {
+                let location = self.eval_string(location)?;
+                let string = self.eval_string(string)?;
+                let address = self.address.resolve(&location, vec![]);
+
+                self.permissions.check_write(&address)?;
+
+                let request = Arc::new(Mutex::new(AsyncRequest::new(true)));
+                let permissions = self.permissions.clone();
+
+                self.spawn_request(Arc::clone(&request), move || {
+                    match address.write_string(string, Some(&permissions)) {
+                        Ok(response) => Ok((response, false)),
+                        Err(e) => Err(e.to_string()),
+                    }
+                });
+
+                Ok(Object::Native(NativeObject::AsyncRequest(request)))
             }
 
-            NativeCallObject::ButtonSetWidthFill(obj) => {
-                let mut button = self.eval_object(obj)?;
+            NativeCallObject::SocketConstructor(address) => {
+                let address = self.eval_string(address)?;
 
-                button
+                self.permissions.check_socket(&address)?;
+
+                let socket = Socket::connect(address, self.page_event_sender.clone());
+                self.sockets.push(Arc::clone(&socket));
+
+                Ok(Object::Native(NativeObject::Socket(socket)))
+            }
+
+            NativeCallObject::SocketSend(obj, message) => {
+                let mut object = self.eval_object(obj)?;
+                let message = self.eval_string(message)?;
+
+                object
                     .extract_native_object()
-                    .extract_button()
+                    .extract_socket()
                     .lock()
                     .unwrap()
-                    .width_fill = true;
+                    .send(&message)
+                    .map_err(TreeWalkerErr::SocketFailed)?;
 
-                Ok(button)
+                Ok(object)
             }
 
-            NativeCallObject::ColumnSetMaxWidth(obj, width) => {
-                let mut button = self.eval_object(obj)?;
-                let width = self.eval_float(width)?;
+            NativeCallObject::SocketClose(obj) => {
+                let mut object = self.eval_object(obj)?;
 
-                button
+                object
                     .extract_native_object()
-                    .extract_column()
+                    .extract_socket()
                     .lock()
                     .unwrap()
-                    .max_width = Some(width as f32);
+                    .close();
 
-                Ok(button)
+                Ok(object)
             }
 
-            NativeCallObject::RowCenter(obj) => {
-                let mut row = self.eval_object(obj)?;
+            NativeCallObject::StorageKeys => {
+                let keys = self.storage()?.keys();
 
-                row.extract_native_object()
-                    .extract_row()
+                Ok(Object::Native(NativeObject::StringArray(Arc::new(
+                    Mutex::new(keys),
+                ))))
+            }
+
+            NativeCallObject::CookieNames(location) => {
+                let location = self.eval_string(location)?;
+                let address = self.address.resolve(&location, vec![]);
+
+                self.permissions.check_network(&address)?;
+
+                let names = cookie_jar::shared()
                     .lock()
                     .unwrap()
-                    .center = true;
+                    .visible(&address.to_string())
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect();
 
-                Ok(row)
+                Ok(Object::Native(NativeObject::StringArray(Arc::new(
+                    Mutex::new(names),
+                ))))
             }
 
-            NativeCallObject::ImageSetMaxWidth(obj, width) => {
-                let mut image = self.eval_object(obj)?;
-                let width = self.eval_float(width)?;
+            NativeCallObject::HttpResponseHeaderNames(obj) => {
+                let mut response = self.eval_object(obj)?;
 
-                image
+                let names = response
                     .extract_native_object()
-                    .extract_image()
+                    .extract_http_response()
                     .lock()
                     .unwrap()
-                    .max_width = Some(width as f32);
+                    .headers
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect();
 
-                Ok(image)
+                Ok(Object::Native(NativeObject::StringArray(Arc::new(
+                    Mutex::new(names),
+                ))))
+            }
+
+            NativeCallObject::JsonConstructor => {
+                Ok(Self::json_to_object(Value::Object(Map::new())))
+            }
+
+            NativeCallObject::JsonParse(string, token) => {
+                let string = self.eval_string(string)?;
+
+                match json::parse(&string) {
+                    Ok(value) => Ok(Self::json_to_object(value)),
+                    Err(e) => Err(TreeWalkerErr::JsonParseFailed(e, token.clone())),
+                }
+            }
+
+            NativeCallObject::JsonGetObject(json, key, token) => {
+                let key = Some(key.clone());
+                let value = self.json_convert(json, &key, "an object", json::as_object, token)?;
+                Ok(Self::json_to_object(value))
+            }
+
+            NativeCallObject::JsonGetArray(json, key, token) => {
+                let values = self.json_convert(json, key, "an array", json::as_array, token)?;
+
+                let array = values.into_iter().map(Self::json_to_object).collect();
+                // Object arrays never leave the interpreter thread, the same as those made by the
+                // object array constructor
+                #[allow(clippy::arc_with_non_send_sync)]
+                let array_obj = Arc::new(Mutex::new(array));
+
+                Ok(Object::Native(NativeObject::ObjectArray(array_obj)))
+            }
+
+            NativeCallObject::JsonSet(json, key, value, token) => {
+                let mut json_obj = self.eval_object(json)?;
+                let key = self.eval_string(key)?;
+                let value = self.json_from_expr(value)?;
+
+                {
+                    let mut json = json_obj
+                        .extract_native_object()
+                        .extract_json()
+                        .lock()
+                        .unwrap();
+
+                    match json::object_mut(&mut json, &key) {
+                        Ok(object) => object.insert(key, value),
+                        Err(e) => return Err(TreeWalkerErr::JsonAccessFailed(e, token.clone())),
+                    };
+                }
+
+                Ok(json_obj)
             }
 
             NativeCallObject::PageCenter(page) => {
@@ -690,7 +1855,8 @@ impl<'a> TreeWalker<'a> {
                     .extract_page()
                     .lock()
                     .unwrap()
-                    .center = true;
+                    .layout
+                    .horizontal_alignment = Some(Alignment::Center);
 
                 Ok(page)
             }
@@ -723,9 +1889,7 @@ impl<'a> TreeWalker<'a> {
             }
 
             NativeCallObject::InstalledApplications => {
-                if let PermissionLevel::NetworkOnly = self.permission_level {
-                    return Err(TreeWalkerErr::InsufficientPermissionLevel);
-                }
+                self.permissions.check(Capability::Settings)?;
 
                 let mut installed_applications = vec![];
 
@@ -743,6 +1907,11 @@ impl<'a> TreeWalker<'a> {
                 for application in data_dir_apps {
                     let Ok(application_name) = application else { return error("Failed to extract application folder.") };
                     let application_name = application_name.path();
+                    // Browser data such as caches and remembered permissions are not applications
+                    let hidden = application_name.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+                    if hidden || !application_name.is_dir() {
+                        continue;
+                    }
                     let Some(application_name) = application_name.to_str() else { return error("Failed to extract name from application folder.") };
                     installed_applications.push(application_name.to_string());
                 }
@@ -754,10 +1923,111 @@ impl<'a> TreeWalker<'a> {
         }
     }
 
+    // Runs the work on another thread, where the result is stored in the request before the
+    // event that finishes it is sent. The work also returns whether a stored copy was used.
+    fn spawn_request(
+        &self,
+        request: Arc<Mutex<AsyncRequest>>,
+        work: impl FnOnce() -> Result<(String, bool), String> + Send + 'static,
+    ) {
+        let interpreter_event_sender = self.interpreter_event_sender.clone();
+        let page_event_sender = self.page_event_sender.clone();
+
+        thread::spawn(move || {
+            let result = work();
+
+            if let Ok((_, true)) = result {
+                interpreter_event_sender
+                    .send(InterpreterEvent::Offline)
+                    .ok();
+            }
+
+            request.lock().unwrap().result = Some(result.map(|(string, _)| string));
+            interpreter_event_sender.send(InterpreterEvent::Update).ok();
+            page_event_sender
+                .send(Some(PageEvent::RequestFinished(request)))
+                .ok();
+        });
+    }
+
     fn colour(hex: String) -> Result<Rgb, TreeWalkerErr> {
         match Rgb::from_hex_str(hex.as_str()) {
             Ok(rgb) => Ok(rgb),
             Err(_) => Err(TreeWalkerErr::InvalidHexColour(hex)),
         }
     }
+
+    // An empty string is used for no colour
+    fn optional_colour(hex: String) -> Result<Option<(u8, u8, u8)>, TreeWalkerErr> {
+        if hex.is_empty() {
+            return Ok(None);
+        }
+
+        let rgb = Self::colour(hex)?;
+        Ok(Some((rgb.red() as u8, rgb.green() as u8, rgb.blue() as u8)))
+    }
+
+    // A slider cannot be drawn or moved with values that are not finite
+    fn slider_value(value: f64) -> Result<f64, TreeWalkerErr> {
+        match value.is_finite() {
+            true => Ok(value),
+            false => Err(TreeWalkerErr::InvalidSliderValue(value)),
+        }
+    }
+
+    fn alignment(alignment: String) -> Result<Alignment, TreeWalkerErr> {
+        match alignment.as_str() {
+            "start" => Ok(Alignment::Start),
+            "center" => Ok(Alignment::Center),
+            "end" => Ok(Alignment::End),
+            _ => Err(TreeWalkerErr::InvalidAlignment(alignment)),
+        }
+    }
+
+    // Row, Column and Page share their styling
+    fn layout(object: &mut Object, style: impl FnOnce(&mut Layout)) {
+        match object.extract_native_object() {
+            NativeObject::Row(row) => style(&mut row.lock().unwrap().layout),
+            NativeObject::Column(column) => style(&mut column.lock().unwrap().layout),
+            NativeObject::Page(page) => style(&mut page.lock().unwrap().layout),
+            _ => unreachable!("Only layout elements have styling methods"),
+        }
+    }
+
+    fn text(object: &mut Object, set: impl FnOnce(&mut Text)) {
+        set(&mut object.extract_native_object().extract_text().lock().unwrap())
+    }
+
+    // Scroll and Stack hold their elements in the same way
+    fn elements(object: &mut Object, change: impl FnOnce(&mut Vec<(u64, ElementType)>)) {
+        match object.extract_native_object() {
+            NativeObject::Scroll(scroll) => change(&mut scroll.lock().unwrap().elements),
+            NativeObject::Stack(stack) => change(&mut stack.lock().unwrap().elements),
+            _ => unreachable!("Only Scroll and Stack share their methods to add elements"),
+        }
+    }
+
+    fn style(object: &mut Object, set: impl FnOnce(&mut Style)) {
+        set(&mut object.extract_native_object().extract_style().lock().unwrap())
+    }
+
+    // A fixed size, or None to fill the available space
+    fn length(&mut self, size: &Option<Box<FloatExpr>>) -> Result<Length, TreeWalkerErr> {
+        match size {
+            Some(size) => Ok(Length::Fixed(self.eval_float(size)?.max(0.) as f32)),
+            None => Ok(Length::Fill),
+        }
+    }
+
+    // Records a command using the current style and font size of the canvas
+    fn draw(object: &mut Object, command: impl FnOnce(canvas::Style, f32) -> DrawCommand) {
+        let mut canvas = object
+            .extract_native_object()
+            .extract_canvas()
+            .lock()
+            .unwrap();
+
+        let command = command(canvas.style, canvas.font_size);
+        canvas.commands.push(command);
+    }
 }
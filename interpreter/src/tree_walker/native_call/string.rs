@@ -1,8 +1,12 @@
-use crate::address::{Address, AddressType};
-use std::io::{stdout, Write};
+use std::{
+    io::{stdout, Write},
+    sync::mpsc,
+};
 
 use super::prelude::*;
-use crate::{standard_prelude::calls::NativeCallString, PermissionLevel};
+use crate::{
+    cookie_jar, json, permission::Capability, standard_prelude::calls::NativeCallString, style,
+};
 
 impl<'a> TreeWalker<'a> {
     pub fn native_call_string(&mut self, call: &NativeCallString) -> Result<String, TreeWalkerErr> {
@@ -23,6 +27,28 @@ impl<'a> TreeWalker<'a> {
                 Ok(input.trim().to_string())
             }
 
+            NativeCallString::PaletteColour(name) => {
+                let name = self.eval_string(name)?;
+
+                match style::palette_colour(&name) {
+                    Some((red, green, blue)) => Ok(format!("#{red:02x}{green:02x}{blue:02x}")),
+                    None => Err(TreeWalkerErr::InvalidPaletteColour(name)),
+                }
+            }
+
+            NativeCallString::ClipboardGet => {
+                self.permissions.check(Capability::Clipboard)?;
+
+                let (response, text) = mpsc::channel();
+
+                self.interpreter_event_sender
+                    .send(InterpreterEvent::ReadClipboard(response))
+                    .ok();
+
+                // Nothing is returned when the clipboard is empty or can't be read
+                Ok(text.recv().unwrap_or_default())
+            }
+
             NativeCallString::GetInputText(input) => {
                 let mut input = self.eval_object(input)?;
 
@@ -53,16 +79,19 @@ impl<'a> TreeWalker<'a> {
 
             NativeCallString::ReadString(location) => {
                 let location = self.eval_string(location)?;
-                let address = Address::new(&location, vec![]);
+                let address = self.address.resolve(&location, vec![]);
 
-                if let (PermissionLevel::NetworkOnly, AddressType::Zonkey | AddressType::File) =
-                    (&self.permission_level, &address.address_type)
-                {
-                    return Err(TreeWalkerErr::InsufficientPermissionLevel);
-                }
+                self.permissions.check_read(&address)?;
 
-                let string = match address.read_string() {
-                    Ok(string) => string,
+                let string = match address.read_string_offline(Some(&self.permissions)) {
+                    Ok((string, offline)) => {
+                        if offline {
+                            self.interpreter_event_sender
+                                .send(InterpreterEvent::Offline)
+                                .ok();
+                        }
+                        string
+                    }
                     Err(e) => return Err(TreeWalkerErr::ReadAddressFailed(e.to_string())),
                 };
 
@@ -72,20 +101,198 @@ impl<'a> TreeWalker<'a> {
             NativeCallString::WriteString(location, string) => {
                 let location = self.eval_string(location)?;
                 let string = self.eval_string(string)?;
-                let address = Address::new(&location, vec![]);
+                let address = self.address.resolve(&location, vec![]);
 
-                if let (PermissionLevel::NetworkOnly, AddressType::Zonkey | AddressType::File) =
-                    (&self.permission_level, &address.address_type)
-                {
-                    return Err(TreeWalkerErr::InsufficientPermissionLevel);
-                }
+                self.permissions.check_write(&address)?;
 
-                match address.write_string(string) {
+                match address.write_string(string, Some(&self.permissions)) {
                     Ok(string) => Ok(string),
                     Err(e) => return Err(TreeWalkerErr::WriteAddressFailed(e.to_string())),
                 }
             }
 
+            NativeCallString::HttpResponseHeader(response, name) => {
+                let mut response = self.eval_object(response)?;
+                let name = self.eval_string(name)?;
+
+                // Headers that were not sent are read as an empty string
+                let value = response
+                    .extract_native_object()
+                    .extract_http_response()
+                    .lock()
+                    .unwrap()
+                    .header(&name)
+                    .unwrap_or("")
+                    .to_string();
+
+                Ok(value)
+            }
+
+            NativeCallString::AsyncRequestResult(request) => {
+                let mut request = self.eval_object(request)?;
+
+                let request = request
+                    .extract_native_object()
+                    .extract_async_request()
+                    .lock()
+                    .unwrap();
+
+                match &request.result {
+                    Some(Ok(string)) => Ok(string.clone()),
+                    Some(Err(e)) if request.write => {
+                        Err(TreeWalkerErr::WriteAddressFailed(e.clone()))
+                    }
+                    Some(Err(e)) => Err(TreeWalkerErr::ReadAddressFailed(e.clone())),
+                    None => Ok(String::new()),
+                }
+            }
+
+            NativeCallString::RadioGroupSelectedOption(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let radio_group = object
+                    .extract_native_object()
+                    .extract_radio_group()
+                    .lock()
+                    .unwrap();
+
+                Ok(radio_group
+                    .selected
+                    .and_then(|index| radio_group.options.get(index))
+                    .cloned()
+                    .unwrap_or_default())
+            }
+
+            NativeCallString::DropdownSelected(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let selected = object
+                    .extract_native_object()
+                    .extract_dropdown()
+                    .lock()
+                    .unwrap()
+                    .selected
+                    .clone();
+
+                Ok(selected.unwrap_or_default())
+            }
+
+            NativeCallString::TextAreaGetText(object) => {
+                let mut object = self.eval_object(object)?;
+
+                let text = object
+                    .extract_native_object()
+                    .extract_text_area()
+                    .lock()
+                    .unwrap()
+                    .text
+                    .clone();
+
+                Ok(text)
+            }
+
+            NativeCallString::TableGetCell(object, row, column, token) => {
+                let mut object = self.eval_object(object)?;
+                let row = self.eval_int(row)? as usize;
+                let column = self.eval_int(column)? as usize;
+
+                let table = object
+                    .extract_native_object()
+                    .extract_table()
+                    .lock()
+                    .unwrap();
+
+                let Some(cells) = table.rows.get(row) else {
+                    return Err(TreeWalkerErr::IndexOutOfRange(
+                        row,
+                        table.rows.len(),
+                        token.clone(),
+                    ));
+                };
+
+                if column >= table.headers.len() {
+                    return Err(TreeWalkerErr::IndexOutOfRange(
+                        column,
+                        table.headers.len(),
+                        token.clone(),
+                    ));
+                }
+
+                // Rows added with fewer cells than there are columns are empty at the end
+                Ok(cells.get(column).cloned().unwrap_or_default())
+            }
+
+            NativeCallString::KeyName => Ok(self.input.key.name.clone()),
+
+            NativeCallString::MouseButton => Ok(self.input.mouse_button.clone()),
+
+            NativeCallString::StorageGet(key) => {
+                let key = self.eval_string(key)?;
+                Ok(self.storage()?.get(&key).unwrap_or_default())
+            }
+
+            NativeCallString::GetCookie(location, name) => {
+                let location = self.eval_string(location)?;
+                let name = self.eval_string(name)?;
+                let address = self.address.resolve(&location, vec![]);
+
+                self.permissions.check_network(&address)?;
+
+                let value = cookie_jar::shared()
+                    .lock()
+                    .unwrap()
+                    .visible(&address.to_string())
+                    .into_iter()
+                    .find(|(cookie, _)| *cookie == name)
+                    .map(|(_, value)| value);
+
+                Ok(value.unwrap_or_default())
+            }
+
+            NativeCallString::SocketNextMessage(socket) => {
+                let mut socket = self.eval_object(socket)?;
+
+                let message = socket
+                    .extract_native_object()
+                    .extract_socket()
+                    .lock()
+                    .unwrap()
+                    .messages
+                    .pop_front();
+
+                Ok(message.unwrap_or_default())
+            }
+
+            NativeCallString::HttpResponseBody(response) => {
+                let mut response = self.eval_object(response)?;
+
+                let body = response
+                    .extract_native_object()
+                    .extract_http_response()
+                    .lock()
+                    .unwrap()
+                    .text();
+
+                Ok(body)
+            }
+
+            NativeCallString::JsonGetString(json, key, token) => {
+                self.json_convert(json, key, "a String", json::as_string, token)
+            }
+
+            NativeCallString::JsonToString(json) => {
+                let mut json = self.eval_object(json)?;
+
+                let string = json
+                    .extract_native_object()
+                    .extract_json()
+                    .lock()
+                    .unwrap()
+                    .to_string();
+
+                Ok(string)
+            }
+
             NativeCallString::StringArrayGet(array, index, token) => {
                 let mut array_obj = self.eval_object(&array)?;
                 let index = self.eval_int(index)? as usize;
@@ -1,5 +1,12 @@
 use super::state::State;
-use crate::element::*;
+use crate::{
+    async_request::AsyncRequest,
+    element::*,
+    http::{HttpRequest, HttpResponse},
+    socket::Socket,
+    style::Style,
+};
+use serde_json::Value;
 use std::{
     cell::RefCell,
     rc::Rc,
@@ -16,6 +23,22 @@ pub enum NativeObject {
     Row(Arc<Mutex<Row>>),
     Column(Arc<Mutex<Column>>),
     Image(Arc<Mutex<Image>>),
+    Checkbox(Arc<Mutex<Checkbox>>),
+    Toggle(Arc<Mutex<Toggle>>),
+    RadioGroup(Arc<Mutex<RadioGroup>>),
+    Slider(Arc<Mutex<Slider>>),
+    Dropdown(Arc<Mutex<Dropdown>>),
+    TextArea(Arc<Mutex<TextArea>>),
+    Table(Arc<Mutex<Table>>),
+    Canvas(Arc<Mutex<Canvas>>),
+    Scroll(Arc<Mutex<Scroll>>),
+    Stack(Arc<Mutex<Stack>>),
+    Style(Arc<Mutex<Style>>),
+    HttpRequest(Arc<Mutex<HttpRequest>>),
+    HttpResponse(Arc<Mutex<HttpResponse>>),
+    AsyncRequest(Arc<Mutex<AsyncRequest>>),
+    Socket(Arc<Mutex<Socket>>),
+    Json(Arc<Mutex<Value>>),
     IntegerArray(Arc<Mutex<Vec<i64>>>),
     FloatArray(Arc<Mutex<Vec<f64>>>),
     StringArray(Arc<Mutex<Vec<String>>>),
@@ -90,6 +113,134 @@ impl NativeObject {
         }
     }
 
+    pub fn extract_http_request(&mut self) -> &mut Arc<Mutex<HttpRequest>> {
+        if let NativeObject::HttpRequest(request) = self {
+            request
+        } else {
+            panic!(
+                "Attempted to extract type HttpRequest from a native object that was not that type"
+            )
+        }
+    }
+
+    pub fn extract_http_response(&mut self) -> &mut Arc<Mutex<HttpResponse>> {
+        if let NativeObject::HttpResponse(response) = self {
+            response
+        } else {
+            panic!(
+                "Attempted to extract type HttpResponse from a native object that was not that type"
+            )
+        }
+    }
+
+    pub fn extract_checkbox(&mut self) -> &mut Arc<Mutex<Checkbox>> {
+        if let NativeObject::Checkbox(checkbox) = self {
+            checkbox
+        } else {
+            panic!("Attempted to extract type Checkbox from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_toggle(&mut self) -> &mut Arc<Mutex<Toggle>> {
+        if let NativeObject::Toggle(toggle) = self {
+            toggle
+        } else {
+            panic!("Attempted to extract type Toggle from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_radio_group(&mut self) -> &mut Arc<Mutex<RadioGroup>> {
+        if let NativeObject::RadioGroup(radio_group) = self {
+            radio_group
+        } else {
+            panic!(
+                "Attempted to extract type RadioGroup from a native object that was not that type"
+            )
+        }
+    }
+
+    pub fn extract_slider(&mut self) -> &mut Arc<Mutex<Slider>> {
+        if let NativeObject::Slider(slider) = self {
+            slider
+        } else {
+            panic!("Attempted to extract type Slider from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_dropdown(&mut self) -> &mut Arc<Mutex<Dropdown>> {
+        if let NativeObject::Dropdown(dropdown) = self {
+            dropdown
+        } else {
+            panic!("Attempted to extract type Dropdown from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_text_area(&mut self) -> &mut Arc<Mutex<TextArea>> {
+        if let NativeObject::TextArea(text_area) = self {
+            text_area
+        } else {
+            panic!("Attempted to extract type TextArea from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_table(&mut self) -> &mut Arc<Mutex<Table>> {
+        if let NativeObject::Table(table) = self {
+            table
+        } else {
+            panic!("Attempted to extract type Table from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_canvas(&mut self) -> &mut Arc<Mutex<Canvas>> {
+        if let NativeObject::Canvas(canvas) = self {
+            canvas
+        } else {
+            panic!("Attempted to extract type Canvas from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_scroll(&mut self) -> &mut Arc<Mutex<Scroll>> {
+        if let NativeObject::Scroll(scroll) = self {
+            scroll
+        } else {
+            panic!("Attempted to extract type Scroll from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_async_request(&mut self) -> &mut Arc<Mutex<AsyncRequest>> {
+        if let NativeObject::AsyncRequest(request) = self {
+            request
+        } else {
+            panic!(
+                "Attempted to extract type AsyncRequest from a native object that was not that type"
+            )
+        }
+    }
+
+    pub fn extract_style(&mut self) -> &mut Arc<Mutex<Style>> {
+        if let NativeObject::Style(style) = self {
+            style
+        } else {
+            panic!("Attempted to extract type Style from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_socket(&mut self) -> &mut Arc<Mutex<Socket>> {
+        if let NativeObject::Socket(socket) = self {
+            socket
+        } else {
+            panic!("Attempted to extract type Socket from a native object that was not that type")
+        }
+    }
+
+    pub fn extract_json(&mut self) -> &mut Arc<Mutex<Value>> {
+        if let NativeObject::Json(json) = self {
+            json
+        } else {
+            panic!("Attempted to extract type Json from a native object that was not that type")
+        }
+    }
+
     pub fn extract_integer_array(&mut self) -> &mut Arc<Mutex<Vec<i64>>> {
         if let NativeObject::IntegerArray(array) = self {
             array
@@ -147,6 +298,16 @@ impl NativeObject {
             Self::Row(obj) => obj.lock().unwrap().id,
             Self::Column(obj) => obj.lock().unwrap().id,
             Self::Image(obj) => obj.lock().unwrap().id,
+            Self::Checkbox(obj) => obj.lock().unwrap().id,
+            Self::Toggle(obj) => obj.lock().unwrap().id,
+            Self::RadioGroup(obj) => obj.lock().unwrap().id,
+            Self::Slider(obj) => obj.lock().unwrap().id,
+            Self::Dropdown(obj) => obj.lock().unwrap().id,
+            Self::TextArea(obj) => obj.lock().unwrap().id,
+            Self::Table(obj) => obj.lock().unwrap().id,
+            Self::Canvas(obj) => obj.lock().unwrap().id,
+            Self::Scroll(obj) => obj.lock().unwrap().id,
+            Self::Stack(obj) => obj.lock().unwrap().id,
             _ => unreachable!("Not applicable for this object"),
         }
     }
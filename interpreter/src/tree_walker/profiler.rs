@@ -0,0 +1,450 @@
+use super::{err::TreeWalkerErr, TreeWalker};
+use crate::debug_symbols::DebugSymbols;
+use rustc_hash::FxHashMap;
+use std::{
+    fmt::{self, Debug, Write},
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+pub struct Profiler {
+    symbols: Rc<DebugSymbols>,
+    report_path: PathBuf,
+    folded_stacks_path: Option<PathBuf>,
+    names: Vec<String>,
+    name_ids: FxHashMap<String, usize>,
+    callable_name_ids: FxHashMap<usize, usize>,
+    callables: Vec<CallableStats>,
+    // Call tree used for the folded stacks, where the first node is the start block
+    nodes: Vec<CallNode>,
+    frames: Vec<Frame>,
+    lines: FxHashMap<usize, LineStats>,
+    statements: Vec<Frame>,
+}
+
+#[derive(Default)]
+struct CallableStats {
+    calls: u64,
+    inclusive: Duration,
+    exclusive: Duration,
+    // Recursive calls are only counted once towards inclusive time
+    active: usize,
+}
+
+struct CallNode {
+    name: usize,
+    children: FxHashMap<usize, usize>,
+    exclusive: Duration,
+}
+
+#[derive(Default)]
+struct LineStats {
+    hits: u64,
+    exclusive: Duration,
+}
+
+struct Frame {
+    id: usize,
+    node: usize,
+    start: Instant,
+    children: Duration,
+}
+
+impl Profiler {
+    pub fn new(report_path: PathBuf, folded_stacks_path: Option<PathBuf>) -> Self {
+        Self {
+            symbols: Rc::default(),
+            report_path,
+            folded_stacks_path,
+            names: vec![],
+            name_ids: FxHashMap::default(),
+            callable_name_ids: FxHashMap::default(),
+            callables: vec![],
+            nodes: vec![],
+            frames: vec![],
+            lines: FxHashMap::default(),
+            statements: vec![],
+        }
+    }
+
+    pub fn load_symbols(&mut self, symbols: Rc<DebugSymbols>) {
+        self.symbols = symbols;
+    }
+
+    pub fn start(&mut self) {
+        let id = self.name_id("start");
+        self.nodes.push(CallNode {
+            name: id,
+            children: FxHashMap::default(),
+            exclusive: Duration::ZERO,
+        });
+        self.enter(id);
+    }
+
+    pub fn enter_callable(&mut self, callable: usize) {
+        let id = match self.callable_name_ids.get(&callable) {
+            Some(id) => *id,
+            None => {
+                let symbols = Rc::clone(&self.symbols);
+                let id = self.name_id(symbols.callable_name(Some(callable)));
+                self.callable_name_ids.insert(callable, id);
+                id
+            }
+        };
+
+        self.enter(id);
+    }
+
+    pub fn enter_native(&mut self, call: &impl Debug) {
+        let name = format!("{} (native)", variant_name(call));
+        let id = self.name_id(&name);
+        self.enter(id);
+    }
+
+    fn enter(&mut self, id: usize) {
+        let node = match self.frames.last() {
+            Some(parent) => match self.nodes[parent.node].children.get(&id) {
+                Some(node) => *node,
+                None => {
+                    self.nodes.push(CallNode {
+                        name: id,
+                        children: FxHashMap::default(),
+                        exclusive: Duration::ZERO,
+                    });
+                    let node = self.nodes.len() - 1;
+                    self.nodes[parent.node].children.insert(id, node);
+                    node
+                }
+            },
+            None => 0,
+        };
+
+        let stats = &mut self.callables[id];
+        stats.calls += 1;
+        stats.active += 1;
+
+        self.frames.push(Frame {
+            id,
+            node,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    pub fn exit(&mut self) {
+        let frame = self.frames.pop().unwrap();
+        let elapsed = frame.start.elapsed();
+        let exclusive = elapsed.saturating_sub(frame.children);
+
+        let stats = &mut self.callables[frame.id];
+        stats.active -= 1;
+        stats.exclusive += exclusive;
+        if stats.active == 0 {
+            stats.inclusive += elapsed;
+        }
+
+        self.nodes[frame.node].exclusive += exclusive;
+
+        if let Some(parent) = self.frames.last_mut() {
+            parent.children += elapsed;
+        }
+    }
+
+    pub fn enter_statement(&mut self, position: usize) {
+        self.statements.push(Frame {
+            id: self.symbols.line(position),
+            node: 0,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    // Time spent in nested statements, including those of called callables, is excluded from
+    // the time of a line so the times of all lines add up to the total
+    pub fn exit_statement(&mut self) {
+        let statement = self.statements.pop().unwrap();
+        let elapsed = statement.start.elapsed();
+
+        let stats = self.lines.entry(statement.id).or_default();
+        stats.hits += 1;
+        stats.exclusive += elapsed.saturating_sub(statement.children);
+
+        if let Some(parent) = self.statements.last_mut() {
+            parent.children += elapsed;
+        }
+    }
+
+    fn name_id(&mut self, name: &str) -> usize {
+        match self.name_ids.get(name) {
+            Some(id) => *id,
+            None => {
+                let id = self.names.len();
+                self.names.push(name.to_string());
+                self.name_ids.insert(name.to_string(), id);
+                self.callables.push(CallableStats::default());
+                id
+            }
+        }
+    }
+
+    pub fn finish(&mut self) {
+        // The script may have exited part way through a callable
+        while !self.frames.is_empty() {
+            self.exit();
+        }
+
+        if let Err(e) = std::fs::write(&self.report_path, self.report()) {
+            eprintln!(
+                "Failed to write profile report to '{}': {e}",
+                self.report_path.display()
+            );
+        }
+
+        if let Some(path) = &self.folded_stacks_path {
+            if let Err(e) = std::fs::write(path, self.folded_stacks()) {
+                eprintln!("Failed to write folded stacks to '{}': {e}", path.display());
+            }
+        }
+    }
+
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        let total = match self.name_ids.get("start") {
+            Some(id) => self.callables[*id].inclusive,
+            None => Duration::ZERO,
+        };
+
+        writeln!(report, "Total time: {:.3} ms", milliseconds(total)).unwrap();
+        writeln!(report).unwrap();
+        writeln!(
+            report,
+            "{:>10} {:>16} {:>16}  Callable",
+            "Calls", "Inclusive (ms)", "Exclusive (ms)"
+        )
+        .unwrap();
+
+        let mut callables = self.callables.iter().enumerate().collect::<Vec<_>>();
+        callables.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.exclusive));
+
+        for (id, stats) in callables {
+            writeln!(
+                report,
+                "{:>10} {:>16.3} {:>16.3}  {}",
+                stats.calls,
+                milliseconds(stats.inclusive),
+                milliseconds(stats.exclusive),
+                self.names[id]
+            )
+            .unwrap();
+        }
+
+        writeln!(report).unwrap();
+        writeln!(report, "{:>10} {:>16}  Line", "Hits", "Time (ms)").unwrap();
+
+        let mut lines = self.lines.iter().collect::<Vec<_>>();
+        lines.sort_by(|(a_line, a), (b_line, b)| {
+            b.exclusive.cmp(&a.exclusive).then(a_line.cmp(b_line))
+        });
+
+        for (line, stats) in lines {
+            writeln!(
+                report,
+                "{:>10} {:>16.3}  {:>4} | {}",
+                stats.hits,
+                milliseconds(stats.exclusive),
+                line,
+                self.symbols.source_line(*line).unwrap_or("").trim()
+            )
+            .unwrap();
+        }
+
+        report
+    }
+
+    // Each line is a semicolon separated stack followed by its exclusive time in microseconds,
+    // which is the input format expected by flamegraph tools
+    pub fn folded_stacks(&self) -> String {
+        let mut folded_stacks = String::new();
+
+        if !self.nodes.is_empty() {
+            self.fold_node(0, &mut vec![], &mut folded_stacks);
+        }
+
+        folded_stacks
+    }
+
+    fn fold_node(&self, node: usize, stack: &mut Vec<usize>, folded_stacks: &mut String) {
+        let node = &self.nodes[node];
+        stack.push(node.name);
+
+        let microseconds = node.exclusive.as_micros();
+
+        if microseconds > 0 {
+            let names = stack
+                .iter()
+                .map(|id| self.names[*id].as_str())
+                .collect::<Vec<_>>();
+            writeln!(folded_stacks, "{} {}", names.join(";"), microseconds).unwrap();
+        }
+
+        let mut children = node.children.values().collect::<Vec<_>>();
+        children.sort();
+
+        for child in children {
+            self.fold_node(*child, stack, folded_stacks);
+        }
+
+        stack.pop();
+    }
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.
+}
+
+// Only the name of the variant is needed, so formatting is abandoned at the first character
+// that cannot be part of it, before any of the arguments are formatted
+fn variant_name(call: &impl Debug) -> String {
+    struct VariantName(String);
+
+    impl fmt::Write for VariantName {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match s.find(|c: char| !c.is_alphanumeric() && c != '_') {
+                Some(end) => {
+                    self.0.push_str(&s[..end]);
+                    Err(fmt::Error)
+                }
+                None => {
+                    self.0.push_str(s);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    let mut name = VariantName(String::new());
+    write!(name, "{:?}", call).ok();
+    name.0
+}
+
+impl<'a> TreeWalker<'a> {
+    pub fn native_call<C: Debug, T>(
+        &mut self,
+        call: &C,
+        native_call: fn(&mut Self, &C) -> Result<T, TreeWalkerErr>,
+    ) -> Result<T, TreeWalkerErr> {
+        match &mut self.profiler {
+            Some(profiler) => {
+                profiler.enter_native(call);
+                let result = native_call(self, call);
+                self.profiler.as_mut().unwrap().exit();
+                result
+            }
+            None => native_call(self, call),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{variant_name, Profiler};
+    use crate::{run, tree_walker::Instrumentation, Permissions};
+    use std::sync::mpsc;
+
+    #[test]
+    fn names_of_native_calls() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        enum Call {
+            CloseTab,
+            ReadString(String, Box<Call>),
+        }
+
+        assert_eq!(variant_name(&Call::CloseTab), "CloseTab");
+        assert_eq!(
+            variant_name(&Call::ReadString(
+                String::from("address"),
+                Box::new(Call::CloseTab)
+            )),
+            "ReadString"
+        );
+    }
+
+    #[test]
+    fn counts_calls_and_lines() {
+        let directory = std::env::temp_dir().join(format!("zonkey-profile-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let report_path = directory.join("profile.txt");
+        let folded_stacks_path = directory.join("stacks.folded");
+
+        let source = "\
+function fib(Integer n) -> Integer {
+    if (n < 2) {
+        return n;
+    }
+    return fib(n - 1) + fib(n - 2);
+}
+
+start {
+    let result = fib(10);
+    print(integer_to_string(result));
+}
+";
+
+        let (report_path_copy, folded_stacks_path_copy) =
+            (report_path.clone(), folded_stacks_path.clone());
+
+        std::thread::Builder::new()
+            .stack_size(crate::REQUIRED_STACK_SIZE)
+            .spawn(move || {
+                let (mut sender, _) = mpsc::channel();
+                let (_, receiver) = mpsc::channel();
+
+                let instrumentation = Instrumentation {
+                    profiler: Some(Profiler::new(
+                        report_path_copy,
+                        Some(folded_stacks_path_copy),
+                    )),
+                    ..Default::default()
+                };
+
+                if let Err(e) = run(
+                    source,
+                    &mut sender,
+                    receiver,
+                    Permissions::all(),
+                    vec![],
+                    instrumentation,
+                ) {
+                    panic!("{}", e.get_err_messages());
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        let report = std::fs::read_to_string(&report_path).unwrap();
+        let folded_stacks = std::fs::read_to_string(&folded_stacks_path).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let first_column = |suffix: &str| {
+            report
+                .lines()
+                .find(|line| line.ends_with(suffix))
+                .and_then(|line| line.split_whitespace().next())
+                .map(|column| column.to_string())
+        };
+
+        assert_eq!(first_column("  fib").as_deref(), Some("177"));
+        assert_eq!(first_column("  start").as_deref(), Some("1"));
+        assert_eq!(first_column("  Print (native)").as_deref(), Some("1"));
+        assert_eq!(first_column("| if (n < 2) {").as_deref(), Some("177"));
+        assert_eq!(first_column("| return n;").as_deref(), Some("89"));
+
+        for line in folded_stacks.lines() {
+            assert!(line.starts_with("start"));
+        }
+    }
+}
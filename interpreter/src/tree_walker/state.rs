@@ -43,6 +43,16 @@ impl State {
         self.object_stack.truncate(stack.object);
     }
 
+    pub fn stack(&self) -> Stack {
+        Stack {
+            integer: self.integer_stack.len(),
+            float: self.float_stack.len(),
+            string: self.string_stack.len(),
+            boolean: self.boolean_stack.len(),
+            object: self.object_stack.len(),
+        }
+    }
+
     pub fn push_int(&mut self, integer: i64) {
         self.integer_stack.push(integer);
     }
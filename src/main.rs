@@ -3,11 +3,19 @@ use clap::{Args, Parser, Subcommand};
 use interpreter::{
     event::InterpreterEvent,
     iced::{self, Application, Settings},
+    Coverage, Debugger, Instrumentation, Profiler,
+};
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+    sync::mpsc,
+    thread,
 };
-use std::{process::ExitCode, sync::mpsc, thread};
 use window::Window;
 
 mod tab;
+mod test_runner;
 mod window;
 
 #[derive(Parser)]
@@ -23,6 +31,10 @@ enum Command {
     Run(RunArgs),
     /// Run the browser
     Browser(BrowserArgs),
+    /// Run the script in the command line interface with a debugger, pausing before the first statement.
+    Debug(DebugArgs),
+    /// Run the tests defined in a script, or in every script within a directory.
+    Test(TestArgs),
 }
 
 #[derive(Args)]
@@ -45,6 +57,22 @@ struct RunArgs {
     #[arg(default_value_t = 720, long)]
     ///Height of the window launched
     height: u32,
+
+    #[arg(long)]
+    ///Record call counts and timings of callables and lines while the script runs
+    profile: bool,
+
+    #[arg(default_value = "zonkey-profile.txt", long)]
+    ///File to write the profile report to
+    profile_output: PathBuf,
+
+    #[arg(long)]
+    ///File to write flamegraph compatible folded stacks to, enabling the profiler
+    folded_stacks: Option<PathBuf>,
+
+    #[arg(long)]
+    ///File to write an lcov report of the statements and branches executed to
+    coverage: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -62,6 +90,29 @@ struct BrowserArgs {
     arguments: Vec<String>,
 }
 
+#[derive(Args)]
+struct DebugArgs {
+    ///A script to debug in the command line interface
+    script_address: String,
+
+    ///Arguments to be passed to the script
+    arguments: Vec<String>,
+
+    #[arg(default_value_t = 1280, long)]
+    ///Width of the window launched
+    width: u32,
+
+    #[arg(default_value_t = 720, long)]
+    ///Height of the window launched
+    height: u32,
+}
+
+#[derive(Args)]
+struct TestArgs {
+    ///A script or directory of scripts containing tests
+    path: PathBuf,
+}
+
 pub fn main() -> ExitCode {
     let arguments = Arguments::parse();
 
@@ -70,7 +121,17 @@ pub fn main() -> ExitCode {
             let address = Address::new(&run_args.script_address, run_args.arguments);
             #[cfg(target_os = "windows")]
             disable_console(run_args.disable_console);
-            command_line_tool(address, run_args.width, run_args.height)
+            let profile = run_args.profile || run_args.folded_stacks.is_some();
+            let source_name = address.location.clone();
+            let instrumentation = move || Instrumentation {
+                profiler: profile
+                    .then(|| Profiler::new(run_args.profile_output, run_args.folded_stacks)),
+                coverage: run_args
+                    .coverage
+                    .map(|report_path| Coverage::new(report_path, source_name)),
+                ..Default::default()
+            };
+            command_line_tool(address, run_args.width, run_args.height, instrumentation)
         }
         Command::Browser(browser_args) => {
             let address = Address::new(&browser_args.script_address, browser_args.arguments);
@@ -78,6 +139,20 @@ pub fn main() -> ExitCode {
             disable_console(!browser_args.enable_console);
             browser(address)
         }
+        Command::Debug(debug_args) => {
+            let address = Address::new(&debug_args.script_address, debug_args.arguments);
+            let instrumentation = || Instrumentation {
+                debugger: Some(Debugger::default()),
+                ..Default::default()
+            };
+            command_line_tool(
+                address,
+                debug_args.width,
+                debug_args.height,
+                instrumentation,
+            )
+        }
+        Command::Test(test_args) => test_runner::run(test_args.path),
     }
 }
 
@@ -112,7 +187,13 @@ fn browser(address: Address) -> ExitCode {
     }
 }
 
-fn command_line_tool(address: Address, width: u32, height: u32) -> ExitCode {
+fn command_line_tool(
+    address: Address,
+    width: u32,
+    height: u32,
+    // Instrumentation is created on the interpreter thread as it cannot be sent between threads
+    instrumentation: impl FnOnce() -> Instrumentation + Send + 'static,
+) -> ExitCode {
     let (interpreter_event_sender, interpreter_event_receiver) = mpsc::channel();
     let (page_event_sender, page_event_receiver) = mpsc::channel();
 
@@ -122,51 +203,113 @@ fn command_line_tool(address: Address, width: u32, height: u32) -> ExitCode {
 
     builder
         .spawn(move || {
-            interpreter::run_with_error_messages(
+            interpreter::run_with_instrumentation(
                 address_copy,
                 interpreter_event_sender,
                 page_event_receiver,
+                instrumentation(),
             );
         })
         .expect("Failed to spawn interpreter thread.");
 
-    match interpreter_event_receiver.recv() {
-        Ok(InterpreterEvent::SetPage(page)) => {
-            let result = Window::run(Settings {
-                default_font: Some("Noto".as_bytes()),
-                antialiasing: true,
-                text_multithreading: true,
-                flags: (
-                    address,
-                    Some((page, page_event_sender, interpreter_event_receiver)),
-                ),
-                id: None,
-                window: iced::window::Settings {
-                    size: (width, height),
-                    ..Default::default()
-                },
-                default_text_size: 20.,
-                exit_on_close_request: true,
-                try_opengles_first: false,
-            });
-
-            return match result {
-                Ok(_) => ExitCode::SUCCESS,
-                Err(e) => {
-                    eprintln!("Failed to open window for script. Please make sure you are using a GPU that supports OpenGL 3.0+ or OpenGL ES 2.0. Error details: {e}");
-                    ExitCode::FAILURE
+    loop {
+        match interpreter_event_receiver.recv() {
+            Ok(InterpreterEvent::SetPage(page)) => {
+                let result = Window::run(Settings {
+                    default_font: Some("Noto".as_bytes()),
+                    antialiasing: true,
+                    text_multithreading: true,
+                    flags: (
+                        address,
+                        Some((page, page_event_sender, interpreter_event_receiver)),
+                    ),
+                    id: None,
+                    window: iced::window::Settings {
+                        size: (width, height),
+                        ..Default::default()
+                    },
+                    default_text_size: 20.,
+                    exit_on_close_request: true,
+                    try_opengles_first: false,
+                });
+
+                return match result {
+                    Ok(_) => ExitCode::SUCCESS,
+                    Err(e) => {
+                        eprintln!("Failed to open window for script. Please make sure you are using a GPU that supports OpenGL 3.0+ or OpenGL ES 2.0. Error details: {e}");
+                        ExitCode::FAILURE
+                    }
+                };
+            }
+            Ok(InterpreterEvent::ScriptError(_)) => {
+                return ExitCode::FAILURE;
+            }
+            Ok(InterpreterEvent::LoadAddressError(error)) => {
+                eprint!("{}", error);
+                return ExitCode::FAILURE;
+            }
+            Ok(InterpreterEvent::RequestPermissions(request)) => {
+                let allowed = ask_permissions(&request.origin, &request.capabilities);
+                request.response.send(allowed).ok();
+            }
+            Ok(InterpreterEvent::ShowDialog(request)) => {
+                // Not answering closes the dialog without a choice
+                if let Some(chosen) = ask_dialog(&request.title, &request.message, &request.buttons)
+                {
+                    request.response.send(chosen).ok();
                 }
-            };
+            }
+            Ok(InterpreterEvent::ReadClipboard(_) | InterpreterEvent::WriteClipboard(_)) => {
+                // There is no clipboard until a window is opened, so reading it returns nothing
+            }
+            Ok(InterpreterEvent::Offline) => {
+                eprintln!("Offline - using a saved copy as the server could not be reached");
+            }
+            _ => break,
         }
-        Ok(InterpreterEvent::ScriptError(_)) => {
-            return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn ask_dialog(title: &str, message: &str, buttons: &[String]) -> Option<usize> {
+    println!("{title}");
+    println!("{message}");
+
+    for (index, button) in buttons.iter().enumerate() {
+        println!("  {}) {button}", index + 1);
+    }
+
+    loop {
+        print!("Choose [1-{}] ", buttons.len());
+        io::stdout().flush().ok();
+
+        let mut answer = String::new();
+
+        if io::stdin().read_line(&mut answer).ok()? == 0 {
+            return None;
         }
-        Ok(InterpreterEvent::LoadAddressError(error)) => {
-            eprint!("{}", error);
-            return ExitCode::FAILURE;
+
+        match answer.trim().parse::<usize>() {
+            Ok(choice) if (1..=buttons.len()).contains(&choice) => return Some(choice - 1),
+            _ => continue,
         }
-        _ => (),
+    }
+}
+
+// Asked on stderr so the question is not mixed into the output of the script
+fn ask_permissions(origin: &str, capabilities: &[String]) -> bool {
+    eprintln!("The application at {origin} would like to:");
+
+    for capability in capabilities {
+        eprintln!("  - {capability}");
     }
 
-    ExitCode::SUCCESS
+    eprint!("Allow? [y/N] ");
+    io::stderr().flush().ok();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).ok();
+
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
 }
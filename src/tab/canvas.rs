@@ -0,0 +1,179 @@
+use super::message::Message;
+use interpreter::{
+    canvas::{Colour, DrawCommand, Pointer, Style},
+    element,
+    iced::{
+        mouse,
+        widget::canvas::{
+            event::{self, Event},
+            path, Cursor, Frame, Geometry, Path, Program, Stroke, Text,
+        },
+        Color, Point, Rectangle, Size, Theme,
+    },
+};
+use std::sync::{Arc, Mutex};
+
+// Draws the display list of a canvas element, and sends the pointer events on it to the script
+pub struct CanvasProgram(pub Arc<Mutex<element::Canvas>>);
+
+impl CanvasProgram {
+    fn pointer(&self, pointer: Pointer) -> Option<Message> {
+        Some(Message::CanvasPointer(pointer, Arc::clone(&self.0)))
+    }
+}
+
+impl Program<Message> for CanvasProgram {
+    // Whether the pointer was pressed on the canvas and has not been released yet
+    type State = bool;
+
+    fn update(
+        &self,
+        pressed: &mut bool,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let Event::Mouse(event) = event else {
+            return (event::Status::Ignored, None);
+        };
+
+        // Positions outside the canvas are only used while the pointer is held after being
+        // pressed on it, so dragging past the edge can be followed
+        let position = match cursor.position_from(bounds.position()) {
+            Some(position) if *pressed || cursor.is_over(&bounds) => position,
+            _ => return (event::Status::Ignored, None),
+        };
+
+        let message = match event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                *pressed = true;
+                self.pointer(Pointer::Pressed(position.x, position.y))
+            }
+            mouse::Event::ButtonReleased(mouse::Button::Left) if *pressed => {
+                *pressed = false;
+                self.pointer(Pointer::Released(position.x, position.y))
+            }
+            mouse::Event::CursorMoved { .. } => {
+                self.pointer(Pointer::Moved(position.x, position.y))
+            }
+            _ => return (event::Status::Ignored, None),
+        };
+
+        (event::Status::Captured, message)
+    }
+
+    fn draw(
+        &self,
+        _state: &bool,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let canvas = self.0.lock().unwrap();
+        let mut frame = Frame::new(bounds.size());
+
+        for command in &canvas.commands {
+            match command {
+                DrawCommand::Line { from, to, style } => {
+                    let path = Path::line(Point::new(from.0, from.1), Point::new(to.0, to.1));
+                    stroke(&mut frame, &path, style);
+                }
+                DrawCommand::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    style,
+                } => {
+                    let path = Path::rectangle(Point::new(*x, *y), Size::new(*width, *height));
+                    fill_and_stroke(&mut frame, &path, style);
+                }
+                DrawCommand::Circle {
+                    x,
+                    y,
+                    radius,
+                    style,
+                } => {
+                    let path = Path::circle(Point::new(*x, *y), *radius);
+                    fill_and_stroke(&mut frame, &path, style);
+                }
+                DrawCommand::Path {
+                    points,
+                    closed,
+                    style,
+                } => {
+                    let mut builder = path::Builder::new();
+
+                    for (index, (x, y)) in points.iter().enumerate() {
+                        match index {
+                            0 => builder.move_to(Point::new(*x, *y)),
+                            _ => builder.line_to(Point::new(*x, *y)),
+                        }
+                    }
+
+                    // Open paths are only stroked, as filling them would join the ends
+                    if *closed {
+                        builder.close();
+                        fill_and_stroke(&mut frame, &builder.build(), style);
+                    } else {
+                        stroke(&mut frame, &builder.build(), style);
+                    }
+                }
+                DrawCommand::Text {
+                    x,
+                    y,
+                    content,
+                    size,
+                    colour,
+                } => {
+                    if let Some(colour) = colour {
+                        frame.fill_text(Text {
+                            content: content.clone(),
+                            position: Point::new(*x, *y),
+                            color: color(*colour),
+                            size: *size,
+                            ..Text::default()
+                        });
+                    }
+                }
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &bool,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        match cursor.is_over(&bounds) {
+            true => mouse::Interaction::Crosshair,
+            false => mouse::Interaction::default(),
+        }
+    }
+}
+
+fn color(colour: Colour) -> Color {
+    Color::from_rgb8(colour.0, colour.1, colour.2)
+}
+
+fn stroke(frame: &mut Frame, path: &Path, style: &Style) {
+    if let Some(colour) = style.stroke {
+        frame.stroke(
+            path,
+            Stroke::default()
+                .with_color(color(colour))
+                .with_width(style.stroke_width),
+        );
+    }
+}
+
+fn fill_and_stroke(frame: &mut Frame, path: &Path, style: &Style) {
+    if let Some(colour) = style.fill {
+        frame.fill(path, color(colour));
+    }
+
+    stroke(frame, path, style);
+}
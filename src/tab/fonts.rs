@@ -0,0 +1,31 @@
+use interpreter::element::FontFamily;
+use interpreter::iced::Font;
+
+macro_rules! bundled {
+    ($name:literal) => {
+        Font::External {
+            name: $name,
+            bytes: include_bytes!(concat!("fonts/", $name, ".ttf")),
+        }
+    };
+}
+
+// The DejaVu fonts are bundled so bold and italic text looks the same on every system. Text that
+// does not choose a font family, and is neither bold nor italic, keeps the default font.
+pub fn font(family: Option<FontFamily>, bold: bool, italic: bool) -> Font {
+    match (family, bold, italic) {
+        (None, false, false) => Font::Default,
+        (Some(FontFamily::Sans), false, false) => bundled!("DejaVuSans"),
+        (None | Some(FontFamily::Sans), true, false) => bundled!("DejaVuSans-Bold"),
+        (None | Some(FontFamily::Sans), false, true) => bundled!("DejaVuSans-Oblique"),
+        (None | Some(FontFamily::Sans), true, true) => bundled!("DejaVuSans-BoldOblique"),
+        (Some(FontFamily::Serif), false, false) => bundled!("DejaVuSerif"),
+        (Some(FontFamily::Serif), true, false) => bundled!("DejaVuSerif-Bold"),
+        (Some(FontFamily::Serif), false, true) => bundled!("DejaVuSerif-Italic"),
+        (Some(FontFamily::Serif), true, true) => bundled!("DejaVuSerif-BoldItalic"),
+        (Some(FontFamily::Monospace), false, false) => bundled!("DejaVuSansMono"),
+        (Some(FontFamily::Monospace), true, false) => bundled!("DejaVuSansMono-Bold"),
+        (Some(FontFamily::Monospace), false, true) => bundled!("DejaVuSansMono-Oblique"),
+        (Some(FontFamily::Monospace), true, true) => bundled!("DejaVuSansMono-BoldOblique"),
+    }
+}
@@ -1,5 +1,10 @@
+use interpreter::canvas::Pointer;
 use interpreter::element::*;
-use interpreter::{element::Page, event::PageEvent, Address};
+use interpreter::{
+    element::Page,
+    event::{DialogRequest, PageEvent, PermissionRequest},
+    Address,
+};
 use std::sync::{mpsc::Sender, Arc, Mutex};
 
 #[derive(Debug, Clone)]
@@ -12,9 +17,25 @@ pub enum Message {
     LoadAddressErr(String),
     Finished,
     OpenLink(String, Vec<String>),
+    RequestPermissions(PermissionRequest),
+    PermissionsAnswered(bool),
+    ShowDialog(DialogRequest),
+    DialogAnswered(usize),
+    ReadClipboard(Sender<String>),
+    WriteClipboard(String),
+    Offline,
     ButtonPressed(Arc<Mutex<Button>>),
     HyperlinkPressed(String, Vec<String>),
     InputChanged(String, Arc<Mutex<Input>>),
     InputSubmit(Arc<Mutex<Input>>),
+    CheckboxChanged(bool, Arc<Mutex<Checkbox>>),
+    ToggleChanged(bool, Arc<Mutex<Toggle>>),
+    RadioSelected(usize, Arc<Mutex<RadioGroup>>),
+    SliderChanged(f64, Arc<Mutex<Slider>>),
+    DropdownSelected(String, Arc<Mutex<Dropdown>>),
+    TextAreaChanged(String, Arc<Mutex<TextArea>>),
+    TableHeaderPressed(usize, Arc<Mutex<Table>>),
+    TableRowPressed(usize, Arc<Mutex<Table>>),
+    CanvasPointer(Pointer, Arc<Mutex<Canvas>>),
     None,
 }
@@ -1,13 +1,13 @@
 pub use interpreter::iced;
 use interpreter::iced::subscription;
 use interpreter::iced::widget::text;
-use interpreter::iced::widget::Container;
+use interpreter::iced::widget::{Column, Container};
 use interpreter::iced::Element;
 pub use interpreter::iced_native;
 pub use interpreter::Address;
 pub use interpreter::{
     element::Page,
-    event::{InterpreterEvent, PageEvent},
+    event::{DialogRequest, InterpreterEvent, PageEvent, PermissionRequest},
 };
 pub use message::Message;
 use non_empty_vec::NonEmpty;
@@ -20,14 +20,22 @@ use std::{
 };
 use subscription_state::{SubscriptionState, SubscriptionStateVariant};
 
+mod canvas;
+mod fonts;
 mod message;
 mod page_builder;
+mod paragraph;
+mod stack;
 mod subscription_state;
+mod text_area;
 
 pub type MessagePointer = (usize, Message);
 
 pub enum TabEvent {
     Finished,
+    // The clipboard belongs to the window, so it is read and written there
+    ReadClipboard(Sender<String>),
+    WriteClipboard(String),
 }
 
 pub enum PageErr {
@@ -39,6 +47,11 @@ pub struct Tab {
     page: Option<Arc<Mutex<Page>>>,
     page_event_sender: Option<Sender<PageEvent>>,
     page_error: Option<PageErr>,
+    permission_request: Option<PermissionRequest>,
+    // Shown over the page while the script waits for a button to be chosen
+    dialog: Option<DialogRequest>,
+    // Whether stored copies were used as the server of the page could not be reached
+    offline: bool,
     script_executor_sender: Option<Sender<Address>>,
     initial_state: Arc<Mutex<SubscriptionState>>,
     pub history: NonEmpty<Address>,
@@ -55,6 +68,9 @@ impl Tab {
             page: None,
             page_event_sender: None,
             page_error: None,
+            permission_request: None,
+            dialog: None,
+            offline: false,
             script_executor_sender: None,
             waiting_to_load_next_script: true,
             initial_state: Arc::new(Mutex::new((
@@ -79,6 +95,9 @@ impl Tab {
             page: Some(page),
             page_event_sender: Some(page_event_sender),
             page_error: None,
+            permission_request: None,
+            dialog: None,
+            offline: false,
             script_executor_sender: None,
             initial_state: Arc::new(Mutex::new((
                 0,
@@ -101,11 +120,26 @@ impl Tab {
             .map(|msg| (self.position, msg));
         }
 
-        if let Some(page) = &self.page {
-            page_builder::build_page(page).map(|msg| (self.position, msg))
-        } else {
-            Container::new(text("")).into()
+        if let Some(request) = &self.permission_request {
+            return page_builder::permission_request_page(request).map(|msg| (self.position, msg));
+        }
+
+        let page = match &self.page {
+            Some(page) => match self.offline {
+                true => Column::new()
+                    .push(page_builder::offline_banner())
+                    .push(page_builder::build_page(page))
+                    .into(),
+                false => page_builder::build_page(page),
+            },
+            None => Container::new(text("")).into(),
+        };
+
+        match &self.dialog {
+            Some(request) => page_builder::dialog(page, request),
+            None => page,
         }
+        .map(|msg| (self.position, msg))
     }
 
     pub fn update(&mut self, message: Message) -> Option<TabEvent> {
@@ -127,10 +161,71 @@ impl Tab {
                     sender.send(PageEvent::InputConfirmed(input)).ok();
                 }
             }
+            Message::CheckboxChanged(checked, checkbox) => {
+                checkbox.lock().unwrap().checked = checked;
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::CheckboxChanged(checkbox)).ok();
+                }
+            }
+            Message::ToggleChanged(on, toggle) => {
+                toggle.lock().unwrap().on = on;
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::ToggleChanged(toggle)).ok();
+                }
+            }
+            Message::RadioSelected(index, radio_group) => {
+                radio_group.lock().unwrap().selected = Some(index);
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::RadioGroupChanged(radio_group)).ok();
+                }
+            }
+            Message::SliderChanged(value, slider) => {
+                slider.lock().unwrap().value = value;
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::SliderChanged(slider)).ok();
+                }
+            }
+            Message::DropdownSelected(value, dropdown) => {
+                dropdown.lock().unwrap().selected = Some(value);
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::DropdownChanged(dropdown)).ok();
+                }
+            }
+            Message::TextAreaChanged(text, text_area) => {
+                text_area.lock().unwrap().text = text;
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::TextAreaChanged(text_area)).ok();
+                }
+            }
+            Message::TableHeaderPressed(column, table) => {
+                {
+                    let mut table = table.lock().unwrap();
+                    // Pressing the header of the sorted column again reverses the order
+                    let ascending = table.sort != Some((column, true));
+                    table.sort(column, ascending);
+                }
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::TableSorted(table)).ok();
+                }
+            }
+            Message::TableRowPressed(index, table) => {
+                table.lock().unwrap().selected = Some(index);
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::TableRowSelected(table)).ok();
+                }
+            }
+            Message::CanvasPointer(pointer, canvas) => {
+                if let Some(sender) = &self.page_event_sender {
+                    sender.send(PageEvent::CanvasPointer(canvas, pointer)).ok();
+                }
+            }
             Message::StartedScript(page_event_sender) => {
                 self.page = None;
                 self.page_event_sender = Some(page_event_sender);
                 self.page_error = None;
+                self.permission_request = None;
+                self.dialog = None;
+                self.offline = false;
             }
             Message::ReadyForNextScript(script_executor_sender) => {
                 if !self.closing {
@@ -153,6 +248,25 @@ impl Tab {
             }
             Message::Finished => return Some(TabEvent::Finished),
             Message::OpenLink(link, arguments) => self.open_address_from_string(link, arguments),
+            Message::RequestPermissions(request) => {
+                self.permission_request = Some(request);
+            }
+            Message::PermissionsAnswered(allowed) => {
+                if let Some(request) = self.permission_request.take() {
+                    request.response.send(allowed).ok();
+                }
+            }
+            Message::ShowDialog(request) => {
+                self.dialog = Some(request);
+            }
+            Message::DialogAnswered(index) => {
+                if let Some(request) = self.dialog.take() {
+                    request.response.send(index).ok();
+                }
+            }
+            Message::ReadClipboard(sender) => return Some(TabEvent::ReadClipboard(sender)),
+            Message::WriteClipboard(text) => return Some(TabEvent::WriteClipboard(text)),
+            Message::Offline => self.offline = true,
             Message::None => (),
         }
 
@@ -161,7 +275,11 @@ impl Tab {
 
     pub fn title(&self) -> String {
         if let Some(page) = &self.page {
-            page.lock().unwrap().title.to_string()
+            let title = page.lock().unwrap().title.to_string();
+            match self.offline {
+                true => format!("{title} (offline)"),
+                false => title,
+            }
         } else {
             if let Some(error) = &self.page_error {
                 match error {
@@ -243,6 +361,19 @@ impl Tab {
                             InterpreterEvent::OpenLink(link, arguments) => {
                                 (index, Message::OpenLink(link, arguments))
                             }
+                            InterpreterEvent::RequestPermissions(request) => {
+                                (index, Message::RequestPermissions(request))
+                            }
+                            InterpreterEvent::ShowDialog(request) => {
+                                (index, Message::ShowDialog(request))
+                            }
+                            InterpreterEvent::ReadClipboard(sender) => {
+                                (index, Message::ReadClipboard(sender))
+                            }
+                            InterpreterEvent::WriteClipboard(text) => {
+                                (index, Message::WriteClipboard(text))
+                            }
+                            InterpreterEvent::Offline => (index, Message::Offline),
                         },
                         (index, SubscriptionStateVariant::RunningScript(receiver)),
                     ),
@@ -301,6 +432,17 @@ impl Tab {
         self.closing = true;
     }
 
+    // Input is only sent to a script once it has shown a page, and not while a dialog covers it
+    pub fn page_input(&self, event: PageEvent) {
+        if self.dialog.is_some() {
+            return;
+        }
+
+        if let (Some(sender), Some(_)) = (&self.page_event_sender, &self.page) {
+            sender.send(event).ok();
+        }
+    }
+
     pub fn open_address(&mut self, address: Address) {
         self.history.push(address);
         self.load_script()
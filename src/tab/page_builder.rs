@@ -1,75 +1,185 @@
+use super::canvas::CanvasProgram;
+use super::fonts;
 use super::message::Message;
+use super::paragraph::Paragraph;
+use super::stack::Stack;
+use super::text_area::TextArea;
 use interpreter::element::{self, ElementType};
+use interpreter::event::{DialogRequest, PermissionRequest};
 use interpreter::iced_native::{image::Handle, theme};
+use interpreter::style::Style;
 use interpreter::{
     iced::{
-        widget::{text, Button, Column, Container, Image, Row, Scrollable, Space, Text, TextInput},
-        Color, Element, Length, Padding,
+        alignment,
+        widget::{
+            container, text, Button, Canvas, Checkbox, Column, Container, Image, PickList, Radio,
+            Row, Scrollable, Slider, Space, Text, TextInput, Toggler,
+        },
+        Background, Color, Element, Length, Padding, Theme,
     },
     iced_native::Alignment,
 };
 use std::sync::{Arc, Mutex};
 
-fn build_calls<'a>(element: &ElementType) -> Element<'a, Message> {
+// Elements are built with the style inherited from the page, row or column holding them
+fn build_calls<'a>(element: &ElementType, style: &Style) -> Element<'a, Message> {
     match element {
-        ElementType::Text(text) => build_text(text.clone()),
+        ElementType::Text(text) => build_text(text.clone(), style),
         ElementType::Input(input) => build_input(input.clone()),
         ElementType::Hyperlink(hyperlink) => build_hyperlink(hyperlink.clone()),
-        ElementType::Button(button) => build_button(button.clone()),
-        ElementType::Row(row) => build_row(row.clone()),
-        ElementType::Column(column) => build_column(column.clone()),
+        ElementType::Button(button) => build_button(button.clone(), style),
+        ElementType::Row(row) => build_row(row.clone(), style),
+        ElementType::Column(column) => build_column(column.clone(), style),
         ElementType::Image(img) => build_image(img.clone()),
+        ElementType::Checkbox(checkbox) => build_checkbox(checkbox.clone()),
+        ElementType::Toggle(toggle) => build_toggle(toggle.clone()),
+        ElementType::RadioGroup(radio_group) => build_radio_group(radio_group.clone()),
+        ElementType::Slider(slider) => build_slider(slider.clone()),
+        ElementType::Dropdown(dropdown) => build_dropdown(dropdown.clone()),
+        ElementType::TextArea(text_area) => build_text_area(text_area.clone()),
+        ElementType::Table(table) => build_table(table.clone()),
+        ElementType::Canvas(canvas) => build_canvas(canvas.clone()),
+        ElementType::Scroll(scroll) => build_scroll(scroll.clone(), style),
+        ElementType::Stack(stack) => build_stack(stack.clone(), style),
     }
 }
 
 pub fn build_page<'a>(page: &Arc<Mutex<element::Page>>) -> Element<'a, Message> {
-    let page = page.lock().unwrap();
+    let (page, style) = page.lock().unwrap().styled();
     let mut page_content = vec![];
 
     for (_, element) in &page.elements {
-        page_content.push(build_calls(element));
+        page_content.push(build_calls(element, &style));
     }
 
-    let mut column = Column::with_children(page_content).padding(30).spacing(20);
+    let layout = &page.layout;
+
+    let mut column = Column::with_children(page_content)
+        .padding(padding(layout, 30.))
+        .spacing(layout.spacing.unwrap_or(20.));
 
     if let Some(max_width) = page.max_width {
         column = column.max_width(max_width);
     }
 
-    let mut container = Container::new(column).width(Length::Fill);
+    if let Some(width) = layout.width {
+        column = column.width(width);
+    }
 
-    if page.center {
-        container = container.center_x();
+    if let Some(height) = layout.height {
+        column = column.height(height);
     }
 
-    Container::new(Scrollable::new(container))
+    // The block holding the elements is aligned within the window
+    let container = Container::new(
+        Container::new(column).style(theme::Container::Custom(Box::new(layout.clone()))),
+    )
+    .width(Length::Fill)
+    .align_x(horizontal(layout.horizontal_alignment));
+
+    // A page filling the height of the window has nothing to scroll
+    let content: Element<'a, Message> = match layout.height {
+        Some(Length::Fill) => container.height(Length::Fill).into(),
+        _ => Scrollable::new(container).into(),
+    };
+
+    Container::new(content)
         .style(theme::Container::Custom(Box::new(page.clone())))
         .height(Length::Fill)
+        .align_y(vertical(layout.vertical_alignment))
+        .into()
+}
+
+fn padding(layout: &element::Layout, default: f32) -> Padding {
+    let (vertical, horizontal) = layout.padding.unwrap_or((default, default));
+    Padding::from([vertical, horizontal])
+}
+
+fn horizontal(alignment: Option<Alignment>) -> alignment::Horizontal {
+    match alignment {
+        None | Some(Alignment::Start) => alignment::Horizontal::Left,
+        Some(Alignment::Center) => alignment::Horizontal::Center,
+        Some(Alignment::End) => alignment::Horizontal::Right,
+    }
+}
+
+fn vertical(alignment: Option<Alignment>) -> alignment::Vertical {
+    match alignment {
+        None | Some(Alignment::Start) => alignment::Vertical::Top,
+        Some(Alignment::Center) => alignment::Vertical::Center,
+        Some(Alignment::End) => alignment::Vertical::Bottom,
+    }
+}
+
+// Applies the padding, size, alignment and styling of a row or column, which are aligned within
+// their container when it is given a size
+fn layout_container<'a>(
+    content: impl Into<Element<'a, Message>>,
+    layout: &element::Layout,
+    default_width: Length,
+) -> Element<'a, Message> {
+    Container::new(content)
+        .padding(padding(layout, 0.))
+        .width(layout.width.unwrap_or(default_width))
+        .height(layout.height.unwrap_or(Length::Shrink))
+        .align_x(horizontal(layout.horizontal_alignment))
+        .align_y(vertical(layout.vertical_alignment))
+        .style(theme::Container::Custom(Box::new(layout.clone())))
         .into()
 }
 
-fn build_text<'a>(text: Arc<Mutex<element::Text>>) -> Element<'a, Message> {
-    let text = text.lock().unwrap();
-    let mut text_ui = Text::new(text.value.clone()).size(text.size);
+fn build_text<'a>(text: Arc<Mutex<element::Text>>, style: &Style) -> Element<'a, Message> {
+    let text = text.lock().unwrap().styled(style);
+    let size = text.size.unwrap_or(element::TEXT_SIZE);
+    let font = fonts::font(text.font_family, text.bold, text.italic);
+    let colour = text
+        .colour
+        .map(|(red, green, blue)| Color::from_rgb8(red, green, blue));
+
+    // Aligned text fills its width, so there is space to align it within
+    let (width, alignment) = match text.alignment {
+        Some(alignment) => (Length::Fill, horizontal(Some(alignment))),
+        None => (Length::Shrink, alignment::Horizontal::Left),
+    };
+
+    // The text widget of iced can only wrap, with the spacing of the font
+    if text.line_height.is_some() || !text.wrap {
+        let mut paragraph = Paragraph::new(&text.value, size)
+            .font(font)
+            .alignment(alignment)
+            .line_height(text.line_height)
+            .wrap(text.wrap);
+
+        if let Some(colour) = colour {
+            paragraph = paragraph.colour(colour);
+        }
 
-    if let Some(colour) = text.colour {
-        text_ui = text_ui.style(Color::from_rgb8(colour.0, colour.1, colour.2));
+        return paragraph.into();
+    }
+
+    let mut text_ui = Text::new(text.value.clone())
+        .size(size)
+        .font(font)
+        .width(width)
+        .horizontal_alignment(alignment);
+
+    if let Some(colour) = colour {
+        text_ui = text_ui.style(colour);
     }
 
     text_ui.into()
 }
 
-fn build_button<'a>(obj: Arc<Mutex<element::Button>>) -> Element<'a, Message> {
-    let button = obj.lock().unwrap();
+fn build_button<'a>(obj: Arc<Mutex<element::Button>>, style: &Style) -> Element<'a, Message> {
+    let button = obj.lock().unwrap().styled(style);
+    let (vertical, horizontal) = button.padding.unwrap_or(element::BUTTON_PADDING);
+    let width_fill = button.width_fill;
     let button_ui = Button::new(Text::new(button.text.clone()))
         .on_press(Message::ButtonPressed(obj.clone()))
-        .style(theme::Button::Custom(Box::new(button.clone())))
-        .padding(Padding::from([
-            button.vertical_padding,
-            button.horizontal_padding,
-        ]));
+        .padding(Padding::from([vertical, horizontal]))
+        .style(theme::Button::Custom(Box::new(button)));
 
-    if button.width_fill {
+    if width_fill {
         button_ui.width(Length::Fill).into()
     } else {
         button_ui.into()
@@ -100,44 +210,211 @@ fn build_input<'a>(obj: Arc<Mutex<element::Input>>) -> Element<'a, Message> {
     .into()
 }
 
-fn build_row<'a>(row: Arc<Mutex<element::Row>>) -> Element<'a, Message> {
+fn build_checkbox<'a>(obj: Arc<Mutex<element::Checkbox>>) -> Element<'a, Message> {
+    let obj_ref = Arc::clone(&obj);
+    let checkbox = obj_ref.lock().unwrap();
+    Checkbox::new(checkbox.label.clone(), checkbox.checked, move |checked| {
+        Message::CheckboxChanged(checked, Arc::clone(&obj))
+    })
+    .into()
+}
+
+fn build_toggle<'a>(obj: Arc<Mutex<element::Toggle>>) -> Element<'a, Message> {
+    let obj_ref = Arc::clone(&obj);
+    let toggle = obj_ref.lock().unwrap();
+    Toggler::new(Some(toggle.label.clone()), toggle.on, move |on| {
+        Message::ToggleChanged(on, Arc::clone(&obj))
+    })
+    .width(Length::Shrink)
+    .spacing(10)
+    .into()
+}
+
+fn build_radio_group<'a>(obj: Arc<Mutex<element::RadioGroup>>) -> Element<'a, Message> {
+    let radio_group = obj.lock().unwrap();
+    let mut options = vec![];
+
+    for (index, option) in radio_group.options.iter().enumerate() {
+        options.push(
+            Radio::new(option.clone(), index, radio_group.selected, |index| {
+                Message::RadioSelected(index, Arc::clone(&obj))
+            })
+            .into(),
+        );
+    }
+
+    Column::with_children(options).spacing(10).into()
+}
+
+fn build_slider<'a>(obj: Arc<Mutex<element::Slider>>) -> Element<'a, Message> {
+    let obj_ref = Arc::clone(&obj);
+    let slider = obj_ref.lock().unwrap();
+    Slider::new(slider.min..=slider.max, slider.value, move |value| {
+        Message::SliderChanged(value, Arc::clone(&obj))
+    })
+    .step(slider.step)
+    .into()
+}
+
+fn build_dropdown<'a>(obj: Arc<Mutex<element::Dropdown>>) -> Element<'a, Message> {
+    let obj_ref = Arc::clone(&obj);
+    let dropdown = obj_ref.lock().unwrap();
+    PickList::new(
+        dropdown.options.clone(),
+        dropdown.selected.clone(),
+        move |value| Message::DropdownSelected(value, Arc::clone(&obj)),
+    )
+    .placeholder(dropdown.placeholder.clone())
+    .into()
+}
+
+fn build_text_area<'a>(obj: Arc<Mutex<element::TextArea>>) -> Element<'a, Message> {
+    let obj_ref = Arc::clone(&obj);
+    let text_area = obj_ref.lock().unwrap();
+    TextArea::new(&text_area.placeholder, &text_area.text, move |text| {
+        Message::TextAreaChanged(text, Arc::clone(&obj))
+    })
+    .into()
+}
+
+fn build_table<'a>(obj: Arc<Mutex<element::Table>>) -> Element<'a, Message> {
+    let table = obj.lock().unwrap();
+    let width = |column: usize| match table.column_widths[column] {
+        Some(width) => Length::Fixed(width),
+        None => Length::Fill,
+    };
+
+    // Headers are buttons with the same padding as the cells below, so the columns line up
+    let headers = table.headers.iter().enumerate().map(|(column, header)| {
+        let label = match table.sort {
+            Some((sorted, true)) if sorted == column => format!("{header} ▲"),
+            Some((sorted, false)) if sorted == column => format!("{header} ▼"),
+            _ => header.clone(),
+        };
+
+        Button::new(Text::new(label))
+            .on_press(Message::TableHeaderPressed(column, Arc::clone(&obj)))
+            .style(theme::Button::Secondary)
+            .width(width(column))
+            .padding(5)
+            .into()
+    });
+
+    let mut rows = vec![Row::with_children(headers.collect())
+        .width(Length::Fill)
+        .into()];
+
+    for (index, cells) in table.rows.iter().enumerate() {
+        let cells = (0..table.headers.len()).map(|column| {
+            Container::new(Text::new(cells.get(column).cloned().unwrap_or_default()))
+                .width(width(column))
+                .padding(5)
+                .into()
+        });
+
+        let style = match table.selected == Some(index) {
+            true => theme::Button::Primary,
+            false => theme::Button::Text,
+        };
+
+        rows.push(
+            Button::new(Row::with_children(cells.collect()).width(Length::Fill))
+                .on_press(Message::TableRowPressed(index, Arc::clone(&obj)))
+                .style(style)
+                .width(Length::Fill)
+                .padding(0)
+                .into(),
+        );
+    }
+
+    Column::with_children(rows).into()
+}
+
+fn build_canvas<'a>(obj: Arc<Mutex<element::Canvas>>) -> Element<'a, Message> {
+    let (width, height) = {
+        let canvas = obj.lock().unwrap();
+        (canvas.width, canvas.height)
+    };
+
+    Canvas::new(CanvasProgram(obj))
+        .width(Length::Fixed(width))
+        .height(Length::Fixed(height))
+        .into()
+}
+
+fn build_row<'a>(row: Arc<Mutex<element::Row>>, parent: &Style) -> Element<'a, Message> {
     let row = row.lock().unwrap();
+    let style = element::cascade(&row.style, parent);
+    let layout = &row.layout.styled(&style);
     let mut row_content = vec![];
 
-    if row.center {
+    // Rows fill their width, so space either side of the elements aligns them
+    let alignment = layout.horizontal_alignment.unwrap_or(Alignment::Start);
+
+    if alignment != Alignment::Start {
         row_content.push(Space::with_width(Length::Fill).into());
     }
 
     for (_, element) in &row.elements {
-        row_content.push(build_calls(element));
+        row_content.push(build_calls(element, &style));
     }
 
-    if row.center {
+    if alignment == Alignment::Center {
         row_content.push(Space::with_width(Length::Fill).into());
     }
 
-    Row::with_children(row_content)
-        .spacing(10)
+    let row_ui = Row::with_children(row_content)
+        .spacing(layout.spacing.unwrap_or(10.))
         .width(Length::Fill)
-        .align_items(Alignment::Center)
-        .into()
+        .align_items(layout.vertical_alignment.unwrap_or(Alignment::Center));
+
+    layout_container(row_ui, layout, Length::Fill)
 }
 
-fn build_column<'a>(column: Arc<Mutex<element::Column>>) -> Element<'a, Message> {
+fn build_column<'a>(column: Arc<Mutex<element::Column>>, parent: &Style) -> Element<'a, Message> {
     let column_obj = column.lock().unwrap();
+    let style = element::cascade(&column_obj.style, parent);
     let mut column_content = vec![];
 
     for (_, element) in &column_obj.elements {
-        column_content.push(build_calls(element));
+        column_content.push(build_calls(element, &style));
     }
 
-    let mut column = Column::with_children(column_content).spacing(10);
+    let layout = &column_obj.layout.styled(&style);
+
+    let mut column = Column::with_children(column_content)
+        .spacing(layout.spacing.unwrap_or(10.))
+        .align_items(layout.horizontal_alignment.unwrap_or(Alignment::Start));
 
     if let Some(max_width) = column_obj.max_width {
         column = column.max_width(max_width);
     }
 
-    column.into()
+    layout_container(column, layout, Length::Shrink)
+}
+
+fn build_scroll<'a>(scroll: Arc<Mutex<element::Scroll>>, style: &Style) -> Element<'a, Message> {
+    let scroll = scroll.lock().unwrap();
+    let mut scroll_content = vec![];
+
+    for (_, element) in &scroll.elements {
+        scroll_content.push(build_calls(element, style));
+    }
+
+    Scrollable::new(Column::with_children(scroll_content).spacing(10))
+        .height(Length::Fixed(scroll.height))
+        .into()
+}
+
+fn build_stack<'a>(stack: Arc<Mutex<element::Stack>>, style: &Style) -> Element<'a, Message> {
+    let stack = stack.lock().unwrap();
+    let mut stack_content = vec![];
+
+    for (_, element) in &stack.elements {
+        stack_content.push(build_calls(element, style));
+    }
+
+    Stack::with_children(stack_content).into()
 }
 
 fn build_image<'a>(image: Arc<Mutex<element::Image>>) -> Element<'a, Message> {
@@ -174,3 +451,98 @@ pub fn load_address_error_page<'a>(error: &str) -> Element<'a, Message> {
         .spacing(20)
         .into()
 }
+
+// The page is dimmed behind the dialog, which can only be closed by choosing one of its buttons
+pub fn dialog<'a>(page: Element<'a, Message>, request: &DialogRequest) -> Element<'a, Message> {
+    let buttons = request
+        .buttons
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            Button::new(text(label))
+                .on_press(Message::DialogAnswered(index))
+                .into()
+        })
+        .collect();
+
+    let card = Container::new(
+        Column::new()
+            .push(text(&request.title).size(30))
+            .push(text(&request.message))
+            .push(Row::with_children(buttons).spacing(10))
+            .spacing(20),
+    )
+    .padding(30)
+    .max_width(500)
+    .style(theme::Container::Custom(Box::new(DialogStyle::Card)));
+
+    let backdrop = Container::new(card)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .style(theme::Container::Custom(Box::new(DialogStyle::Backdrop)));
+
+    Stack::with_children(vec![page, backdrop.into()])
+        .modal()
+        .into()
+}
+
+enum DialogStyle {
+    Backdrop,
+    Card,
+}
+
+impl container::StyleSheet for DialogStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _: &Self::Style) -> container::Appearance {
+        match self {
+            DialogStyle::Backdrop => container::Appearance {
+                background: Some(Background::Color(Color::from_rgba8(0, 0, 0, 0.4))),
+                ..Default::default()
+            },
+            DialogStyle::Card => container::Appearance {
+                background: Some(Background::Color(Color::WHITE)),
+                text_color: Some(Color::BLACK),
+                border_radius: 5.,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub fn offline_banner<'a>() -> Element<'a, Message> {
+    Container::new(
+        text("Offline - showing a saved copy as the server could not be reached")
+            .style(Color::from_rgb8(0x8a, 0x5a, 0x00)),
+    )
+    .padding([10, 30])
+    .width(Length::Fill)
+    .into()
+}
+
+pub fn permission_request_page<'a>(request: &PermissionRequest) -> Element<'a, Message> {
+    let mut capabilities = Column::new().spacing(10);
+
+    for capability in &request.capabilities {
+        capabilities = capabilities.push(text(format!("- {capability}")));
+    }
+
+    Column::new()
+        .push(text("Permission requested").size(40))
+        .push(text(format!(
+            "The application at {} would like to:",
+            request.origin
+        )))
+        .push(capabilities)
+        .push(
+            Row::new()
+                .push(Button::new(text("Allow")).on_press(Message::PermissionsAnswered(true)))
+                .push(Button::new(text("Deny")).on_press(Message::PermissionsAnswered(false)))
+                .spacing(10),
+        )
+        .padding(20)
+        .spacing(20)
+        .into()
+}
@@ -0,0 +1,192 @@
+use interpreter::iced_native::{
+    alignment, layout, renderer,
+    text::{self, Text},
+    widget::tree::Tree,
+    Color, Element, Font, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+// Text with a chosen space between its lines, or which is not wrapped, which iced does not
+// provide. Lines that are too long for the width available are wrapped between words, the same
+// as the text area, and text which is not wrapped is cut off at the edge.
+pub struct Paragraph {
+    value: String,
+    size: f32,
+    font: Font,
+    colour: Option<Color>,
+    alignment: alignment::Horizontal,
+    // A multiple of the size, or the spacing of the font when not set
+    line_height: Option<f32>,
+    wrap: bool,
+}
+
+impl Paragraph {
+    pub fn new(value: &str, size: f32) -> Self {
+        Self {
+            value: value.to_string(),
+            size,
+            font: Font::Default,
+            colour: None,
+            alignment: alignment::Horizontal::Left,
+            line_height: None,
+            wrap: true,
+        }
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    pub fn colour(mut self, colour: Color) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+
+    pub fn alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn line_height(mut self, line_height: Option<f32>) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    fn lines<Renderer: text::Renderer<Font = Font>>(
+        &self,
+        renderer: &Renderer,
+        width: f32,
+    ) -> Vec<String> {
+        let mut lines = vec![];
+
+        for line in self.value.split('\n') {
+            if !self.wrap {
+                lines.push(line.to_string());
+                continue;
+            }
+
+            let mut current = String::new();
+
+            for word in line.split(' ') {
+                let joined = match current.is_empty() {
+                    true => word.to_string(),
+                    false => format!("{current} {word}"),
+                };
+
+                // A word too long for a line of its own is left to overflow
+                if !current.is_empty()
+                    && renderer.measure_width(&joined, self.size, self.font) > width
+                {
+                    lines.push(std::mem::replace(&mut current, word.to_string()));
+                } else {
+                    current = joined;
+                }
+            }
+
+            lines.push(current);
+        }
+
+        lines
+    }
+
+    fn line_height_of<Renderer: text::Renderer<Font = Font>>(&self, renderer: &Renderer) -> f32 {
+        match self.line_height {
+            Some(line_height) => self.size * line_height,
+            None => {
+                renderer
+                    .measure(" ", self.size, self.font, Size::INFINITY)
+                    .1
+            }
+        }
+    }
+
+    // Aligned text fills the width available to it, so there is space to align it within
+    fn width(&self) -> Length {
+        match self.alignment {
+            alignment::Horizontal::Left => Length::Shrink,
+            _ => Length::Fill,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Paragraph
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn width(&self) -> Length {
+        Paragraph::width(self)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(Paragraph::width(self)).height(Length::Shrink);
+        let lines = self.lines(renderer, limits.max().width);
+
+        let width = lines
+            .iter()
+            .map(|line| renderer.measure_width(line, self.size, self.font))
+            .fold(0., f32::max);
+        let height = lines.len() as f32 * self.line_height_of(renderer);
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let lines = self.lines(renderer, bounds.width);
+        let line_height = self.line_height_of(renderer);
+
+        let x = match self.alignment {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x(),
+            alignment::Horizontal::Right => bounds.x + bounds.width,
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, line) in lines.iter().enumerate() {
+                renderer.fill_text(Text {
+                    content: line,
+                    // Each line is centred vertically within its line height, and is not wrapped
+                    // again by the renderer
+                    bounds: Rectangle {
+                        x,
+                        y: bounds.y + (index as f32 + 0.5) * line_height,
+                        width: f32::INFINITY,
+                        height: line_height,
+                    },
+                    size: self.size,
+                    color: self.colour.unwrap_or(style.text_color),
+                    font: self.font,
+                    horizontal_alignment: self.alignment,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Paragraph> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = Font>,
+{
+    fn from(paragraph: Paragraph) -> Self {
+        Element::new(paragraph)
+    }
+}
@@ -0,0 +1,248 @@
+use interpreter::iced_native::{
+    event::{self, Event},
+    layout, mouse, overlay, renderer,
+    widget::{tree::Tree, Operation},
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+// Elements drawn on top of each other from their top left corner, which iced does not provide.
+// Events go to the top element first, and only reach the elements below if it does not handle
+// them. A modal stack only lets the top element be used, which is how dialogs cover the page.
+pub struct Stack<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Renderer>>,
+    modal: bool,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    pub fn with_children(children: Vec<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            children,
+            modal: false,
+        }
+    }
+
+    pub fn modal(mut self) -> Self {
+        self.modal = true;
+        self
+    }
+
+    // The elements that can be used, from the top one down
+    fn usable(&self) -> usize {
+        match self.modal {
+            true => self.children.len().min(1),
+            false => self.children.len(),
+        }
+    }
+
+    // The stack fills the available space if any of its elements do
+    fn length(&self, length: impl Fn(&Element<'a, Message, Renderer>) -> Length) -> Length {
+        match self
+            .children
+            .iter()
+            .any(|child| length(child) == Length::Fill)
+        {
+            true => Length::Fill,
+            false => Length::Shrink,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Stack<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.length(|child| child.as_widget().width())
+    }
+
+    fn height(&self) -> Length {
+        self.length(|child| child.as_widget().height())
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width()).height(self.height());
+
+        let children = self
+            .children
+            .iter()
+            .map(|child| child.as_widget().layout(renderer, &limits))
+            .collect::<Vec<_>>();
+
+        let size = children.iter().fold(Size::ZERO, |size, child| {
+            Size::new(
+                size.width.max(child.size().width),
+                size.height.max(child.size().height),
+            )
+        });
+
+        layout::Node::with_children(limits.resolve(size), children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let usable = self.usable();
+
+        for ((child, state), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .rev()
+            .take(usable)
+        {
+            let status = child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .rev()
+            .take(self.usable())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let unusable = self.children.len() - self.usable();
+
+        for (index, ((child, state), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            // Elements that cannot be used are not highlighted when the cursor is over them
+            let cursor_position = match index < unusable {
+                true => Point::new(-1., -1.),
+                false => cursor_position,
+            };
+
+            let draw = |renderer: &mut Renderer| {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            };
+
+            // Each element is drawn on its own layer, otherwise the text below would be drawn
+            // over the backgrounds above it
+            match index {
+                0 => draw(renderer),
+                _ => renderer.with_layer(layout.bounds(), draw),
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        match self.modal {
+            true => {
+                let child = self.children.last_mut()?;
+                child.as_widget_mut().overlay(
+                    tree.children.last_mut()?,
+                    layout.children().last()?,
+                    renderer,
+                )
+            }
+            false => overlay::from_children(&mut self.children, tree, layout, renderer),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(stack: Stack<'a, Message, Renderer>) -> Self {
+        Element::new(stack)
+    }
+}
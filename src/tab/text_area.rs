@@ -0,0 +1,448 @@
+use interpreter::iced_native::{
+    alignment,
+    event::{self, Event},
+    keyboard, layout, mouse, renderer,
+    text::{self, Text},
+    widget::{
+        text_input::StyleSheet,
+        tree::{self, Tree},
+    },
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size, Widget,
+};
+
+// A multi-line text input, which iced does not provide. Lines that are too long for the width of
+// the text area are wrapped between words, and the text area grows to fit its content.
+pub struct TextArea<'a, Message> {
+    placeholder: String,
+    value: Vec<char>,
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    padding: Padding,
+    min_rows: usize,
+}
+
+#[derive(Default)]
+struct State {
+    is_focused: bool,
+    // Index of the character the cursor is before
+    cursor: usize,
+}
+
+// Characters of the value shown on one row, where the end is not included
+#[derive(Clone, Copy)]
+struct Row {
+    start: usize,
+    end: usize,
+}
+
+impl<'a, Message> TextArea<'a, Message> {
+    pub fn new(placeholder: &str, value: &str, on_change: impl Fn(String) -> Message + 'a) -> Self {
+        Self {
+            placeholder: placeholder.to_string(),
+            value: value.chars().collect(),
+            on_change: Box::new(on_change),
+            padding: Padding::new(5.),
+            min_rows: 4,
+        }
+    }
+
+    fn text(&self, start: usize, end: usize) -> String {
+        self.value[start..end].iter().collect()
+    }
+
+    fn wrap<Renderer: text::Renderer>(&self, renderer: &Renderer, width: f32) -> Vec<Row> {
+        let measure = |start: usize, end: usize| {
+            renderer.measure_width(
+                self.text(start, end).trim_end(),
+                renderer.default_size(),
+                Default::default(),
+            )
+        };
+
+        let mut rows = vec![];
+        let mut line_start = 0;
+
+        loop {
+            let line_end = self.value[line_start..]
+                .iter()
+                .position(|c| *c == '\n')
+                .map_or(self.value.len(), |position| line_start + position);
+
+            let mut row_start = line_start;
+            let mut word_start = line_start;
+
+            while word_start < line_end {
+                let mut word_end = word_start;
+
+                while word_end < line_end && self.value[word_end] != ' ' {
+                    word_end += 1;
+                }
+
+                while word_end < line_end && self.value[word_end] == ' ' {
+                    word_end += 1;
+                }
+
+                if measure(row_start, word_end) <= width {
+                    word_start = word_end;
+                } else if row_start < word_start {
+                    // The word is moved to the next row
+                    rows.push(Row {
+                        start: row_start,
+                        end: word_start,
+                    });
+                    row_start = word_start;
+                } else {
+                    // The word is too long for a row of its own, so it is broken between characters
+                    let mut end = word_start + 1;
+
+                    while end < word_end && measure(row_start, end + 1) <= width {
+                        end += 1;
+                    }
+
+                    rows.push(Row {
+                        start: row_start,
+                        end,
+                    });
+                    row_start = end;
+                    word_start = end;
+                }
+            }
+
+            rows.push(Row {
+                start: row_start,
+                end: line_end,
+            });
+
+            if line_end == self.value.len() {
+                return rows;
+            }
+
+            line_start = line_end + 1;
+        }
+    }
+
+    // A cursor at the end of a wrapped row is shown at the start of the next one
+    fn row_of(rows: &[Row], cursor: usize) -> usize {
+        rows.iter()
+            .enumerate()
+            .position(|(index, row)| {
+                cursor >= row.start
+                    && (cursor < row.end
+                        || rows.get(index + 1).is_none_or(|next| next.start > row.end))
+            })
+            .unwrap_or(rows.len() - 1)
+    }
+
+    // The position in the row closest to the horizontal offset
+    fn position_in_row<Renderer: text::Renderer>(
+        &self,
+        renderer: &Renderer,
+        row: Row,
+        x: f32,
+    ) -> usize {
+        let width = |end: usize| {
+            renderer.measure_width(
+                &self.text(row.start, end),
+                renderer.default_size(),
+                Default::default(),
+            )
+        };
+
+        (row.start..=row.end)
+            .min_by(|a, b| (width(*a) - x).abs().total_cmp(&(width(*b) - x).abs()))
+            .unwrap_or(row.start)
+    }
+
+    fn line_height<Renderer: text::Renderer>(renderer: &Renderer) -> f32 {
+        let (_, height) = renderer.measure(
+            " ",
+            renderer.default_size(),
+            Default::default(),
+            Size::INFINITY,
+        );
+
+        height
+    }
+
+    fn text_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: bounds.width - self.padding.horizontal(),
+            height: bounds.height - self.padding.vertical(),
+        }
+    }
+
+    fn edit(&mut self, shell: &mut Shell<'_, Message>, state: &mut State, edit: Edit) {
+        let cursor = state.cursor.min(self.value.len());
+
+        state.cursor = match edit {
+            Edit::Insert(text) => {
+                let text = text.chars().filter(|c| *c == '\n' || !c.is_control());
+                let length = self.value.len();
+                self.value.splice(cursor..cursor, text);
+                cursor + self.value.len() - length
+            }
+            Edit::Backspace if cursor > 0 => {
+                self.value.remove(cursor - 1);
+                cursor - 1
+            }
+            Edit::Delete if cursor < self.value.len() => {
+                self.value.remove(cursor);
+                cursor
+            }
+            _ => return,
+        };
+
+        shell.publish((self.on_change)(self.value.iter().collect()));
+    }
+}
+
+enum Edit {
+    Insert(String),
+    Backspace,
+    Delete,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TextArea<'a, Message>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let width = limits.max().width;
+
+        let rows = self
+            .wrap(renderer, width - self.padding.horizontal())
+            .len()
+            .max(self.min_rows);
+        let height = rows as f32 * Self::line_height(renderer) + self.padding.vertical();
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let text_bounds = self.text_bounds(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.is_focused = layout.bounds().contains(cursor_position);
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let rows = self.wrap(renderer, text_bounds.width);
+                let row = ((cursor_position.y - text_bounds.y) / Self::line_height(renderer))
+                    .max(0.) as usize;
+
+                state.cursor = self.position_in_row(
+                    renderer,
+                    rows[row.min(rows.len() - 1)],
+                    cursor_position.x - text_bounds.x,
+                );
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused && !c.is_control() =>
+            {
+                self.edit(shell, state, Edit::Insert(c.to_string()));
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused => {
+                let rows = self.wrap(renderer, text_bounds.width);
+                let cursor = state.cursor.min(self.value.len());
+                let row = Self::row_of(&rows, cursor);
+
+                // Moves to the position on another row closest to the cursor
+                let vertical = |row: Row| {
+                    let x = renderer.measure_width(
+                        &self.text(rows[Self::row_of(&rows, cursor)].start, cursor),
+                        renderer.default_size(),
+                        Default::default(),
+                    );
+
+                    self.position_in_row(renderer, row, x)
+                };
+
+                match key_code {
+                    keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                        self.edit(shell, state, Edit::Insert(String::from("\n")))
+                    }
+                    keyboard::KeyCode::Backspace => self.edit(shell, state, Edit::Backspace),
+                    keyboard::KeyCode::Delete => self.edit(shell, state, Edit::Delete),
+                    keyboard::KeyCode::V if modifiers.command() => {
+                        if let Some(text) = clipboard.read() {
+                            self.edit(shell, state, Edit::Insert(text));
+                        }
+                    }
+                    keyboard::KeyCode::Left => state.cursor = cursor.saturating_sub(1),
+                    keyboard::KeyCode::Right => state.cursor = (cursor + 1).min(self.value.len()),
+                    keyboard::KeyCode::Up if row > 0 => state.cursor = vertical(rows[row - 1]),
+                    keyboard::KeyCode::Down if row + 1 < rows.len() => {
+                        state.cursor = vertical(rows[row + 1])
+                    }
+                    keyboard::KeyCode::Home => state.cursor = rows[row].start,
+                    keyboard::KeyCode::End => state.cursor = rows[row].end,
+                    keyboard::KeyCode::Escape => state.is_focused = false,
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = Default::default();
+        let bounds = layout.bounds();
+        let text_bounds = self.text_bounds(layout);
+        let line_height = Self::line_height(renderer);
+
+        let appearance = if state.is_focused {
+            theme.focused(&style)
+        } else if bounds.contains(cursor_position) {
+            theme.hovered(&style)
+        } else {
+            theme.active(&style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let rows = self.wrap(renderer, text_bounds.width);
+        let size = renderer.default_size();
+
+        renderer.with_layer(text_bounds, |renderer| {
+            let row_bounds = |row: usize| Rectangle {
+                y: text_bounds.y + row as f32 * line_height,
+                height: line_height,
+                ..text_bounds
+            };
+
+            if self.value.is_empty() {
+                renderer.fill_text(Text {
+                    content: &self.placeholder,
+                    bounds: row_bounds(0),
+                    size,
+                    color: theme.placeholder_color(&style),
+                    font: Default::default(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                });
+            }
+
+            for (index, row) in rows.iter().enumerate() {
+                renderer.fill_text(Text {
+                    content: &self.text(row.start, row.end),
+                    bounds: row_bounds(index),
+                    size,
+                    color: theme.value_color(&style),
+                    font: Default::default(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                });
+            }
+
+            if state.is_focused {
+                let cursor = state.cursor.min(self.value.len());
+                let row = Self::row_of(&rows, cursor);
+                let x = renderer.measure_width(
+                    &self.text(rows[row].start, cursor),
+                    size,
+                    Default::default(),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + x,
+                            width: 1.,
+                            ..row_bounds(row)
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    theme.value_color(&style),
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TextArea<'a, Message>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(text_area: TextArea<'a, Message>) -> Self {
+        Element::new(text_area)
+    }
+}
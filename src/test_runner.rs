@@ -0,0 +1,99 @@
+use interpreter::{Address, TestResult};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+};
+
+pub fn run(path: PathBuf) -> ExitCode {
+    let mut scripts = vec![];
+
+    if let Err(e) = find_scripts(&path, &mut scripts) {
+        eprintln!("Failed to find scripts in '{}': {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    scripts.sort();
+
+    let (mut passed, mut failed) = (0, 0);
+    let mut failures = vec![];
+
+    for script in scripts {
+        let address = Address::new(&format!("file:{}", script.display()), vec![]);
+
+        let builder = thread::Builder::new().stack_size(interpreter::REQUIRED_STACK_SIZE);
+
+        let results = builder
+            .spawn(move || interpreter::run_tests(address))
+            .expect("Failed to spawn interpreter thread.")
+            .join()
+            .expect("Failed to join interpreter thread.");
+
+        let results = match results {
+            Ok(results) => results,
+            Err(error_messages) => {
+                println!("{} ... FAILED TO LOAD", script.display());
+                failed += 1;
+                failures.push((script.display().to_string(), error_messages));
+                continue;
+            }
+        };
+
+        for TestResult { name, failure } in results {
+            match failure {
+                None => {
+                    println!("test \"{name}\" in {} ... ok", script.display());
+                    passed += 1;
+                }
+                Some(error_messages) => {
+                    println!("test \"{name}\" in {} ... FAILED", script.display());
+                    failed += 1;
+                    failures.push((
+                        format!("test \"{name}\" in {}", script.display()),
+                        error_messages,
+                    ));
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nFailures:");
+
+        for (test, error_messages) in failures {
+            println!("\n{test}");
+            println!("{}", error_messages.trim_end());
+        }
+    }
+
+    let outcome = if failed == 0 { "ok" } else { "FAILED" };
+    println!("\nTest result: {outcome}. {passed} passed, {failed} failed");
+
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+// Directories are searched recursively for Zonkey scripts
+fn find_scripts(path: &Path, scripts: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        scripts.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            find_scripts(&path, scripts)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "zonk")
+        {
+            scripts.push(path);
+        }
+    }
+
+    Ok(())
+}
@@ -12,4 +12,5 @@ pub enum Message {
     TabClosePressed(usize),
     NewTab,
     SettingsPressed,
+    PageInput(interpreter::event::PageEvent),
 }
@@ -1,16 +1,17 @@
 use crate::tab::iced;
 use crate::tab::iced_native;
-use crate::tab::iced_native::color;
 use crate::tab::Address;
-use crate::tab::{Tab, TabEvent};
+use crate::tab::{Message as TabMessage, Tab, TabEvent};
 use interpreter::address::AddressType;
 use interpreter::element::Page;
 use interpreter::event::{InterpreterEvent, PageEvent};
 use interpreter::iced::{
-    executor, theme::Palette, widget::Column, Application, Color, Command, Element, Length,
-    Subscription, Theme,
+    event, executor, keyboard, mouse, subscription, theme::Palette, widget::Column, window,
+    Application, Color, Command, Element, Event, Length, Subscription, Theme,
 };
 use interpreter::iced_native::command::Action;
+use interpreter::input::Key;
+use interpreter::style;
 use message::Message;
 use std::collections::BTreeMap;
 use std::sync::mpsc::{Receiver, Sender};
@@ -86,12 +87,18 @@ impl Application for Window {
     }
 
     fn theme(&self) -> Self::Theme {
+        // Shared with pages through palette_colour
+        let colour = |name| {
+            let (red, green, blue) = style::palette_colour(name).unwrap();
+            Color::from_rgb8(red, green, blue)
+        };
+
         iced::Theme::custom(Palette {
-            background: Color::WHITE,
-            primary: color!(0xe1e2e2),
-            text: Color::BLACK,
-            success: Color::from_rgb8(255, 255, 255),
-            danger: Color::from_rgb8(0, 0, 0),
+            background: colour("background"),
+            primary: colour("primary"),
+            text: colour("text"),
+            success: colour("success"),
+            danger: colour("danger"),
         })
     }
 
@@ -99,30 +106,45 @@ impl Application for Window {
         match message {
             Message::Tab((index, msg)) => {
                 if let Some(tab) = self.tabs.get_mut(&index) {
-                    if let Some(TabEvent::Finished) = tab.update(msg) {
-                        if self.tabs.len() > 1 {
-                            if index == self.current_tab {
-                                // Move focus to right of tab to be closed
-                                let pos = self.tabs.iter().position(|t| *t.0 == index).unwrap();
-                                self.tabs.remove(&index);
-                                // Make sure that this isn't the last tab
-                                if let Some(new_id) = self.tabs.iter().nth(pos) {
-                                    self.current_tab = *new_id.0;
+                    match tab.update(msg) {
+                        Some(TabEvent::Finished) => {
+                            if self.tabs.len() > 1 {
+                                if index == self.current_tab {
+                                    // Move focus to right of tab to be closed
+                                    let pos = self.tabs.iter().position(|t| *t.0 == index).unwrap();
+                                    self.tabs.remove(&index);
+                                    // Make sure that this isn't the last tab
+                                    if let Some(new_id) = self.tabs.iter().nth(pos) {
+                                        self.current_tab = *new_id.0;
+                                    } else {
+                                        self.current_tab = *self.tabs.last_entry().unwrap().key();
+                                    }
                                 } else {
-                                    self.current_tab = *self.tabs.last_entry().unwrap().key();
+                                    self.tabs.remove(&index);
                                 }
                             } else {
-                                self.tabs.remove(&index);
+                                // This is the last tab - close the application
+                                return Command::single(Action::Window(
+                                    iced_native::window::Action::Close,
+                                ));
                             }
-                        } else {
-                            // This is the last tab - close the application
-                            return Command::single(Action::Window(
-                                iced_native::window::Action::Close,
-                            ));
                         }
+                        Some(TabEvent::ReadClipboard(sender)) => {
+                            return iced::clipboard::read(move |text| {
+                                if let Some(text) = text {
+                                    sender.send(text).ok();
+                                }
+                                Message::Tab((index, TabMessage::None))
+                            });
+                        }
+                        Some(TabEvent::WriteClipboard(text)) => {
+                            return iced::clipboard::write(text);
+                        }
+                        None => (),
                     }
                 }
             }
+            Message::PageInput(event) => self.current_tab().page_input(event),
             Message::HomePressed => {
                 self.current_tab_mut().open_address(Address {
                     address_type: AddressType::Zonkey,
@@ -213,7 +235,8 @@ impl Application for Window {
         Subscription::batch(
             self.tabs
                 .iter()
-                .map(|(_, tab)| tab.subscription().map(Message::Tab)),
+                .map(|(_, tab)| tab.subscription().map(Message::Tab))
+                .chain(std::iter::once(subscription::events_with(page_input))),
         )
     }
 
@@ -231,3 +254,45 @@ impl Window {
         self.tabs.get_mut(&self.current_tab).unwrap()
     }
 }
+
+// Keys and clicks already handled by an element, such as typing into an input, are not sent to
+// the script
+fn page_input(event: Event, status: event::Status) -> Option<Message> {
+    let page_event = match (event, status) {
+        (
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            event::Status::Ignored,
+        ) => {
+            let name = format!("{:?}", key_code);
+
+            PageEvent::KeyPressed(Key {
+                // Number keys are named Key0 to Key9
+                name: match name.strip_prefix("Key") {
+                    Some(digit) => digit.to_string(),
+                    None => name,
+                },
+                shift: modifiers.shift(),
+                control: modifiers.control(),
+                alt: modifiers.alt(),
+            })
+        }
+        (Event::Mouse(mouse::Event::ButtonPressed(button)), event::Status::Ignored) => {
+            PageEvent::MouseClicked(match button {
+                mouse::Button::Other(number) => number.to_string(),
+                button => format!("{:?}", button),
+            })
+        }
+        (Event::Mouse(mouse::Event::CursorMoved { position }), _) => {
+            PageEvent::MouseMoved(position.x, position.y)
+        }
+        (Event::Window(window::Event::Resized { width, height }), _) => {
+            PageEvent::WindowResized(width as f32, height as f32)
+        }
+        _ => return None,
+    };
+
+    Some(Message::PageInput(page_event))
+}
@@ -9,6 +9,8 @@ macro_rules! test_success {
                     .unwrap()
                     .arg("run")
                     .arg($argument)
+                    // Allows the capabilities declared by the script when the user is asked
+                    .write_stdin("y\n")
                     .assert()
                     .success()
                     .get_output()
@@ -170,6 +172,18 @@ fn add_and_remove_elements_from_row_and_column() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn form_elements() -> Result<(), Box<dyn Error>> {
+    test_success!("form_elements", "tests/scripts/form_elements.zonk");
+    Ok(())
+}
+
+#[test]
+fn table() -> Result<(), Box<dyn Error>> {
+    test_success!("table", "tests/scripts/table.zonk");
+    Ok(())
+}
+
 #[test]
 fn integer_array() -> Result<(), Box<dyn Error>> {
     test_success!("integer_array", "tests/scripts/integer_array.zonk");
@@ -278,6 +292,12 @@ fn invalid_hex_colour() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn invalid_alignment() -> Result<(), Box<dyn Error>> {
+    test_fail!("invalid_alignment", "tests/scripts/invalid_alignment.zonk");
+    Ok(())
+}
+
 #[test]
 fn complex_expressions() -> Result<(), Box<dyn Error>> {
     test_success!(
@@ -314,20 +334,30 @@ fn insufficient_permission_level_for_network_script() -> Result<(), Box<dyn Erro
     Ok(())
 }
 
+#[test]
+fn permission_denied_for_undeclared_capability() -> Result<(), Box<dyn Error>> {
+    test_fail!("permission_denied", "tests/scripts/permission_denied.zonk");
+    Ok(())
+}
+
 #[test]
 fn read_and_write_file() -> Result<(), Box<dyn Error>> {
-    std::fs::write("test.txt", "Here is a sequence of numbers from 1 to 10:")
-        .expect("Unable to write file");
+    // The script reads and writes test.txt relative to its own directory
+    std::fs::write(
+        "tests/scripts/test.txt",
+        "Here is a sequence of numbers from 1 to 10:",
+    )
+    .expect("Unable to write file");
     test_success!(
         "read_and_write_file",
         "tests/scripts/read_and_write_file.zonk"
     );
-    let written_data = read_to_string("test.txt").expect("Unable to read file");
+    let written_data = read_to_string("tests/scripts/test.txt").expect("Unable to read file");
     assert_eq!(
         "Here is a sequence of numbers from 1 to 10: 1 2 3 4 5 6 7 8 9 10",
         written_data
     );
-    std::fs::remove_file("test.txt")?;
+    std::fs::remove_file("tests/scripts/test.txt")?;
     Ok(())
 }
 
@@ -413,3 +443,71 @@ fn learning_loops() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn zonkey_tests() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        std::str::from_utf8(
+            &Command::cargo_bin("zonkey")
+                .unwrap()
+                .arg("test")
+                .arg("tests/scripts/zonkey_tests.zonk")
+                .assert()
+                .failure()
+                .get_output()
+                .stdout
+        )
+        .unwrap()
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .collect::<String>(),
+        include_str!("expected_output/zonkey_tests.txt")
+            .chars()
+            .filter(|char| !char.is_whitespace())
+            .collect::<String>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn json() -> Result<(), Box<dyn Error>> {
+    test_success!("json", "tests/scripts/json.zonk");
+    Ok(())
+}
+
+#[test]
+fn json_access_failed() -> Result<(), Box<dyn Error>> {
+    test_fail!(
+        "json_access_failed",
+        "tests/scripts/json_access_failed.zonk"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_palette_colour() -> Result<(), Box<dyn Error>> {
+    test_fail!(
+        "invalid_palette_colour",
+        "tests/scripts/invalid_palette_colour.zonk"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_font_family() -> Result<(), Box<dyn Error>> {
+    test_fail!(
+        "invalid_font_family",
+        "tests/scripts/invalid_font_family.zonk"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_slider_value() -> Result<(), Box<dyn Error>> {
+    test_fail!(
+        "invalid_slider_value",
+        "tests/scripts/invalid_slider_value.zonk"
+    );
+    Ok(())
+}